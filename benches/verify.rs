@@ -0,0 +1,88 @@
+extern crate link_cplusplus;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use elements::opcodes::all::OP_PUSHNUM_1;
+use elements::script::Builder;
+use elements::{
+    confidential, AssetIssuance, OutPoint, Script, Transaction, TxIn, TxInWitness, TxOut,
+};
+use elements_consensus::{verify, verify_transaction, ChainParams, PreparedTransaction};
+
+/// Builds a transaction whose inputs all spend a P2WSH output locked by `OP_TRUE`, together with the spent outputs.
+fn transaction_with_inputs(inputs: usize) -> (Transaction, Vec<TxOut>) {
+    let witness_script = Builder::new().push_opcode(OP_PUSHNUM_1).into_script();
+    let prevout = TxOut {
+        script_pubkey: witness_script.to_v0_p2wsh(),
+        value: confidential::Value::Explicit(100_000),
+        ..Default::default()
+    };
+
+    let transaction = Transaction {
+        version: 2,
+        lock_time: 0,
+        input: (0..inputs)
+            .map(|vout| TxIn {
+                previous_output: OutPoint {
+                    vout: vout as u32,
+                    ..Default::default()
+                },
+                is_pegin: false,
+                has_issuance: false,
+                script_sig: Script::new(),
+                sequence: 0xffff_ffff,
+                asset_issuance: AssetIssuance::default(),
+                witness: TxInWitness {
+                    script_witness: vec![witness_script.to_bytes()],
+                    ..Default::default()
+                },
+            })
+            .collect(),
+        output: vec![TxOut::default()],
+    };
+
+    (transaction, vec![prevout; inputs])
+}
+
+fn verify_all_inputs(c: &mut Criterion) {
+    let mut group = c.benchmark_group("verify all inputs");
+    let chain = ChainParams::elements_regtest();
+
+    for inputs in [1, 10, 100].iter() {
+        let (transaction, prevouts) = transaction_with_inputs(*inputs);
+
+        group.bench_with_input(BenchmarkId::new("verify", inputs), &transaction, |b, tx| {
+            b.iter(|| {
+                for (index, prevout) in prevouts.iter().enumerate() {
+                    verify(prevout.script_pubkey.clone(), &prevout.value, index, tx)
+                        .unwrap()
+                        .unwrap();
+                }
+            })
+        });
+        group.bench_with_input(
+            BenchmarkId::new("PreparedTransaction", inputs),
+            &transaction,
+            |b, tx| {
+                b.iter(|| {
+                    let prepared = PreparedTransaction::new(tx).unwrap();
+                    for (index, prevout) in prevouts.iter().enumerate() {
+                        prepared
+                            .verify(&prevout.script_pubkey, &prevout.value, index)
+                            .unwrap()
+                            .unwrap();
+                    }
+                })
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("verify_transaction", inputs),
+            &transaction,
+            |b, tx| b.iter(|| verify_transaction(tx, &prevouts, &chain).unwrap().unwrap()),
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, verify_all_inputs);
+criterion_main!(benches);
@@ -21,6 +21,14 @@ fn main() {
         .without("upnp", None)
         .build();
 
+    // Wrap every entry point in a try/catch, so that no C++ exception unwinds into Rust.
+    // The shim has to be linked before the library it calls into.
+    cc::Build::new()
+        .cpp(true)
+        .file("shim/shim.cpp")
+        .flag_if_supported("-std=c++17")
+        .compile("elementsconsensus_shim");
+
     // Simply link the library without using pkg-config
     println!("cargo:rustc-link-search=native={}/lib", dst.display());
     println!("cargo:rustc-link-lib=static=elementsconsensus");
@@ -28,14 +36,25 @@ fn main() {
     let binding_file = out_dir().join("bindings.rs");
 
     bindgen::Builder::default()
-        .header("depend/elements/src/script/bitcoinconsensus.h")
+        .header("shim/shim.h")
         .generate_comments(true)
         .generate()
         .expect("Unable to generate bindings")
         .write_to_file(&binding_file)
         .expect("Couldn't write bindings!");
 
+    // The codes of the script errors reported through script_err, which is a C++ header.
+    bindgen::Builder::default()
+        .header("depend/elements/src/script/script_error.h")
+        .clang_args(&["-x", "c++", "-std=c++17"])
+        .allowlist_type("ScriptError_t")
+        .generate()
+        .expect("Unable to generate script error bindings")
+        .write_to_file(out_dir().join("script_error.rs"))
+        .expect("Couldn't write script error bindings!");
+
     println!("cargo:rerun-if-changed=depend/elements-HEAD-revision.txt");
+    println!("cargo:rerun-if-changed=shim");
     println!("cargo:rerun-if-changed=build.rs");
 }
 
@@ -55,48 +55,48 @@ bool VerifyConfidentialPair(const CConfidentialValue& conf_value, const CConfide
     }
 
     // Valid asset commitment?
-    rustsecp256k1_v0_4_1_generator observed_gen;
+    rustsecp256k1zkp_v0_4_0_generator observed_gen;
     if (conf_asset.IsCommitment()) {
-        if (rustsecp256k1_v0_4_1_generator_parse(rustsecp256k1_v0_4_1_blind_context, &observed_gen, &conf_asset.vchCommitment[0]) != 1)
+        if (rustsecp256k1zkp_v0_4_0_generator_parse(rustsecp256k1_v0_4_1_blind_context, &observed_gen, &conf_asset.vchCommitment[0]) != 1)
             return false;
     } else if (conf_asset.IsExplicit()) {
-        if (rustsecp256k1_v0_4_1_generator_generate(rustsecp256k1_v0_4_1_blind_context, &observed_gen, conf_asset.GetAsset().begin()) != 1)
+        if (rustsecp256k1zkp_v0_4_0_generator_generate(rustsecp256k1_v0_4_1_blind_context, &observed_gen, conf_asset.GetAsset().begin()) != 1)
             return false;
     }
 
     // Valid value commitment?
-    rustsecp256k1_v0_4_1_pedersen_commitment value_commit;
-    if (rustsecp256k1_v0_4_1_pedersen_commitment_parse(rustsecp256k1_v0_4_1_blind_context, &value_commit, conf_value.vchCommitment.data()) != 1) {
+    rustsecp256k1zkp_v0_4_0_pedersen_commitment value_commit;
+    if (rustsecp256k1zkp_v0_4_0_pedersen_commitment_parse(rustsecp256k1_v0_4_1_blind_context, &value_commit, conf_value.vchCommitment.data()) != 1) {
         return false;
     }
 
     const unsigned char *asset_type = claimed_asset.id.begin();
     const unsigned char *asset_blinder = asset_blinding_factor.begin();
-    rustsecp256k1_v0_4_1_generator recalculated_gen;
-    if (rustsecp256k1_v0_4_1_generator_generate_blinded(rustsecp256k1_v0_4_1_blind_context, &recalculated_gen, asset_type, asset_blinder) != 1) {
+    rustsecp256k1zkp_v0_4_0_generator recalculated_gen;
+    if (rustsecp256k1zkp_v0_4_0_generator_generate_blinded(rustsecp256k1_v0_4_1_blind_context, &recalculated_gen, asset_type, asset_blinder) != 1) {
         return false;
     }
 
     // Serialize both generators then compare
     unsigned char observed_generator[33];
     unsigned char derived_generator[33];
-    rustsecp256k1_v0_4_1_generator_serialize(rustsecp256k1_v0_4_1_blind_context, observed_generator, &observed_gen);
-    rustsecp256k1_v0_4_1_generator_serialize(rustsecp256k1_v0_4_1_blind_context, derived_generator, &recalculated_gen);
+    rustsecp256k1zkp_v0_4_0_generator_serialize(rustsecp256k1_v0_4_1_blind_context, observed_generator, &observed_gen);
+    rustsecp256k1zkp_v0_4_0_generator_serialize(rustsecp256k1_v0_4_1_blind_context, derived_generator, &recalculated_gen);
     if (memcmp(observed_generator, derived_generator, sizeof(observed_generator))) {
         return false;
     }
 
     const unsigned char *value_blinder = value_blinding_factor.begin();
-    rustsecp256k1_v0_4_1_pedersen_commitment recalculated_commit;
-    if(rustsecp256k1_v0_4_1_pedersen_commit(rustsecp256k1_v0_4_1_blind_context, &recalculated_commit, value_blinder, claimed_value, &observed_gen) != 1) {
+    rustsecp256k1zkp_v0_4_0_pedersen_commitment recalculated_commit;
+    if(rustsecp256k1zkp_v0_4_0_pedersen_commit(rustsecp256k1_v0_4_1_blind_context, &recalculated_commit, value_blinder, claimed_value, &observed_gen) != 1) {
         return false;
     }
 
     // Serialize both value commitments then compare
     unsigned char claimed_commitment[33];
     unsigned char derived_commitment[33];
-    rustsecp256k1_v0_4_1_pedersen_commitment_serialize(rustsecp256k1_v0_4_1_blind_context, claimed_commitment, &value_commit);
-    rustsecp256k1_v0_4_1_pedersen_commitment_serialize(rustsecp256k1_v0_4_1_blind_context, derived_commitment, &recalculated_commit);
+    rustsecp256k1zkp_v0_4_0_pedersen_commitment_serialize(rustsecp256k1_v0_4_1_blind_context, claimed_commitment, &value_commit);
+    rustsecp256k1zkp_v0_4_0_pedersen_commitment_serialize(rustsecp256k1_v0_4_1_blind_context, derived_commitment, &recalculated_commit);
     if (memcmp(claimed_commitment, derived_commitment, sizeof(claimed_commitment))) {
         return false;
     }
@@ -136,24 +136,24 @@ bool UnblindConfidentialPair(const CKey& blinding_key, const CConfidentialValue&
     }
 
     // Valid asset commitment?
-    rustsecp256k1_v0_4_1_generator observed_gen;
+    rustsecp256k1zkp_v0_4_0_generator observed_gen;
     if (conf_asset.IsCommitment()) {
-        if (rustsecp256k1_v0_4_1_generator_parse(rustsecp256k1_v0_4_1_blind_context, &observed_gen, &conf_asset.vchCommitment[0]) != 1)
+        if (rustsecp256k1zkp_v0_4_0_generator_parse(rustsecp256k1_v0_4_1_blind_context, &observed_gen, &conf_asset.vchCommitment[0]) != 1)
             return false;
     } else if (conf_asset.IsExplicit()) {
-        if (rustsecp256k1_v0_4_1_generator_generate(rustsecp256k1_v0_4_1_blind_context, &observed_gen, conf_asset.GetAsset().begin()) != 1)
+        if (rustsecp256k1zkp_v0_4_0_generator_generate(rustsecp256k1_v0_4_1_blind_context, &observed_gen, conf_asset.GetAsset().begin()) != 1)
             return false;
     }
 
     // Valid value commitment?
-    rustsecp256k1_v0_4_1_pedersen_commitment value_commit;
-    if (rustsecp256k1_v0_4_1_pedersen_commitment_parse(rustsecp256k1_v0_4_1_blind_context, &value_commit, conf_value.vchCommitment.data()) != 1) {
+    rustsecp256k1zkp_v0_4_0_pedersen_commitment value_commit;
+    if (rustsecp256k1zkp_v0_4_0_pedersen_commitment_parse(rustsecp256k1_v0_4_1_blind_context, &value_commit, conf_value.vchCommitment.data()) != 1) {
         return false;
     }
 
     // Rewind rangeproof
     uint64_t min_value, max_value, amount;
-    if (!rustsecp256k1_v0_4_1_rangeproof_rewind(rustsecp256k1_v0_4_1_blind_context, blinding_factor_out.begin(), &amount, msg, &msg_size, nonce.begin(), &min_value, &max_value, &value_commit, &vchRangeproof[0], vchRangeproof.size(), (committedScript.size() && !blank_nonce)? &committedScript.front(): NULL, blank_nonce ? 0 : committedScript.size(), &observed_gen)) {
+    if (!rustsecp256k1zkp_v0_4_0_rangeproof_rewind(rustsecp256k1_v0_4_1_blind_context, blinding_factor_out.begin(), &amount, msg, &msg_size, nonce.begin(), &min_value, &max_value, &value_commit, &vchRangeproof[0], vchRangeproof.size(), (committedScript.size() && !blank_nonce)? &committedScript.front(): NULL, blank_nonce ? 0 : committedScript.size(), &observed_gen)) {
         return false;
     }
 
@@ -167,16 +167,16 @@ bool UnblindConfidentialPair(const CKey& blinding_key, const CConfidentialValue&
     unsigned char *asset_blinder = msg+32;
 
     // Asset sidechannel of asset type + asset blinder
-    rustsecp256k1_v0_4_1_generator recalculated_gen;
-    if (msg_size != SIDECHANNEL_MSG_SIZE || rustsecp256k1_v0_4_1_generator_generate_blinded(rustsecp256k1_v0_4_1_blind_context, &recalculated_gen, asset_type, asset_blinder) != 1) {
+    rustsecp256k1zkp_v0_4_0_generator recalculated_gen;
+    if (msg_size != SIDECHANNEL_MSG_SIZE || rustsecp256k1zkp_v0_4_0_generator_generate_blinded(rustsecp256k1_v0_4_1_blind_context, &recalculated_gen, asset_type, asset_blinder) != 1) {
         return false;
     }
 
     // Serialize both generators then compare
     unsigned char observed_generator[33];
     unsigned char derived_generator[33];
-    rustsecp256k1_v0_4_1_generator_serialize(rustsecp256k1_v0_4_1_blind_context, observed_generator, &observed_gen);
-    rustsecp256k1_v0_4_1_generator_serialize(rustsecp256k1_v0_4_1_blind_context, derived_generator, &recalculated_gen);
+    rustsecp256k1zkp_v0_4_0_generator_serialize(rustsecp256k1_v0_4_1_blind_context, observed_generator, &observed_gen);
+    rustsecp256k1zkp_v0_4_0_generator_serialize(rustsecp256k1_v0_4_1_blind_context, derived_generator, &recalculated_gen);
     if (memcmp(observed_generator, derived_generator, sizeof(observed_generator))) {
         return false;
     }
@@ -188,7 +188,7 @@ bool UnblindConfidentialPair(const CKey& blinding_key, const CConfidentialValue&
 }
 
 // Create surjection proof
-bool SurjectOutput(CTxOutWitness& txoutwit, const std::vector<rustsecp256k1_v0_4_1_fixed_asset_tag>& surjection_targets, const std::vector<rustsecp256k1_v0_4_1_generator>& target_asset_generators, const std::vector<uint256 >& target_asset_blinders, const std::vector<const unsigned char*> asset_blindptrs, const rustsecp256k1_v0_4_1_generator& output_asset_gen, const CAsset& asset)
+bool SurjectOutput(CTxOutWitness& txoutwit, const std::vector<rustsecp256k1_v0_4_1_fixed_asset_tag>& surjection_targets, const std::vector<rustsecp256k1zkp_v0_4_0_generator>& target_asset_generators, const std::vector<uint256 >& target_asset_blinders, const std::vector<const unsigned char*> asset_blindptrs, const rustsecp256k1zkp_v0_4_0_generator& output_asset_gen, const CAsset& asset)
 {
     int ret;
     // 1 to 3 targets
@@ -196,24 +196,24 @@ bool SurjectOutput(CTxOutWitness& txoutwit, const std::vector<rustsecp256k1_v0_4
     unsigned char randseed[32];
     GetStrongRandBytes(randseed, 32);
     size_t input_index;
-    rustsecp256k1_v0_4_1_surjectionproof proof;
+    rustsecp256k1zkp_v0_4_0_surjectionproof proof;
     rustsecp256k1_v0_4_1_fixed_asset_tag tag;
     memcpy(&tag, asset.begin(), 32);
     // Find correlation between asset tag and listed input tags
-    if (rustsecp256k1_v0_4_1_surjectionproof_initialize(rustsecp256k1_v0_4_1_blind_context, &proof, &input_index, &surjection_targets[0], surjection_targets.size(), nInputsToSelect, &tag, 100, randseed) == 0) {
+    if (rustsecp256k1zkp_v0_4_0_surjectionproof_initialize(rustsecp256k1_v0_4_1_blind_context, &proof, &input_index, &surjection_targets[0], surjection_targets.size(), nInputsToSelect, &tag, 100, randseed) == 0) {
         return false;
     }
     // Using the input chosen, build proof
-    ret = rustsecp256k1_v0_4_1_surjectionproof_generate(rustsecp256k1_v0_4_1_blind_context, &proof, target_asset_generators.data(), target_asset_generators.size(), &output_asset_gen, input_index, target_asset_blinders[input_index].begin(), asset_blindptrs[asset_blindptrs.size()-1]);
+    ret = rustsecp256k1zkp_v0_4_0_surjectionproof_generate(rustsecp256k1_v0_4_1_blind_context, &proof, target_asset_generators.data(), target_asset_generators.size(), &output_asset_gen, input_index, target_asset_blinders[input_index].begin(), asset_blindptrs[asset_blindptrs.size()-1]);
     assert(ret == 1);
     // Double-check answer
-    ret = rustsecp256k1_v0_4_1_surjectionproof_verify(rustsecp256k1_v0_4_1_blind_context, &proof, target_asset_generators.data(), target_asset_generators.size(), &output_asset_gen);
+    ret = rustsecp256k1zkp_v0_4_0_surjectionproof_verify(rustsecp256k1_v0_4_1_blind_context, &proof, target_asset_generators.data(), target_asset_generators.size(), &output_asset_gen);
     assert(ret != 0);
 
     // Serialize into output witness structure
-    size_t output_len = rustsecp256k1_v0_4_1_surjectionproof_serialized_size(rustsecp256k1_v0_4_1_blind_context, &proof);
+    size_t output_len = rustsecp256k1zkp_v0_4_0_surjectionproof_serialized_size(rustsecp256k1_v0_4_1_blind_context, &proof);
     txoutwit.vchSurjectionproof.resize(output_len);
-    rustsecp256k1_v0_4_1_surjectionproof_serialize(rustsecp256k1_v0_4_1_blind_context, &txoutwit.vchSurjectionproof[0], &output_len, &proof);
+    rustsecp256k1zkp_v0_4_0_surjectionproof_serialize(rustsecp256k1_v0_4_1_blind_context, &txoutwit.vchSurjectionproof[0], &output_len, &proof);
     assert(output_len == txoutwit.vchSurjectionproof.size());
     return true;
 }
@@ -234,7 +234,7 @@ uint256 GenerateOutputRangeproofNonce(CTxOut& out, const CPubKey output_pubkey)
     return nonce;
 }
 
-bool GenerateRangeproof(std::vector<unsigned char>& rangeproof, const std::vector<unsigned char*>& value_blindptrs, const uint256& nonce, const CAmount amount, const CScript& scriptPubKey, const rustsecp256k1_v0_4_1_pedersen_commitment& value_commit, const rustsecp256k1_v0_4_1_generator& gen, const CAsset& asset, std::vector<const unsigned char*>& asset_blindptrs)
+bool GenerateRangeproof(std::vector<unsigned char>& rangeproof, const std::vector<unsigned char*>& value_blindptrs, const uint256& nonce, const CAmount amount, const CScript& scriptPubKey, const rustsecp256k1zkp_v0_4_0_pedersen_commitment& value_commit, const rustsecp256k1zkp_v0_4_0_generator& gen, const CAsset& asset, std::vector<const unsigned char*>& asset_blindptrs)
 {
     // Prep range proof
     size_t nRangeProofLen = 5134;
@@ -250,27 +250,27 @@ bool GenerateRangeproof(std::vector<unsigned char>& rangeproof, const std::vecto
     int ct_bits = (int)gArgs.GetArg("-ct_bits", 52);
     // If min_value is 0, scriptPubKey must be unspendable
     uint64_t min_value = scriptPubKey.IsUnspendable() ? 0 : 1;
-    int res = rustsecp256k1_v0_4_1_rangeproof_sign(rustsecp256k1_v0_4_1_blind_context, rangeproof.data(), &nRangeProofLen, min_value, &value_commit, value_blindptrs.back(), nonce.begin(), ct_exponent, ct_bits, amount, asset_message, sizeof(asset_message), scriptPubKey.size() ? &scriptPubKey.front() : NULL, scriptPubKey.size(), &gen);
+    int res = rustsecp256k1zkp_v0_4_0_rangeproof_sign(rustsecp256k1_v0_4_1_blind_context, rangeproof.data(), &nRangeProofLen, min_value, &value_commit, value_blindptrs.back(), nonce.begin(), ct_exponent, ct_bits, amount, asset_message, sizeof(asset_message), scriptPubKey.size() ? &scriptPubKey.front() : NULL, scriptPubKey.size(), &gen);
     rangeproof.resize(nRangeProofLen);
     return (res == 1);
 }
 
-void BlindAsset(CConfidentialAsset& conf_asset, rustsecp256k1_v0_4_1_generator& asset_gen, const CAsset& asset, const unsigned char* asset_blindptr)
+void BlindAsset(CConfidentialAsset& conf_asset, rustsecp256k1zkp_v0_4_0_generator& asset_gen, const CAsset& asset, const unsigned char* asset_blindptr)
 {
     conf_asset.vchCommitment.resize(CConfidentialAsset::nCommittedSize);
-    int ret = rustsecp256k1_v0_4_1_generator_generate_blinded(rustsecp256k1_v0_4_1_blind_context, &asset_gen, asset.begin(), asset_blindptr);
+    int ret = rustsecp256k1zkp_v0_4_0_generator_generate_blinded(rustsecp256k1_v0_4_1_blind_context, &asset_gen, asset.begin(), asset_blindptr);
     assert(ret == 1);
-    ret = rustsecp256k1_v0_4_1_generator_serialize(rustsecp256k1_v0_4_1_blind_context, conf_asset.vchCommitment.data(), &asset_gen);
+    ret = rustsecp256k1zkp_v0_4_0_generator_serialize(rustsecp256k1_v0_4_1_blind_context, conf_asset.vchCommitment.data(), &asset_gen);
     assert(ret != 0);
 }
 
-void CreateValueCommitment(CConfidentialValue& conf_value, rustsecp256k1_v0_4_1_pedersen_commitment& value_commit, const unsigned char* value_blindptr, const rustsecp256k1_v0_4_1_generator& asset_gen, const CAmount amount)
+void CreateValueCommitment(CConfidentialValue& conf_value, rustsecp256k1zkp_v0_4_0_pedersen_commitment& value_commit, const unsigned char* value_blindptr, const rustsecp256k1zkp_v0_4_0_generator& asset_gen, const CAmount amount)
 {
     int ret;
     conf_value.vchCommitment.resize(CConfidentialValue::nCommittedSize);
-    ret = rustsecp256k1_v0_4_1_pedersen_commit(rustsecp256k1_v0_4_1_blind_context, &value_commit, value_blindptr, amount, &asset_gen);
+    ret = rustsecp256k1zkp_v0_4_0_pedersen_commit(rustsecp256k1_v0_4_1_blind_context, &value_commit, value_blindptr, amount, &asset_gen);
     assert(ret != 0);
-    rustsecp256k1_v0_4_1_pedersen_commitment_serialize(rustsecp256k1_v0_4_1_blind_context, conf_value.vchCommitment.data(), &value_commit);
+    rustsecp256k1zkp_v0_4_0_pedersen_commitment_serialize(rustsecp256k1_v0_4_1_blind_context, conf_value.vchCommitment.data(), &value_commit);
     assert(conf_value.IsValid());
 }
 
@@ -304,7 +304,7 @@ int BlindTransaction(std::vector<uint256 >& input_value_blinding_factors, const
     std::vector<rustsecp256k1_v0_4_1_fixed_asset_tag> surjection_targets;
 
     // Needed to construct the proof itself. Generators must match final transaction to be valid
-    std::vector<rustsecp256k1_v0_4_1_generator> target_asset_generators;
+    std::vector<rustsecp256k1zkp_v0_4_0_generator> target_asset_generators;
 
     // maxTargets is a strict upper-bound for the size of target vectors.
     // The vectors will be shrunk later according to final count of totalTargets
@@ -328,7 +328,7 @@ int BlindTransaction(std::vector<uint256 >& input_value_blinding_factors, const
             // If non-empty generator exists, parse
             if (auxiliary_generators) {
                 // Parse generator here
-                ret = rustsecp256k1_v0_4_1_generator_parse(rustsecp256k1_v0_4_1_blind_context, &target_asset_generators[totalTargets], &(*auxiliary_generators)[i][0]);
+                ret = rustsecp256k1zkp_v0_4_0_generator_parse(rustsecp256k1_v0_4_1_blind_context, &target_asset_generators[totalTargets], &(*auxiliary_generators)[i][0]);
                 if (ret != 1) {
                     return -1;
                 }
@@ -336,7 +336,7 @@ int BlindTransaction(std::vector<uint256 >& input_value_blinding_factors, const
                 return -1;
             }
         } else {
-            ret = rustsecp256k1_v0_4_1_generator_generate_blinded(rustsecp256k1_v0_4_1_blind_context, &target_asset_generators[totalTargets], input_assets[i].begin(), input_asset_blinding_factors[i].begin());
+            ret = rustsecp256k1zkp_v0_4_0_generator_generate_blinded(rustsecp256k1_v0_4_1_blind_context, &target_asset_generators[totalTargets], input_assets[i].begin(), input_asset_blinding_factors[i].begin());
             if (ret != 1) {
                 // Possibly invalid blinding factor provided by user.
                 return -1;
@@ -367,7 +367,7 @@ int BlindTransaction(std::vector<uint256 >& input_value_blinding_factors, const
 
             if (!issuance.nAmount.IsNull()) {
                 memcpy(&surjection_targets[totalTargets], asset.begin(), 32);
-                ret = rustsecp256k1_v0_4_1_generator_generate(rustsecp256k1_v0_4_1_blind_context, &target_asset_generators[totalTargets], asset.begin());
+                ret = rustsecp256k1zkp_v0_4_0_generator_generate(rustsecp256k1_v0_4_1_blind_context, &target_asset_generators[totalTargets], asset.begin());
                 assert(ret != 0);
                 // Issuance asset cannot be blinded by definition
                 target_asset_blinders.push_back(uint256());
@@ -376,7 +376,7 @@ int BlindTransaction(std::vector<uint256 >& input_value_blinding_factors, const
             if (!issuance.nInflationKeys.IsNull()) {
                 assert(!token.IsNull());
                 memcpy(&surjection_targets[totalTargets], token.begin(), 32);
-                ret = rustsecp256k1_v0_4_1_generator_generate(rustsecp256k1_v0_4_1_blind_context, &target_asset_generators[totalTargets], token.begin());
+                ret = rustsecp256k1zkp_v0_4_0_generator_generate(rustsecp256k1_v0_4_1_blind_context, &target_asset_generators[totalTargets], token.begin());
                 assert(ret != 0);
                 // Issuance asset cannot be blinded by definition
                 target_asset_blinders.push_back(uint256());
@@ -389,7 +389,7 @@ int BlindTransaction(std::vector<uint256 >& input_value_blinding_factors, const
         // Process any additional targets from auxiliary_generators
         // we know nothing about it other than the generator itself
         for (size_t i = tx.vin.size(); i < auxiliary_generators->size(); i++) {
-            ret = rustsecp256k1_v0_4_1_generator_parse(rustsecp256k1_v0_4_1_blind_context, &target_asset_generators[totalTargets], &(*auxiliary_generators)[i][0]);
+            ret = rustsecp256k1zkp_v0_4_0_generator_parse(rustsecp256k1_v0_4_1_blind_context, &target_asset_generators[totalTargets], &(*auxiliary_generators)[i][0]);
             if (ret != 1) {
                 return -1;
             }
@@ -465,8 +465,8 @@ int BlindTransaction(std::vector<uint256 >& input_value_blinding_factors, const
     assert(num_to_blind <= 10000); // More than 10k outputs? Stop spamming.
     unsigned char blind[10000][32];
     unsigned char asset_blind[10000][32];
-    rustsecp256k1_v0_4_1_pedersen_commitment value_commit;
-    rustsecp256k1_v0_4_1_generator asset_gen;
+    rustsecp256k1zkp_v0_4_0_pedersen_commitment value_commit;
+    rustsecp256k1zkp_v0_4_0_generator asset_gen;
     CAsset asset;
 
     // First blind issuance pseudo-inputs
@@ -574,7 +574,7 @@ int BlindTransaction(std::vector<uint256 >& input_value_blinding_factors, const
                 }
 
                 // Generate value we intend to insert
-                ret = rustsecp256k1_v0_4_1_pedersen_blind_generator_blind_sum(rustsecp256k1_v0_4_1_blind_context, &blinded_amounts[0], &asset_blindptrs[0], &value_blindptrs[0], num_blind_attempts + num_known_input_blinds, num_issuance_blind_attempts + num_known_input_blinds);
+                ret = rustsecp256k1zkp_v0_4_0_pedersen_blind_generator_blind_sum(rustsecp256k1_v0_4_1_blind_context, &blinded_amounts[0], &asset_blindptrs[0], &value_blindptrs[0], num_blind_attempts + num_known_input_blinds, num_issuance_blind_attempts + num_known_input_blinds);
                 if (!ret) {
                     // Possibly invalid blinding factor provided by user.
                     return -1;
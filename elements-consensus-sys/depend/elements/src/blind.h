@@ -47,15 +47,15 @@ bool VerifyConfidentialPair(const CConfidentialValue& conf_value, const CConfide
  */
 bool UnblindConfidentialPair(const CKey& blinding_key, const CConfidentialValue& conf_value, const CConfidentialAsset& conf_asset, const CConfidentialNonce& nonce_commitment, const CScript& committedScript, const std::vector<unsigned char>& vchRangeproof, CAmount& amount_out, uint256& blinding_factor_out, CAsset& asset_out, uint256& asset_blinding_factor_out);
 
-bool GenerateRangeproof(std::vector<unsigned char>& rangeproof, const std::vector<unsigned char*>& value_blindptrs, const uint256& nonce, const CAmount amount, const CScript& scriptPubKey, const rustsecp256k1_v0_4_1_pedersen_commitment& value_commit, const rustsecp256k1_v0_4_1_generator& gen, const CAsset& asset, std::vector<const unsigned char*>& asset_blindptrs);
+bool GenerateRangeproof(std::vector<unsigned char>& rangeproof, const std::vector<unsigned char*>& value_blindptrs, const uint256& nonce, const CAmount amount, const CScript& scriptPubKey, const rustsecp256k1zkp_v0_4_0_pedersen_commitment& value_commit, const rustsecp256k1zkp_v0_4_0_generator& gen, const CAsset& asset, std::vector<const unsigned char*>& asset_blindptrs);
 
-bool SurjectOutput(CTxOutWitness& txoutwit, const std::vector<rustsecp256k1_v0_4_1_fixed_asset_tag>& surjection_targets, const std::vector<rustsecp256k1_v0_4_1_generator>& target_asset_generators, const std::vector<uint256 >& target_asset_blinders, const std::vector<const unsigned char*> asset_blindptrs, const rustsecp256k1_v0_4_1_generator& output_asset_gen, const CAsset& asset);
+bool SurjectOutput(CTxOutWitness& txoutwit, const std::vector<rustsecp256k1_v0_4_1_fixed_asset_tag>& surjection_targets, const std::vector<rustsecp256k1zkp_v0_4_0_generator>& target_asset_generators, const std::vector<uint256 >& target_asset_blinders, const std::vector<const unsigned char*> asset_blindptrs, const rustsecp256k1zkp_v0_4_0_generator& output_asset_gen, const CAsset& asset);
 
 uint256 GenerateOutputRangeproofNonce(CTxOut& out, const CPubKey output_pubkey);
 
-void BlindAsset(CConfidentialAsset& conf_asset, rustsecp256k1_v0_4_1_generator& asset_gen, const CAsset& asset, const unsigned char* asset_blindptr);
+void BlindAsset(CConfidentialAsset& conf_asset, rustsecp256k1zkp_v0_4_0_generator& asset_gen, const CAsset& asset, const unsigned char* asset_blindptr);
 
-void CreateValueCommitment(CConfidentialValue& conf_value, rustsecp256k1_v0_4_1_pedersen_commitment& value_commit, const unsigned char* value_blindptr, const rustsecp256k1_v0_4_1_generator& asset_gen, const CAmount amount);
+void CreateValueCommitment(CConfidentialValue& conf_value, rustsecp256k1zkp_v0_4_0_pedersen_commitment& value_commit, const unsigned char* value_blindptr, const rustsecp256k1zkp_v0_4_0_generator& asset_gen, const CAmount amount);
 
 /* Returns the number of outputs that were successfully blinded.
  * In many cases a `0` can be fixed by adding an additional output.
@@ -34,7 +34,7 @@ std::string GetBlindingStatusError(const BlindingStatus& status)
 }
 
 // Create surjection proof
-bool CreateAssetSurjectionProof(std::vector<unsigned char>& output_proof, const std::vector<rustsecp256k1_v0_4_1_fixed_asset_tag>& fixed_input_tags, const std::vector<rustsecp256k1_v0_4_1_generator>& ephemeral_input_tags, const std::vector<uint256>& input_asset_blinders, const uint256& output_asset_blinder, const rustsecp256k1_v0_4_1_generator& output_asset_tag, const CAsset& asset)
+bool CreateAssetSurjectionProof(std::vector<unsigned char>& output_proof, const std::vector<rustsecp256k1_v0_4_1_fixed_asset_tag>& fixed_input_tags, const std::vector<rustsecp256k1zkp_v0_4_0_generator>& ephemeral_input_tags, const std::vector<uint256>& input_asset_blinders, const uint256& output_asset_blinder, const rustsecp256k1zkp_v0_4_0_generator& output_asset_tag, const CAsset& asset)
 {
     int ret;
     // 1 to 3 targets
@@ -42,24 +42,24 @@ bool CreateAssetSurjectionProof(std::vector<unsigned char>& output_proof, const
     unsigned char randseed[32];
     GetStrongRandBytes(randseed, 32);
     size_t input_index;
-    rustsecp256k1_v0_4_1_surjectionproof proof;
+    rustsecp256k1zkp_v0_4_0_surjectionproof proof;
     rustsecp256k1_v0_4_1_fixed_asset_tag fixed_output_tag;
     memcpy(&fixed_output_tag, asset.begin(), 32);
     // Find correlation between asset tag and listed input tags
-    if (rustsecp256k1_v0_4_1_surjectionproof_initialize(rustsecp256k1_v0_4_1_blind_context, &proof, &input_index, &fixed_input_tags[0], fixed_input_tags.size(), inputs_to_select, &fixed_output_tag, 100, randseed) == 0) {
+    if (rustsecp256k1zkp_v0_4_0_surjectionproof_initialize(rustsecp256k1_v0_4_1_blind_context, &proof, &input_index, &fixed_input_tags[0], fixed_input_tags.size(), inputs_to_select, &fixed_output_tag, 100, randseed) == 0) {
         return false;
     }
     // Using the input chosen, build proof
-    ret = rustsecp256k1_v0_4_1_surjectionproof_generate(rustsecp256k1_v0_4_1_blind_context, &proof, &ephemeral_input_tags[0], ephemeral_input_tags.size(), &output_asset_tag, input_index, input_asset_blinders[input_index].begin(), output_asset_blinder.begin());
+    ret = rustsecp256k1zkp_v0_4_0_surjectionproof_generate(rustsecp256k1_v0_4_1_blind_context, &proof, &ephemeral_input_tags[0], ephemeral_input_tags.size(), &output_asset_tag, input_index, input_asset_blinders[input_index].begin(), output_asset_blinder.begin());
     assert(ret == 1);
     // Double-check answer
-    ret = rustsecp256k1_v0_4_1_surjectionproof_verify(rustsecp256k1_v0_4_1_blind_context, &proof, &ephemeral_input_tags[0], ephemeral_input_tags.size(), &output_asset_tag);
+    ret = rustsecp256k1zkp_v0_4_0_surjectionproof_verify(rustsecp256k1_v0_4_1_blind_context, &proof, &ephemeral_input_tags[0], ephemeral_input_tags.size(), &output_asset_tag);
     assert(ret == 1);
 
     // Serialize into output witness structure
-    size_t output_len = rustsecp256k1_v0_4_1_surjectionproof_serialized_size(rustsecp256k1_v0_4_1_blind_context, &proof);
+    size_t output_len = rustsecp256k1zkp_v0_4_0_surjectionproof_serialized_size(rustsecp256k1_v0_4_1_blind_context, &proof);
     output_proof.resize(output_len);
-    rustsecp256k1_v0_4_1_surjectionproof_serialize(rustsecp256k1_v0_4_1_blind_context, &output_proof[0], &output_len, &proof);
+    rustsecp256k1zkp_v0_4_0_surjectionproof_serialize(rustsecp256k1_v0_4_1_blind_context, &output_proof[0], &output_len, &proof);
     assert(output_len == output_proof.size());
     return true;
 }
@@ -77,7 +77,7 @@ uint256 GenerateRangeproofECDHKey(CPubKey& ephemeral_pubkey, const CPubKey blind
     return nonce;
 }
 
-bool CreateValueRangeProof(std::vector<unsigned char>& rangeproof, const uint256& value_blinder, const uint256& nonce, const CAmount amount, const CScript& scriptPubKey, const rustsecp256k1_v0_4_1_pedersen_commitment& value_commit, const rustsecp256k1_v0_4_1_generator& gen, const CAsset& asset, const uint256& asset_blinder)
+bool CreateValueRangeProof(std::vector<unsigned char>& rangeproof, const uint256& value_blinder, const uint256& nonce, const CAmount amount, const CScript& scriptPubKey, const rustsecp256k1zkp_v0_4_0_pedersen_commitment& value_commit, const rustsecp256k1zkp_v0_4_0_generator& gen, const CAsset& asset, const uint256& asset_blinder)
 {
     // Prep range proof
     size_t rangeproof_len = 5134;
@@ -93,27 +93,27 @@ bool CreateValueRangeProof(std::vector<unsigned char>& rangeproof, const uint256
     int ct_bits = (int)gArgs.GetArg("-ct_bits", 52);
     // If min_value is 0, scriptPubKey must be unspendable
     uint64_t min_value = scriptPubKey.IsUnspendable() ? 0 : 1;
-    int res = rustsecp256k1_v0_4_1_rangeproof_sign(rustsecp256k1_v0_4_1_blind_context, rangeproof.data(), &rangeproof_len, min_value, &value_commit, value_blinder.begin(), nonce.begin(), ct_exponent, ct_bits, amount, asset_message, sizeof(asset_message), scriptPubKey.size() ? &scriptPubKey.front() : NULL, scriptPubKey.size(), &gen);
+    int res = rustsecp256k1zkp_v0_4_0_rangeproof_sign(rustsecp256k1_v0_4_1_blind_context, rangeproof.data(), &rangeproof_len, min_value, &value_commit, value_blinder.begin(), nonce.begin(), ct_exponent, ct_bits, amount, asset_message, sizeof(asset_message), scriptPubKey.size() ? &scriptPubKey.front() : NULL, scriptPubKey.size(), &gen);
     rangeproof.resize(rangeproof_len);
     return (res == 1);
 }
 
-void CreateAssetCommitment(CConfidentialAsset& conf_asset, rustsecp256k1_v0_4_1_generator& asset_gen, const CAsset& asset, const uint256& asset_blinder)
+void CreateAssetCommitment(CConfidentialAsset& conf_asset, rustsecp256k1zkp_v0_4_0_generator& asset_gen, const CAsset& asset, const uint256& asset_blinder)
 {
     conf_asset.vchCommitment.resize(CConfidentialAsset::nCommittedSize);
-    int ret = rustsecp256k1_v0_4_1_generator_generate_blinded(rustsecp256k1_v0_4_1_blind_context, &asset_gen, asset.begin(), asset_blinder.begin());
+    int ret = rustsecp256k1zkp_v0_4_0_generator_generate_blinded(rustsecp256k1_v0_4_1_blind_context, &asset_gen, asset.begin(), asset_blinder.begin());
     assert(ret == 1);
-    ret = rustsecp256k1_v0_4_1_generator_serialize(rustsecp256k1_v0_4_1_blind_context, conf_asset.vchCommitment.data(), &asset_gen);
+    ret = rustsecp256k1zkp_v0_4_0_generator_serialize(rustsecp256k1_v0_4_1_blind_context, conf_asset.vchCommitment.data(), &asset_gen);
     assert(ret == 1);
 }
 
-void CreateValueCommitment(CConfidentialValue& conf_value, rustsecp256k1_v0_4_1_pedersen_commitment& value_commit, const uint256& value_blinder, const rustsecp256k1_v0_4_1_generator& asset_gen, const CAmount amount)
+void CreateValueCommitment(CConfidentialValue& conf_value, rustsecp256k1zkp_v0_4_0_pedersen_commitment& value_commit, const uint256& value_blinder, const rustsecp256k1zkp_v0_4_0_generator& asset_gen, const CAmount amount)
 {
     int ret;
     conf_value.vchCommitment.resize(CConfidentialValue::nCommittedSize);
-    ret = rustsecp256k1_v0_4_1_pedersen_commit(rustsecp256k1_v0_4_1_blind_context, &value_commit, value_blinder.begin(), amount, &asset_gen);
+    ret = rustsecp256k1zkp_v0_4_0_pedersen_commit(rustsecp256k1_v0_4_1_blind_context, &value_commit, value_blinder.begin(), amount, &asset_gen);
     assert(ret == 1);
-    rustsecp256k1_v0_4_1_pedersen_commitment_serialize(rustsecp256k1_v0_4_1_blind_context, conf_value.vchCommitment.data(), &value_commit);
+    rustsecp256k1zkp_v0_4_0_pedersen_commitment_serialize(rustsecp256k1_v0_4_1_blind_context, conf_value.vchCommitment.data(), &value_commit);
     assert(conf_value.IsValid());
 }
 
@@ -193,7 +193,7 @@ BlindingStatus BlindPSBT(PartiallySignedTransaction& psbt, std::map<uint32_t, st
     }
 
     std::vector<rustsecp256k1_v0_4_1_fixed_asset_tag> fixed_input_tags; // Explicit Asset IDs for the inputs we know. Blinded for unknown ones
-    std::vector<rustsecp256k1_v0_4_1_generator> ephemeral_input_tags; // Blinded Asset IDs. Explicit Asset ID blinded with 0 if not blinded
+    std::vector<rustsecp256k1zkp_v0_4_0_generator> ephemeral_input_tags; // Blinded Asset IDs. Explicit Asset ID blinded with 0 if not blinded
     std::vector<uint256> input_asset_blinders; // Blinding factors for the input asset tags
 
     uint256 input_scalar;
@@ -210,12 +210,12 @@ BlindingStatus BlindPSBT(PartiallySignedTransaction& psbt, std::map<uint32_t, st
         ephemeral_input_tags.emplace_back();
         if (asset.IsExplicit()) {
             // Explicit asset
-            if (rustsecp256k1_v0_4_1_generator_generate(rustsecp256k1_v0_4_1_blind_context, &ephemeral_input_tags.back(), asset.GetAsset().begin()) != 1) {
+            if (rustsecp256k1zkp_v0_4_0_generator_generate(rustsecp256k1_v0_4_1_blind_context, &ephemeral_input_tags.back(), asset.GetAsset().begin()) != 1) {
                 return BlindingStatus::INVALID_ASSET;
             }
         } else {
             // Parse the asset commitment as a generator (because it is)
-            if (rustsecp256k1_v0_4_1_generator_parse(rustsecp256k1_v0_4_1_blind_context, &ephemeral_input_tags.back(), asset.vchCommitment.data()) != 1) {
+            if (rustsecp256k1zkp_v0_4_0_generator_parse(rustsecp256k1_v0_4_1_blind_context, &ephemeral_input_tags.back(), asset.vchCommitment.data()) != 1) {
                 return BlindingStatus::INVALID_ASSET_COMMITMENT;
             }
         }
@@ -255,7 +255,7 @@ BlindingStatus BlindPSBT(PartiallySignedTransaction& psbt, std::map<uint32_t, st
                 fixed_input_tags.emplace_back();
                 memcpy(fixed_input_tags.back().data, issuance_asset.begin(), 32);
                 ephemeral_input_tags.emplace_back();
-                if (rustsecp256k1_v0_4_1_generator_generate(rustsecp256k1_v0_4_1_blind_context, &ephemeral_input_tags.back(), issuance_asset.begin()) != 1) {
+                if (rustsecp256k1zkp_v0_4_0_generator_generate(rustsecp256k1_v0_4_1_blind_context, &ephemeral_input_tags.back(), issuance_asset.begin()) != 1) {
                     return BlindingStatus::INVALID_ASSET;
                 }
                 unsigned int iss_to_blind = 1; // Always do the first issuance blinding iteration for the issuance value
@@ -269,7 +269,7 @@ BlindingStatus BlindPSBT(PartiallySignedTransaction& psbt, std::map<uint32_t, st
                     fixed_input_tags.emplace_back();
                     memcpy(fixed_input_tags.back().data, reissuance_asset.begin(), 32);
                     ephemeral_input_tags.emplace_back();
-                    if (rustsecp256k1_v0_4_1_generator_generate(rustsecp256k1_v0_4_1_blind_context, &ephemeral_input_tags.back(), reissuance_asset.begin()) != 1) {
+                    if (rustsecp256k1zkp_v0_4_0_generator_generate(rustsecp256k1_v0_4_1_blind_context, &ephemeral_input_tags.back(), reissuance_asset.begin()) != 1) {
                         return BlindingStatus::INVALID_ASSET;
                     }
                     iss_to_blind++; // If we have a reissuance, do the second blinding iteration for the inflation keys
@@ -290,7 +290,7 @@ BlindingStatus BlindPSBT(PartiallySignedTransaction& psbt, std::map<uint32_t, st
                         // Create unblinded generator. Throw away everything except asset_gen
                         uint256 asset_blinder;
                         CConfidentialAsset conf_asset;
-                        rustsecp256k1_v0_4_1_generator asset_gen;
+                        rustsecp256k1zkp_v0_4_0_generator asset_gen;
                         CreateAssetCommitment(conf_asset, asset_gen, asset, asset_blinder);
                         input_asset_blinders.push_back(asset_blinder);
 
@@ -298,7 +298,7 @@ BlindingStatus BlindPSBT(PartiallySignedTransaction& psbt, std::map<uint32_t, st
                         if (!ComputeAndAddToScalarOffset(input_scalar, value, asset_blinder, value_blinder)) return BlindingStatus::SCALAR_UNABLE;
 
                         // Create value commitment
-                        rustsecp256k1_v0_4_1_pedersen_commitment value_commit;
+                        rustsecp256k1zkp_v0_4_0_pedersen_commitment value_commit;
                         CConfidentialValue conf_value;
                         CreateValueCommitment(conf_value, value_commit, value_blinder, asset_gen, value);
 
@@ -382,11 +382,11 @@ BlindingStatus BlindPSBT(PartiallySignedTransaction& psbt, std::map<uint32_t, st
         CAsset asset(output.m_asset);
 
         // Blind the asset ID
-        rustsecp256k1_v0_4_1_generator asset_generator;
+        rustsecp256k1zkp_v0_4_0_generator asset_generator;
         CreateAssetCommitment(asset_commitment, asset_generator, asset, asset_blinder);
 
         // Blind the value
-        rustsecp256k1_v0_4_1_pedersen_commitment value_commit;
+        rustsecp256k1zkp_v0_4_0_pedersen_commitment value_commit;
         CreateValueCommitment(value_commitment, value_commit, value_blinder, asset_generator, *output.amount);
 
         // Generate rangproof nonce
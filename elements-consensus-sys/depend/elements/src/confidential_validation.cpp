@@ -1,24 +1,33 @@
 
 #include <confidential_validation.h>
 #include <issuance.h>
-#include <pegins.h>
+#ifndef BUILD_BITCOIN_INTERNAL
 #include <script/sigcache.h>
-#include <blind.h>
+#endif
+#include <secp256k1_zkp_preallocated.h>
+
+#include <stdlib.h>
 
 namespace {
-static rustsecp256k1_v0_4_1_context *rustsecp256k1_v0_4_1_ctx_verify_amounts;
+static rustsecp256k1zkp_v0_4_0_context *rustsecp256k1zkp_v0_4_0_ctx_verify_amounts;
 
+// rust-secp256k1-zkp only exports the preallocated context functions, so the context lives in memory allocated here.
 class CSecp256k1Init {
+    void* m_memory;
 public:
     CSecp256k1Init() {
-        assert(rustsecp256k1_v0_4_1_ctx_verify_amounts == NULL);
-        rustsecp256k1_v0_4_1_ctx_verify_amounts = rustsecp256k1_v0_4_1_context_create(SECP256K1_CONTEXT_VERIFY | SECP256K1_CONTEXT_SIGN);
-        assert(rustsecp256k1_v0_4_1_ctx_verify_amounts != NULL);
+        assert(rustsecp256k1zkp_v0_4_0_ctx_verify_amounts == NULL);
+        const unsigned int flags = SECP256K1_CONTEXT_VERIFY | SECP256K1_CONTEXT_SIGN;
+        m_memory = malloc(rustsecp256k1zkp_v0_4_0_context_preallocated_size(flags));
+        assert(m_memory != NULL);
+        rustsecp256k1zkp_v0_4_0_ctx_verify_amounts = rustsecp256k1zkp_v0_4_0_context_preallocated_create(m_memory, flags);
+        assert(rustsecp256k1zkp_v0_4_0_ctx_verify_amounts != NULL);
     }
     ~CSecp256k1Init() {
-        assert(rustsecp256k1_v0_4_1_ctx_verify_amounts != NULL);
-        rustsecp256k1_v0_4_1_context_destroy(rustsecp256k1_v0_4_1_ctx_verify_amounts);
-        rustsecp256k1_v0_4_1_ctx_verify_amounts = NULL;
+        assert(rustsecp256k1zkp_v0_4_0_ctx_verify_amounts != NULL);
+        rustsecp256k1zkp_v0_4_0_context_preallocated_destroy(rustsecp256k1zkp_v0_4_0_ctx_verify_amounts);
+        rustsecp256k1zkp_v0_4_0_ctx_verify_amounts = NULL;
+        free(m_memory);
     }
 };
 static CSecp256k1Init instance_of_csecp256k1;
@@ -51,10 +60,51 @@ CAmountMap GetFeeMap(const CTransaction& tx) {
     return fee;
 }
 
+bool VerifyRangeProof(const std::vector<unsigned char>& vchRangeProof, const std::vector<unsigned char>& vchValueCommitment, const std::vector<unsigned char>& vchAssetCommitment, const CScript& scriptPubKey, const rustsecp256k1zkp_v0_4_0_context* ctx)
+{
+    if (vchRangeProof.size() == 0) {
+        return false;
+    }
+
+    uint64_t min_value, max_value;
+    rustsecp256k1zkp_v0_4_0_pedersen_commitment commit;
+    if (rustsecp256k1zkp_v0_4_0_pedersen_commitment_parse(ctx, &commit, &vchValueCommitment[0]) != 1)
+            return false;
+
+    rustsecp256k1zkp_v0_4_0_generator tag;
+    if (rustsecp256k1zkp_v0_4_0_generator_parse(ctx, &tag, &vchAssetCommitment[0]) != 1)
+        return false;
+
+    if (!rustsecp256k1zkp_v0_4_0_rangeproof_verify(ctx, &min_value, &max_value, &commit, vchRangeProof.data(), vchRangeProof.size(), scriptPubKey.size() ? &scriptPubKey.front() : NULL, scriptPubKey.size(), &tag)) {
+        return false;
+    }
+
+    // An rangeproof is not valid if the output is spendable but the minimum number
+    // is 0. This is to prevent people passing 0-value tokens around, or conjuring
+    // reissuance tokens from nothing then attempting to reissue an asset.
+    // ie reissuance doesn't require revealing value of reissuance output
+    // Issuances proofs are always "unspendable" as they commit to an empty script.
+    if (min_value == 0 && !scriptPubKey.IsUnspendable()) {
+        return false;
+    }
+
+    return true;
+}
+
+bool VerifySurjectionProof(const rustsecp256k1zkp_v0_4_0_surjectionproof& proof, const std::vector<rustsecp256k1zkp_v0_4_0_generator>& vTags, const rustsecp256k1zkp_v0_4_0_generator& gen, const rustsecp256k1zkp_v0_4_0_context* ctx)
+{
+    return rustsecp256k1zkp_v0_4_0_surjectionproof_verify(ctx, &proof, vTags.data(), vTags.size(), &gen) == 1;
+}
+
 bool CRangeCheck::operator()() {
     assert(val->IsCommitment());
 
-    if (!CachingRangeProofChecker(store).VerifyRangeProof(rangeproof, val->vchCommitment, assetCommitment, scriptPubKey, rustsecp256k1_v0_4_1_ctx_verify_amounts)) {
+#ifdef BUILD_BITCOIN_INTERNAL
+    // The consensus library has no rangeproof cache.
+    if (!VerifyRangeProof(rangeproof, val->vchCommitment, assetCommitment, scriptPubKey, rustsecp256k1zkp_v0_4_0_ctx_verify_amounts)) {
+#else
+    if (!CachingRangeProofChecker(store).VerifyRangeProof(rangeproof, val->vchCommitment, assetCommitment, scriptPubKey, rustsecp256k1zkp_v0_4_0_ctx_verify_amounts)) {
+#endif
         error = SCRIPT_ERR_RANGEPROOF;
         return false;
     }
@@ -63,7 +113,7 @@ bool CRangeCheck::operator()() {
 };
 
 bool CBalanceCheck::operator()() {
-    if (!rustsecp256k1_v0_4_1_pedersen_verify_tally(rustsecp256k1_v0_4_1_ctx_verify_amounts, vpCommitsIn.data(), vpCommitsIn.size(), vpCommitsOut.data(), vpCommitsOut.size())) {
+    if (!rustsecp256k1zkp_v0_4_0_pedersen_verify_tally(rustsecp256k1zkp_v0_4_0_ctx_verify_amounts, vpCommitsIn.data(), vpCommitsIn.size(), vpCommitsOut.data(), vpCommitsOut.size())) {
         error = SCRIPT_ERR_PEDERSEN_TALLY;
         return false;
     }
@@ -72,7 +122,12 @@ bool CBalanceCheck::operator()() {
 }
 
 bool CSurjectionCheck::operator()() {
-    return CachingSurjectionProofChecker(store).VerifySurjectionProof(proof, vTags, gen, rustsecp256k1_v0_4_1_ctx_verify_amounts, wtxid);
+#ifdef BUILD_BITCOIN_INTERNAL
+    // The consensus library has no surjection proof cache.
+    return VerifySurjectionProof(proof, vTags, gen, rustsecp256k1zkp_v0_4_0_ctx_verify_amounts);
+#else
+    return CachingSurjectionProofChecker(store).VerifySurjectionProof(proof, vTags, gen, rustsecp256k1zkp_v0_4_0_ctx_verify_amounts, wtxid);
+#endif
 }
 
 // Destroys the check in the case of no queue, or passes its ownership to the queue.
@@ -88,7 +143,7 @@ ScriptError QueueCheck(std::vector<CCheck*>* queue, CCheck* check) {
 }
 
 // Helper function for VerifyAmount(), not exported
-static bool VerifyIssuanceAmount(rustsecp256k1_v0_4_1_pedersen_commitment& value_commit, rustsecp256k1_v0_4_1_generator& asset_gen,
+static bool VerifyIssuanceAmount(rustsecp256k1zkp_v0_4_0_pedersen_commitment& value_commit, rustsecp256k1zkp_v0_4_0_generator& asset_gen,
                     const CAsset& asset, const CConfidentialValue& value, const std::vector<unsigned char>& rangeproof,
                     std::vector<CCheck*>* checks, const bool store_result)
 {
@@ -97,7 +152,7 @@ static bool VerifyIssuanceAmount(rustsecp256k1_v0_4_1_pedersen_commitment& value
     memset(explicit_blinds, 0, sizeof(explicit_blinds));
     int ret;
 
-    ret = rustsecp256k1_v0_4_1_generator_generate(rustsecp256k1_v0_4_1_ctx_verify_amounts, &asset_gen, asset.begin());
+    ret = rustsecp256k1zkp_v0_4_0_generator_generate(rustsecp256k1zkp_v0_4_0_ctx_verify_amounts, &asset_gen, asset.begin());
     assert(ret == 1);
 
     // Build value commitment
@@ -110,18 +165,18 @@ static bool VerifyIssuanceAmount(rustsecp256k1_v0_4_1_pedersen_commitment& value
         }
 
 
-        ret = rustsecp256k1_v0_4_1_pedersen_commit(rustsecp256k1_v0_4_1_ctx_verify_amounts, &value_commit, explicit_blinds, value.GetAmount(), &asset_gen);
-        // The explicit_blinds are all 0, and the amount is not 0. So rustsecp256k1_v0_4_1_pedersen_commit does not fail.
+        ret = rustsecp256k1zkp_v0_4_0_pedersen_commit(rustsecp256k1zkp_v0_4_0_ctx_verify_amounts, &value_commit, explicit_blinds, value.GetAmount(), &asset_gen);
+        // The explicit_blinds are all 0, and the amount is not 0. So rustsecp256k1zkp_v0_4_0_pedersen_commit does not fail.
         assert(ret == 1);
     } else if (value.IsCommitment()) {
         // Verify range proof
         std::vector<unsigned char> vchAssetCommitment(CConfidentialAsset::nExplicitSize);
-        rustsecp256k1_v0_4_1_generator_serialize(rustsecp256k1_v0_4_1_ctx_verify_amounts, vchAssetCommitment.data(), &asset_gen);
+        rustsecp256k1zkp_v0_4_0_generator_serialize(rustsecp256k1zkp_v0_4_0_ctx_verify_amounts, vchAssetCommitment.data(), &asset_gen);
         if (QueueCheck(checks, new CRangeCheck(&value, rangeproof, vchAssetCommitment, CScript(), store_result)) != SCRIPT_ERR_OK) {
             return false;
         }
 
-        if (rustsecp256k1_v0_4_1_pedersen_commitment_parse(rustsecp256k1_v0_4_1_ctx_verify_amounts, &value_commit, value.vchCommitment.data()) != 1) {
+        if (rustsecp256k1zkp_v0_4_0_pedersen_commitment_parse(rustsecp256k1zkp_v0_4_0_ctx_verify_amounts, &value_commit, value.vchCommitment.data()) != 1) {
             return false;
         }
     } else {
@@ -131,17 +186,24 @@ static bool VerifyIssuanceAmount(rustsecp256k1_v0_4_1_pedersen_commitment& value
     return true;
 }
 
-bool VerifyAmounts(const std::vector<CTxOut>& inputs, const CTransaction& tx, std::vector<CCheck*>* checks, const bool store_result) {
+bool VerifyAmounts(const std::vector<CTxOut>& inputs, const CTransaction& tx, std::vector<CCheck*>* checks, const bool store_result, AmountsError* error, size_t* error_index) {
     assert(!tx.IsCoinBase());
     assert(inputs.size() == tx.vin.size());
 
-    std::vector<rustsecp256k1_v0_4_1_pedersen_commitment> vData;
-    std::vector<rustsecp256k1_v0_4_1_pedersen_commitment *> vpCommitsIn, vpCommitsOut;
+    const auto fail = [&](AmountsError reason, size_t index) {
+        if (error) *error = reason;
+        if (error_index) *error_index = index;
+        return false;
+    };
+    if (error) *error = AmountsError::OK;
+
+    std::vector<rustsecp256k1zkp_v0_4_0_pedersen_commitment> vData;
+    std::vector<rustsecp256k1zkp_v0_4_0_pedersen_commitment *> vpCommitsIn, vpCommitsOut;
 
     vData.reserve((tx.vin.size() + tx.vout.size() + GetNumIssuances(tx)));
-    rustsecp256k1_v0_4_1_pedersen_commitment *p = vData.data();
-    rustsecp256k1_v0_4_1_pedersen_commitment commit;
-    rustsecp256k1_v0_4_1_generator gen;
+    rustsecp256k1zkp_v0_4_0_pedersen_commitment *p = vData.data();
+    rustsecp256k1zkp_v0_4_0_pedersen_commitment commit;
+    rustsecp256k1zkp_v0_4_0_generator gen;
     // This is used to add in the explicit values
     unsigned char explicit_blinds[32] = {0};
     int ret;
@@ -152,7 +214,7 @@ bool VerifyAmounts(const std::vector<CTxOut>& inputs, const CTransaction& tx, st
     // Proofs must be constructed with the list being in
     // order of input and non-null issuance pseudo-inputs, with
     // input first, asset issuance second, reissuance token third.
-    std::vector<rustsecp256k1_v0_4_1_generator> target_generators;
+    std::vector<rustsecp256k1zkp_v0_4_0_generator> target_generators;
     target_generators.reserve(tx.vin.size() + GetNumIssuances(tx));
 
     // Tally up value commitments, check balance
@@ -161,34 +223,34 @@ bool VerifyAmounts(const std::vector<CTxOut>& inputs, const CTransaction& tx, st
         const CConfidentialAsset& asset = inputs[i].nAsset;
 
         if (val.IsNull() || asset.IsNull())
-            return false;
+            return fail(AmountsError::INPUT, i);
 
         if (asset.IsExplicit()) {
-            ret = rustsecp256k1_v0_4_1_generator_generate(rustsecp256k1_v0_4_1_ctx_verify_amounts, &gen, asset.GetAsset().begin());
+            ret = rustsecp256k1zkp_v0_4_0_generator_generate(rustsecp256k1zkp_v0_4_0_ctx_verify_amounts, &gen, asset.GetAsset().begin());
             assert(ret != 0);
         }
         else if (asset.IsCommitment()) {
-            if (rustsecp256k1_v0_4_1_generator_parse(rustsecp256k1_v0_4_1_ctx_verify_amounts, &gen, &asset.vchCommitment[0]) != 1)
-                return false;
+            if (rustsecp256k1zkp_v0_4_0_generator_parse(rustsecp256k1zkp_v0_4_0_ctx_verify_amounts, &gen, &asset.vchCommitment[0]) != 1)
+                return fail(AmountsError::INPUT, i);
         }
         else {
-            return false;
+            return fail(AmountsError::INPUT, i);
         }
 
         target_generators.push_back(gen);
 
         if (val.IsExplicit()) {
             if (!MoneyRange(val.GetAmount()))
-                return false;
+                return fail(AmountsError::INPUT, i);
 
             // Fails if val.GetAmount() == 0
-            if (rustsecp256k1_v0_4_1_pedersen_commit(rustsecp256k1_v0_4_1_ctx_verify_amounts, &commit, explicit_blinds, val.GetAmount(), &gen) != 1)
-                return false;
+            if (rustsecp256k1zkp_v0_4_0_pedersen_commit(rustsecp256k1zkp_v0_4_0_ctx_verify_amounts, &commit, explicit_blinds, val.GetAmount(), &gen) != 1)
+                return fail(AmountsError::INPUT, i);
         } else if (val.IsCommitment()) {
-            if (rustsecp256k1_v0_4_1_pedersen_commitment_parse(rustsecp256k1_v0_4_1_ctx_verify_amounts, &commit, &val.vchCommitment[0]) != 1)
-                return false;
+            if (rustsecp256k1zkp_v0_4_0_pedersen_commitment_parse(rustsecp256k1zkp_v0_4_0_ctx_verify_amounts, &commit, &val.vchCommitment[0]) != 1)
+                return fail(AmountsError::INPUT, i);
         } else {
-                return false;
+                return fail(AmountsError::INPUT, i);
         }
 
         vData.push_back(commit);
@@ -227,38 +289,38 @@ bool VerifyAmounts(const std::vector<CTxOut>& inputs, const CTransaction& tx, st
 
             // Must check that prevout is the blinded issuance token
             // prevout's asset tag = assetTokenID + assetBlindingNonce
-            if (rustsecp256k1_v0_4_1_generator_generate_blinded(rustsecp256k1_v0_4_1_ctx_verify_amounts, &gen, assetTokenID.begin(), issuance.assetBlindingNonce.begin()) != 1) {
-                return false;
+            if (rustsecp256k1zkp_v0_4_0_generator_generate_blinded(rustsecp256k1zkp_v0_4_0_ctx_verify_amounts, &gen, assetTokenID.begin(), issuance.assetBlindingNonce.begin()) != 1) {
+                return fail(AmountsError::ISSUANCE, i);
             }
             // Serialize the generator for direct comparison
             unsigned char derived_generator[33];
-            rustsecp256k1_v0_4_1_generator_serialize(rustsecp256k1_v0_4_1_ctx_verify_amounts, derived_generator, &gen);
+            rustsecp256k1zkp_v0_4_0_generator_serialize(rustsecp256k1zkp_v0_4_0_ctx_verify_amounts, derived_generator, &gen);
 
             // Belt-and-suspenders: Check that asset commitment from issuance input is correct size
             if (asset.vchCommitment.size() != sizeof(derived_generator)) {
-                return false;
+                return fail(AmountsError::ISSUANCE, i);
             }
 
             // We have already checked the outputs' generator commitment for general validity, so directly compare serialized bytes
             if (memcmp(asset.vchCommitment.data(), derived_generator, sizeof(derived_generator))) {
-                return false;
+                return fail(AmountsError::ISSUANCE, i);
             }
         }
 
         // Process issuance of asset
 
         if (!issuance.nAmount.IsValid()) {
-            return false;
+            return fail(AmountsError::ISSUANCE, i);
         }
         if (!issuance.nAmount.IsNull()) {
             // Note: This check disallows issuances in transactions with *no* witness data.
             // This can be relaxed in a future update as a HF by passing in an empty rangeproof
             // to `VerifyIssuanceAmount` instead.
             if (i >= tx.witness.vtxinwit.size()) {
-                return false;
+                return fail(AmountsError::ISSUANCE, i);
             }
             if (!VerifyIssuanceAmount(commit, gen, assetID, issuance.nAmount, tx.witness.vtxinwit[i].vchIssuanceAmountRangeproof, checks, store_result)) {
-                return false;
+                return fail(AmountsError::ISSUANCE, i);
             }
             target_generators.push_back(gen);
             vData.push_back(commit);
@@ -269,22 +331,22 @@ bool VerifyAmounts(const std::vector<CTxOut>& inputs, const CTransaction& tx, st
         // Process issuance of reissuance tokens
 
         if (!issuance.nInflationKeys.IsValid()) {
-            return false;
+            return fail(AmountsError::ISSUANCE, i);
         }
         if (!issuance.nInflationKeys.IsNull()) {
             // Only initial issuance can have reissuance tokens
             if (!issuance.assetBlindingNonce.IsNull()) {
-                return false;
+                return fail(AmountsError::ISSUANCE, i);
             }
 
             // Note: This check disallows issuances in transactions with *no* witness data.
             // This can be relaxed in a future update as a HF by passing in an empty rangeproof
             // to `VerifyIssuanceAmount` instead.
             if (i >= tx.witness.vtxinwit.size()) {
-                return false;
+                return fail(AmountsError::ISSUANCE, i);
             }
             if (!VerifyIssuanceAmount(commit, gen, assetTokenID, issuance.nInflationKeys, tx.witness.vtxinwit[i].vchInflationKeysRangeproof, checks, store_result)) {
-                return false;
+                return fail(AmountsError::ISSUANCE, i);
             }
             target_generators.push_back(gen);
             vData.push_back(commit);
@@ -298,27 +360,27 @@ bool VerifyAmounts(const std::vector<CTxOut>& inputs, const CTransaction& tx, st
         const CConfidentialValue& val = tx.vout[i].nValue;
         const CConfidentialAsset& asset = tx.vout[i].nAsset;
         if (!asset.IsValid())
-            return false;
+            return fail(AmountsError::OUTPUT, i);
         if (!val.IsValid())
-            return false;
+            return fail(AmountsError::OUTPUT, i);
         if (!tx.vout[i].nNonce.IsValid())
-            return false;
+            return fail(AmountsError::OUTPUT, i);
 
         if (asset.IsExplicit()) {
-            ret = rustsecp256k1_v0_4_1_generator_generate(rustsecp256k1_v0_4_1_ctx_verify_amounts, &gen, asset.GetAsset().begin());
+            ret = rustsecp256k1zkp_v0_4_0_generator_generate(rustsecp256k1zkp_v0_4_0_ctx_verify_amounts, &gen, asset.GetAsset().begin());
             assert(ret != 0);
         }
         else if (asset.IsCommitment()) {
-            if (rustsecp256k1_v0_4_1_generator_parse(rustsecp256k1_v0_4_1_ctx_verify_amounts, &gen, &asset.vchCommitment[0]) != 1)
-                return false;
+            if (rustsecp256k1zkp_v0_4_0_generator_parse(rustsecp256k1zkp_v0_4_0_ctx_verify_amounts, &gen, &asset.vchCommitment[0]) != 1)
+                return fail(AmountsError::OUTPUT, i);
         }
         else {
-            return false;
+            return fail(AmountsError::OUTPUT, i);
         }
 
         if (val.IsExplicit()) {
             if (!MoneyRange(val.GetAmount()))
-                return false;
+                return fail(AmountsError::OUTPUT, i);
 
             if (val.GetAmount() == 0) {
                 if (tx.vout[i].scriptPubKey.IsUnspendable()) {
@@ -326,19 +388,19 @@ bool VerifyAmounts(const std::vector<CTxOut>& inputs, const CTransaction& tx, st
                 } else {
                     // No spendable 0-value outputs
                     // Reason: A spendable output of 0 reissuance tokens would allow reissuance without reissuance tokens.
-                    return false;
+                    return fail(AmountsError::OUTPUT, i);
                 }
             }
 
-            ret = rustsecp256k1_v0_4_1_pedersen_commit(rustsecp256k1_v0_4_1_ctx_verify_amounts, &commit, explicit_blinds, val.GetAmount(), &gen);
-            // The explicit_blinds are all 0, and the amount is not 0. So rustsecp256k1_v0_4_1_pedersen_commit does not fail.
+            ret = rustsecp256k1zkp_v0_4_0_pedersen_commit(rustsecp256k1zkp_v0_4_0_ctx_verify_amounts, &commit, explicit_blinds, val.GetAmount(), &gen);
+            // The explicit_blinds are all 0, and the amount is not 0. So rustsecp256k1zkp_v0_4_0_pedersen_commit does not fail.
             assert(ret == 1);
         }
         else if (val.IsCommitment()) {
-            if (rustsecp256k1_v0_4_1_pedersen_commitment_parse(rustsecp256k1_v0_4_1_ctx_verify_amounts, &commit, &val.vchCommitment[0]) != 1)
-                return false;
+            if (rustsecp256k1zkp_v0_4_0_pedersen_commitment_parse(rustsecp256k1zkp_v0_4_0_ctx_verify_amounts, &commit, &val.vchCommitment[0]) != 1)
+                return fail(AmountsError::OUTPUT, i);
         } else {
-            return false;
+            return fail(AmountsError::OUTPUT, i);
         }
 
         vData.push_back(commit);
@@ -348,7 +410,7 @@ bool VerifyAmounts(const std::vector<CTxOut>& inputs, const CTransaction& tx, st
 
     // Check balance
     if (QueueCheck(checks, new CBalanceCheck(vData, vpCommitsIn, vpCommitsOut)) != SCRIPT_ERR_OK) {
-        return false;
+        return fail(AmountsError::BALANCE, 0);
     }
 
     // Range proofs
@@ -360,19 +422,19 @@ bool VerifyAmounts(const std::vector<CTxOut>& inputs, const CTransaction& tx, st
         if (val.IsExplicit())
         {
             if (ptxoutwit && !ptxoutwit->vchRangeproof.empty())
-                return false;
+                return fail(AmountsError::RANGEPROOF, i);
             continue;
         }
         if (asset.IsExplicit()) {
-            int ret = rustsecp256k1_v0_4_1_generator_generate(rustsecp256k1_v0_4_1_ctx_verify_amounts, &gen, asset.GetAsset().begin());
+            int ret = rustsecp256k1zkp_v0_4_0_generator_generate(rustsecp256k1zkp_v0_4_0_ctx_verify_amounts, &gen, asset.GetAsset().begin());
             assert(ret != 0);
-            rustsecp256k1_v0_4_1_generator_serialize(rustsecp256k1_v0_4_1_ctx_verify_amounts, &vchAssetCommitment[0], &gen);
+            rustsecp256k1zkp_v0_4_0_generator_serialize(rustsecp256k1zkp_v0_4_0_ctx_verify_amounts, &vchAssetCommitment[0], &gen);
         }
         if (!ptxoutwit) {
-            return false;
+            return fail(AmountsError::RANGEPROOF, i);
         }
         if (QueueCheck(checks, new CRangeCheck(&val, ptxoutwit->vchRangeproof, vchAssetCommitment, tx.vout[i].scriptPubKey, store_result)) != SCRIPT_ERR_OK) {
-            return false;
+            return fail(AmountsError::RANGEPROOF, i);
         }
     }
 
@@ -384,27 +446,196 @@ bool VerifyAmounts(const std::vector<CTxOut>& inputs, const CTransaction& tx, st
         // No need for surjection proof
         if (asset.IsExplicit()) {
             if (ptxoutwit && !ptxoutwit->vchSurjectionproof.empty()) {
-                return false;
+                return fail(AmountsError::SURJECTIONPROOF, i);
             }
             continue;
         }
         if (!ptxoutwit)
+            return fail(AmountsError::SURJECTIONPROOF, i);
+        if (rustsecp256k1zkp_v0_4_0_generator_parse(rustsecp256k1zkp_v0_4_0_ctx_verify_amounts, &gen, &asset.vchCommitment[0]) != 1)
+            return fail(AmountsError::SURJECTIONPROOF, i);
+
+        rustsecp256k1zkp_v0_4_0_surjectionproof proof;
+        if (rustsecp256k1zkp_v0_4_0_surjectionproof_parse(rustsecp256k1zkp_v0_4_0_ctx_verify_amounts, &proof, &ptxoutwit->vchSurjectionproof[0], ptxoutwit->vchSurjectionproof.size()) != 1)
+            return fail(AmountsError::SURJECTIONPROOF, i);
+
+        if (QueueCheck(checks, new CSurjectionCheck(proof, target_generators, gen, wtxid, store_result)) != SCRIPT_ERR_OK) {
+            return fail(AmountsError::SURJECTIONPROOF, i);
+        }
+    }
+
+    return true;
+}
+
+bool VerifyIssuance(const CTxOut& spent_output, const CTransaction& tx, size_t nIn, uint256& entropy, CAsset& asset, CAsset& token, IssuanceError& error)
+{
+    assert(nIn < tx.vin.size());
+    const CAssetIssuance& issuance = tx.vin[nIn].assetIssuance;
+    assert(!issuance.IsNull());
+
+    rustsecp256k1zkp_v0_4_0_pedersen_commitment commit;
+    rustsecp256k1zkp_v0_4_0_generator gen;
+    error = IssuanceError::OK;
+
+    // The same checks in the same order as the issuance pseudo-inputs of VerifyAmounts()
+    if (issuance.assetBlindingNonce.IsNull()) {
+        GenerateAssetEntropy(entropy, tx.vin[nIn].prevout, issuance.assetEntropy);
+    } else {
+        // hashAssetIdentifier doubles as the entropy on reissuance
+        entropy = issuance.assetEntropy;
+    }
+    CalculateAsset(asset, entropy);
+    // Null nAmount is considered explicit 0, so just check for commitment
+    CalculateReissuanceToken(token, entropy, issuance.nAmount.IsCommitment());
+
+    if (!issuance.assetBlindingNonce.IsNull()) {
+        // The spent output's asset tag must be the reissuance token blinded with assetBlindingNonce
+        if (rustsecp256k1zkp_v0_4_0_generator_generate_blinded(rustsecp256k1zkp_v0_4_0_ctx_verify_amounts, &gen, token.begin(), issuance.assetBlindingNonce.begin()) != 1) {
+            error = IssuanceError::REISSUANCE_TOKEN;
+            return false;
+        }
+        unsigned char derived_generator[33];
+        rustsecp256k1zkp_v0_4_0_generator_serialize(rustsecp256k1zkp_v0_4_0_ctx_verify_amounts, derived_generator, &gen);
+        if (spent_output.nAsset.vchCommitment.size() != sizeof(derived_generator) ||
+            memcmp(spent_output.nAsset.vchCommitment.data(), derived_generator, sizeof(derived_generator))) {
+            error = IssuanceError::REISSUANCE_TOKEN;
+            return false;
+        }
+    }
+
+    if (!issuance.nAmount.IsValid()) {
+        error = IssuanceError::AMOUNT;
+        return false;
+    }
+    if (!issuance.nAmount.IsNull()) {
+        if (nIn >= tx.witness.vtxinwit.size()) {
+            error = IssuanceError::WITNESS;
             return false;
-        if (rustsecp256k1_v0_4_1_generator_parse(rustsecp256k1_v0_4_1_ctx_verify_amounts, &gen, &asset.vchCommitment[0]) != 1)
+        }
+        if (!VerifyIssuanceAmount(commit, gen, asset, issuance.nAmount, tx.witness.vtxinwit[nIn].vchIssuanceAmountRangeproof, nullptr, false)) {
+            error = issuance.nAmount.IsCommitment() ? IssuanceError::AMOUNT_RANGEPROOF : IssuanceError::AMOUNT;
             return false;
+        }
+    }
 
-        rustsecp256k1_v0_4_1_surjectionproof proof;
-        if (rustsecp256k1_v0_4_1_surjectionproof_parse(rustsecp256k1_v0_4_1_ctx_verify_amounts, &proof, &ptxoutwit->vchSurjectionproof[0], ptxoutwit->vchSurjectionproof.size()) != 1)
+    if (!issuance.nInflationKeys.IsValid()) {
+        error = IssuanceError::INFLATION_KEYS;
+        return false;
+    }
+    if (!issuance.nInflationKeys.IsNull()) {
+        if (!issuance.assetBlindingNonce.IsNull()) {
+            error = IssuanceError::REISSUANCE_INFLATION_KEYS;
+            return false;
+        }
+        if (nIn >= tx.witness.vtxinwit.size()) {
+            error = IssuanceError::WITNESS;
+            return false;
+        }
+        if (!VerifyIssuanceAmount(commit, gen, token, issuance.nInflationKeys, tx.witness.vtxinwit[nIn].vchInflationKeysRangeproof, nullptr, false)) {
+            error = issuance.nInflationKeys.IsCommitment() ? IssuanceError::INFLATION_KEYS_RANGEPROOF : IssuanceError::INFLATION_KEYS;
             return false;
+        }
+    }
 
-        if (QueueCheck(checks, new CSurjectionCheck(proof, target_generators, gen, wtxid, store_result)) != SCRIPT_ERR_OK) {
+    return true;
+}
+
+// Helper function for VerifyOutputProofs(), not exported
+// Collects the assets of the inputs and their non-null issuance pseudo-inputs in the order
+// surjection proofs are constructed against, as done by VerifyAmounts().
+static bool GetSurjectionTargets(const std::vector<CTxOut>& inputs, const CTransaction& tx, std::vector<rustsecp256k1zkp_v0_4_0_generator>& target_generators)
+{
+    rustsecp256k1zkp_v0_4_0_generator gen;
+    int ret;
+
+    for (size_t i = 0; i < tx.vin.size(); ++i) {
+        const CConfidentialAsset& asset = inputs[i].nAsset;
+        if (asset.IsExplicit()) {
+            ret = rustsecp256k1zkp_v0_4_0_generator_generate(rustsecp256k1zkp_v0_4_0_ctx_verify_amounts, &gen, asset.GetAsset().begin());
+            assert(ret != 0);
+        } else if (asset.IsCommitment()) {
+            if (rustsecp256k1zkp_v0_4_0_generator_parse(rustsecp256k1zkp_v0_4_0_ctx_verify_amounts, &gen, &asset.vchCommitment[0]) != 1)
+                return false;
+        } else {
             return false;
         }
+        target_generators.push_back(gen);
+
+        const CAssetIssuance& issuance = tx.vin[i].assetIssuance;
+        if (issuance.IsNull()) {
+            continue;
+        }
+
+        uint256 entropy;
+        if (issuance.assetBlindingNonce.IsNull()) {
+            GenerateAssetEntropy(entropy, tx.vin[i].prevout, issuance.assetEntropy);
+        } else {
+            // hashAssetIdentifier doubles as the entropy on reissuance
+            entropy = issuance.assetEntropy;
+        }
+        CAsset assetID;
+        CAsset assetTokenID;
+        CalculateAsset(assetID, entropy);
+        CalculateReissuanceToken(assetTokenID, entropy, issuance.nAmount.IsCommitment());
+
+        if (!issuance.nAmount.IsNull()) {
+            ret = rustsecp256k1zkp_v0_4_0_generator_generate(rustsecp256k1zkp_v0_4_0_ctx_verify_amounts, &gen, assetID.begin());
+            assert(ret != 0);
+            target_generators.push_back(gen);
+        }
+        if (!issuance.nInflationKeys.IsNull()) {
+            ret = rustsecp256k1zkp_v0_4_0_generator_generate(rustsecp256k1zkp_v0_4_0_ctx_verify_amounts, &gen, assetTokenID.begin());
+            assert(ret != 0);
+            target_generators.push_back(gen);
+        }
     }
 
     return true;
 }
 
+void VerifyOutputProofs(const std::vector<CTxOut>& inputs, const CTransaction& tx, std::vector<bool>& valid_rangeproofs, std::vector<bool>& valid_surjectionproofs) {
+    assert(!tx.IsCoinBase());
+    assert(inputs.size() == tx.vin.size());
+
+    valid_rangeproofs.assign(tx.vout.size(), false);
+    valid_surjectionproofs.assign(tx.vout.size(), false);
+
+    std::vector<rustsecp256k1zkp_v0_4_0_generator> target_generators;
+    target_generators.reserve(tx.vin.size() + GetNumIssuances(tx));
+    const bool valid_targets = GetSurjectionTargets(inputs, tx, target_generators);
+
+    rustsecp256k1zkp_v0_4_0_generator gen;
+    for (size_t i = 0; i < tx.vout.size(); i++) {
+        const CConfidentialValue& val = tx.vout[i].nValue;
+        const CConfidentialAsset& asset = tx.vout[i].nAsset;
+        const CTxOutWitness* ptxoutwit = tx.witness.vtxoutwit.size() <= i? NULL: &tx.witness.vtxoutwit[i];
+
+        // Outputs without a value commitment must not carry a rangeproof
+        if (!val.IsCommitment()) {
+            valid_rangeproofs[i] = !ptxoutwit || ptxoutwit->vchRangeproof.empty();
+        } else if (ptxoutwit && (asset.IsExplicit() || asset.IsCommitment())) {
+            std::vector<unsigned char> vchAssetCommitment = asset.vchCommitment;
+            if (asset.IsExplicit()) {
+                int ret = rustsecp256k1zkp_v0_4_0_generator_generate(rustsecp256k1zkp_v0_4_0_ctx_verify_amounts, &gen, asset.GetAsset().begin());
+                assert(ret != 0);
+                rustsecp256k1zkp_v0_4_0_generator_serialize(rustsecp256k1zkp_v0_4_0_ctx_verify_amounts, &vchAssetCommitment[0], &gen);
+            }
+            valid_rangeproofs[i] = VerifyRangeProof(ptxoutwit->vchRangeproof, val.vchCommitment, vchAssetCommitment, tx.vout[i].scriptPubKey, rustsecp256k1zkp_v0_4_0_ctx_verify_amounts);
+        }
+
+        // Outputs without an asset commitment must not carry a surjection proof
+        if (!asset.IsCommitment()) {
+            valid_surjectionproofs[i] = !ptxoutwit || ptxoutwit->vchSurjectionproof.empty();
+        } else if (valid_targets && ptxoutwit && !ptxoutwit->vchSurjectionproof.empty()) {
+            rustsecp256k1zkp_v0_4_0_surjectionproof proof;
+            valid_surjectionproofs[i] =
+                rustsecp256k1zkp_v0_4_0_generator_parse(rustsecp256k1zkp_v0_4_0_ctx_verify_amounts, &gen, &asset.vchCommitment[0]) == 1 &&
+                rustsecp256k1zkp_v0_4_0_surjectionproof_parse(rustsecp256k1zkp_v0_4_0_ctx_verify_amounts, &proof, &ptxoutwit->vchSurjectionproof[0], ptxoutwit->vchSurjectionproof.size()) == 1 &&
+                VerifySurjectionProof(proof, target_generators, gen, rustsecp256k1zkp_v0_4_0_ctx_verify_amounts);
+        }
+    }
+}
+
 bool VerifyCoinbaseAmount(const CTransaction& tx, const CAmountMap& mapFees) {
     assert(tx.IsCoinBase());
 
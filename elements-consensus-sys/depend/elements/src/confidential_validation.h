@@ -60,11 +60,11 @@ public:
 class CBalanceCheck : public CCheck
 {
 private:
-    std::vector<rustsecp256k1_v0_4_1_pedersen_commitment> vData;
-    std::vector<rustsecp256k1_v0_4_1_pedersen_commitment *> vpCommitsIn, vpCommitsOut;
+    std::vector<rustsecp256k1zkp_v0_4_0_pedersen_commitment> vData;
+    std::vector<rustsecp256k1zkp_v0_4_0_pedersen_commitment *> vpCommitsIn, vpCommitsOut;
 
 public:
-    CBalanceCheck(std::vector<rustsecp256k1_v0_4_1_pedersen_commitment>& vData_, std::vector<rustsecp256k1_v0_4_1_pedersen_commitment*>& vpCommitsIn_, std::vector<rustsecp256k1_v0_4_1_pedersen_commitment*>& vpCommitsOut_)  {
+    CBalanceCheck(std::vector<rustsecp256k1zkp_v0_4_0_pedersen_commitment>& vData_, std::vector<rustsecp256k1zkp_v0_4_0_pedersen_commitment*>& vpCommitsIn_, std::vector<rustsecp256k1zkp_v0_4_0_pedersen_commitment*>& vpCommitsOut_)  {
         vData.swap(vData_);
         vpCommitsIn.swap(vpCommitsIn_);
         vpCommitsOut.swap(vpCommitsOut_);
@@ -76,20 +76,68 @@ public:
 class CSurjectionCheck : public CCheck
 {
 private:
-    rustsecp256k1_v0_4_1_surjectionproof proof;
-    std::vector<rustsecp256k1_v0_4_1_generator> vTags;
-    rustsecp256k1_v0_4_1_generator gen;
+    rustsecp256k1zkp_v0_4_0_surjectionproof proof;
+    std::vector<rustsecp256k1zkp_v0_4_0_generator> vTags;
+    rustsecp256k1zkp_v0_4_0_generator gen;
     uint256 wtxid;
     const bool store;
 public:
-    CSurjectionCheck(rustsecp256k1_v0_4_1_surjectionproof& proof_in, std::vector<rustsecp256k1_v0_4_1_generator>& tags_in, rustsecp256k1_v0_4_1_generator& gen_in, uint256& wtxid_in, const bool store_in) : proof(proof_in), vTags(tags_in), gen(gen_in), wtxid(wtxid_in), store(store_in) {}
+    CSurjectionCheck(rustsecp256k1zkp_v0_4_0_surjectionproof& proof_in, std::vector<rustsecp256k1zkp_v0_4_0_generator>& tags_in, rustsecp256k1zkp_v0_4_0_generator& gen_in, uint256& wtxid_in, const bool store_in) : proof(proof_in), vTags(tags_in), gen(gen_in), wtxid(wtxid_in), store(store_in) {}
 
     bool operator()() override;
 };
 
 ScriptError QueueCheck(std::vector<CCheck*>* queue, CCheck* check);
 
-bool VerifyAmounts(const std::vector<CTxOut>& inputs, const CTransaction& tx, std::vector<CCheck*>* pvChecks, const bool cacheStore);
+/** Verify a rangeproof without consulting the rangeproof cache. */
+bool VerifyRangeProof(const std::vector<unsigned char>& vchRangeProof, const std::vector<unsigned char>& vchValueCommitment, const std::vector<unsigned char>& vchAssetCommitment, const CScript& scriptPubKey, const rustsecp256k1zkp_v0_4_0_context* ctx);
+
+/** Verify a surjection proof without consulting the surjection proof cache. */
+bool VerifySurjectionProof(const rustsecp256k1zkp_v0_4_0_surjectionproof& proof, const std::vector<rustsecp256k1zkp_v0_4_0_generator>& vTags, const rustsecp256k1zkp_v0_4_0_generator& gen, const rustsecp256k1zkp_v0_4_0_context* ctx);
+
+/** The check of VerifyAmounts that a transaction failed. */
+enum class AmountsError {
+    OK,
+    INPUT,           //!< the asset or value of the output spent by an input is invalid
+    ISSUANCE,        //!< the issuance of an input is invalid, including its rangeproofs
+    OUTPUT,          //!< the asset, value or nonce of an output is invalid, or a spendable output has zero value
+    BALANCE,         //!< inputs and issuances do not balance outputs and fees
+    RANGEPROOF,      //!< the rangeproof of an output is missing or invalid
+    SURJECTIONPROOF, //!< the surjection proof of an output is missing or invalid
+};
+
+/**
+ * Check that the value commitments of the inputs, issuances, outputs and fees of tx balance and that the
+ * rangeproofs and surjection proofs of its outputs are valid. If not nullptr, error and error_index are set
+ * to the failed check and the index of the input or output it failed for.
+ */
+bool VerifyAmounts(const std::vector<CTxOut>& inputs, const CTransaction& tx, std::vector<CCheck*>* pvChecks, const bool cacheStore, AmountsError* error = nullptr, size_t* error_index = nullptr);
+
+/** The check of VerifyIssuance that an issuance failed. */
+enum class IssuanceError {
+    OK,
+    WITNESS,                   //!< the transaction has no witness, which issuances of a non-null amount require
+    AMOUNT,                    //!< the issued amount is invalid, zero, out of range or explicit but has a rangeproof
+    AMOUNT_RANGEPROOF,         //!< the rangeproof of the blinded issued amount is invalid
+    INFLATION_KEYS,            //!< the amount of issued reissuance tokens is invalid, zero, out of range or explicit but has a rangeproof
+    INFLATION_KEYS_RANGEPROOF, //!< the rangeproof of the blinded amount of issued reissuance tokens is invalid
+    REISSUANCE_INFLATION_KEYS, //!< a reissuance issues reissuance tokens
+    REISSUANCE_TOKEN,          //!< the output spent by a reissuance is not the reissuance token blinded with its assetBlindingNonce
+};
+
+/**
+ * Check the non-null issuance of input nIn of tx, which spends spent_output, like VerifyAmounts does for its
+ * issuance pseudo-inputs. Sets entropy, asset and token to the issuance entropy, the issued asset and its
+ * reissuance token, even if the check fails.
+ */
+bool VerifyIssuance(const CTxOut& spent_output, const CTransaction& tx, size_t nIn, uint256& entropy, CAsset& asset, CAsset& token, IssuanceError& error);
+
+/**
+ * Verify the rangeproof and surjection proof of each output of tx on their own, without checking that tx balances.
+ * Surjection proofs are verified against the assets of the inputs and their issuances, so they are all invalid
+ * if the asset of an input is. Sets valid_rangeproofs and valid_surjectionproofs to one entry per output.
+ */
+void VerifyOutputProofs(const std::vector<CTxOut>& inputs, const CTransaction& tx, std::vector<bool>& valid_rangeproofs, std::vector<bool>& valid_surjectionproofs);
 
 bool VerifyCoinbaseAmount(const CTransaction& tx, const CAmountMap& mapFees);
 
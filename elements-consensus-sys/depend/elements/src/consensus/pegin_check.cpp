@@ -0,0 +1,385 @@
+// Copyright (c) 2017-2021 The Bitcoin Core developers
+// Distributed under the MIT software license, see the accompanying
+// file COPYING or http://www.opensource.org/licenses/mit-license.php.
+
+#include <consensus/pegin_check.h>
+
+#include <amount.h>
+#include <arith_uint256.h>
+#include <consensus/consensus.h>
+#include <crypto/hmac_sha256.h>
+#include <crypto/sha256.h>
+#include <hash.h>
+#include <primitives/bitcoin/merkleblock.h>
+#include <primitives/bitcoin/transaction.h>
+#include <primitives/transaction.h>
+#include <script/interpreter.h>
+#include <secp256k1_zkp_preallocated.h>
+#include <streams.h>
+#include <version.h>
+
+#include <algorithm>
+#include <stdlib.h>
+
+namespace {
+static rustsecp256k1zkp_v0_4_0_context* rustsecp256k1zkp_v0_4_0_ctx_validation;
+
+// rust-secp256k1-zkp only exports the preallocated context functions, so the context lives in memory allocated here.
+class Secp256k1Ctx
+{
+    void* m_memory;
+public:
+    Secp256k1Ctx() {
+        assert(rustsecp256k1zkp_v0_4_0_ctx_validation == NULL);
+        const unsigned int flags = SECP256K1_CONTEXT_VERIFY | SECP256K1_CONTEXT_SIGN;
+        m_memory = malloc(rustsecp256k1zkp_v0_4_0_context_preallocated_size(flags));
+        assert(m_memory != NULL);
+        rustsecp256k1zkp_v0_4_0_ctx_validation = rustsecp256k1zkp_v0_4_0_context_preallocated_create(m_memory, flags);
+        assert(rustsecp256k1zkp_v0_4_0_ctx_validation != NULL);
+    }
+
+    ~Secp256k1Ctx() {
+        assert(rustsecp256k1zkp_v0_4_0_ctx_validation != NULL);
+        rustsecp256k1zkp_v0_4_0_context_preallocated_destroy(rustsecp256k1zkp_v0_4_0_ctx_validation);
+        rustsecp256k1zkp_v0_4_0_ctx_validation = NULL;
+        free(m_memory);
+    }
+};
+static Secp256k1Ctx instance_of_secp256k1ctx;
+}
+
+// Takes federation redeem script and adds HMAC_SHA256(pubkey, scriptPubKey) as a tweak to each pubkey
+CScript calculate_contract(const CScript& federation_script, const CScript& scriptPubKey) {
+    CScript scriptDestination;
+
+    bool is_liquidv1_watchman = MatchLiquidWatchman(federation_script);
+
+    CScript::const_iterator sdpc = federation_script.begin();
+    std::vector<unsigned char> vch;
+    opcodetype opcodeTmp;
+    bool liquid_op_else_found = false;
+    while (federation_script.GetOp(sdpc, opcodeTmp, vch))
+    {
+
+        // For liquidv1 initial watchman template, don't tweak emergency keys
+        if (is_liquidv1_watchman && opcodeTmp == OP_ELSE) {
+            liquid_op_else_found = true;
+        }
+
+        size_t pub_len = 33;
+        if (vch.size() == pub_len && !liquid_op_else_found)
+        {
+            unsigned char tweak[32];
+            CHMAC_SHA256(vch.data(), pub_len).Write(scriptPubKey.data(), scriptPubKey.size()).Finalize(tweak);
+            int ret;
+            rustsecp256k1zkp_v0_4_0_pubkey watchman;
+            rustsecp256k1zkp_v0_4_0_pubkey tweaked;
+            ret = rustsecp256k1zkp_v0_4_0_ec_pubkey_parse(rustsecp256k1zkp_v0_4_0_ctx_validation, &watchman, vch.data(), pub_len);
+            assert(ret == 1);
+            ret = rustsecp256k1zkp_v0_4_0_ec_pubkey_parse(rustsecp256k1zkp_v0_4_0_ctx_validation, &tweaked, vch.data(), pub_len);
+            assert(ret == 1);
+            // If someone creates a tweak that makes this fail, they broke SHA256
+            ret = rustsecp256k1zkp_v0_4_0_ec_pubkey_tweak_add(rustsecp256k1zkp_v0_4_0_ctx_validation, &tweaked, tweak);
+            assert(ret == 1);
+            unsigned char new_pub[33];
+            ret = rustsecp256k1zkp_v0_4_0_ec_pubkey_serialize(rustsecp256k1zkp_v0_4_0_ctx_validation, new_pub, &pub_len, &tweaked, SECP256K1_EC_COMPRESSED);
+            assert(ret == 1);
+            assert(pub_len == 33);
+
+            // push tweaked pubkey
+            std::vector<unsigned char> pub_vec(new_pub, new_pub + pub_len);
+            scriptDestination << pub_vec;
+
+            // Sanity checks to reduce pegin risk. If the tweaked
+            // value flips a bit, we may lose pegin funds irretrievably.
+            // We take the tweak, derive its pubkey and check that
+            // `tweaked - watchman = tweak` to check the computation
+            // two different ways
+            rustsecp256k1zkp_v0_4_0_pubkey tweaked2;
+            ret = rustsecp256k1zkp_v0_4_0_ec_pubkey_create(rustsecp256k1zkp_v0_4_0_ctx_validation, &tweaked2, tweak);
+            assert(ret);
+            ret = rustsecp256k1zkp_v0_4_0_ec_pubkey_negate(rustsecp256k1zkp_v0_4_0_ctx_validation, &watchman);
+            assert(ret);
+            rustsecp256k1zkp_v0_4_0_pubkey* pubkey_combined[2];
+            pubkey_combined[0] = &watchman;
+            pubkey_combined[1] = &tweaked;
+            rustsecp256k1zkp_v0_4_0_pubkey maybe_tweaked2;
+            ret = rustsecp256k1zkp_v0_4_0_ec_pubkey_combine(rustsecp256k1zkp_v0_4_0_ctx_validation, &maybe_tweaked2, pubkey_combined, 2);
+            assert(ret);
+            assert(!memcmp(&maybe_tweaked2, &tweaked2, 64));
+        } else {
+            // add to script untouched
+            if (vch.size() > 0) {
+                scriptDestination << vch;
+            } else {
+                scriptDestination << opcodeTmp;
+            }
+        }
+    }
+
+    return scriptDestination;
+}
+
+bool MatchLiquidWatchman(const CScript& script)
+{
+    CScript::const_iterator it = script.begin();
+    std::vector<unsigned char> data;
+    opcodetype opcode;
+
+    // Stack depth check for branch choice
+    if (!script.GetOp(it, opcode, data) || opcode != OP_DEPTH) {
+        return false;
+    }
+    // Take in value, then check equality
+    if (!script.GetOp(it, opcode, data) ||
+            !script.GetOp(it, opcode, data) ||
+            opcode != OP_EQUAL) {
+        return false;
+    }
+    // IF EQUAL
+    if (!script.GetOp(it, opcode, data) || opcode != OP_IF) {
+        return false;
+    }
+    // Take in value k, make sure minimally encoded number from 1 to 16
+    if (!script.GetOp(it, opcode, data) ||
+            opcode > OP_16 ||
+            (opcode < OP_1NEGATE && !CheckMinimalPush(data, opcode))) {
+        return false;
+    }
+    opcodetype opcode2 = opcode;
+    std::vector<unsigned char> num = data;
+    // Iterate through multisig stuff until ELSE is hit
+    while (opcode != OP_ELSE) {
+        if (!script.GetOp(it, opcode, data)) {
+            return false;
+        }
+    }
+    // Take minimally-encoded CSV push number k'
+    if (!script.GetOp(it, opcode, data) ||
+            opcode > OP_16 || (opcode < OP_1NEGATE && !CheckMinimalPush(data, opcode))) {
+        return false;
+    }
+    // CSV
+    if (!script.GetOp(it, opcode, data) || opcode != OP_CHECKSEQUENCEVERIFY) {
+        return false;
+    }
+    // Drop the CSV number
+    if (!script.GetOp(it, opcode, data) || opcode != OP_DROP) {
+        return false;
+    }
+    // Take the minimally-encoded n of k-of-n multisig arg
+    if (!script.GetOp(it, opcode, data) ||
+            opcode > OP_16 || (opcode < OP_1NEGATE && !CheckMinimalPush(data, opcode)) ) {
+        return false;
+    }
+
+    // The two multisig k-numbers must not match, otherwise ELSE branch can not be reached
+    if (opcode == opcode2 && num == data) {
+        return false;
+    }
+
+    // Find the ENDIF
+    while (opcode != OP_ENDIF) {
+        if (!script.GetOp(it, opcode, data)) {
+            return false;
+        }
+    }
+    // CHECKMULTISIG
+    if (!script.GetOp(it, opcode, data) || opcode != OP_CHECKMULTISIG) {
+        return false;
+    }
+    // No more pushes
+    return (it == script.end());
+}
+
+bool CheckParentProofOfWork(const uint256& hash, unsigned int nBits, const uint256& parent_pow_limit)
+{
+    bool fNegative;
+    bool fOverflow;
+    arith_uint256 bnTarget;
+
+    bnTarget.SetCompact(nBits, &fNegative, &fOverflow);
+
+    // Check range
+    if (fNegative || bnTarget == 0 || fOverflow || bnTarget > UintToArith256(parent_pow_limit))
+        return false;
+
+    // Check proof of work matches claimed amount
+    if (UintToArith256(hash) > bnTarget)
+        return false;
+
+    return true;
+}
+
+static bool CheckBitcoinPeginTx(const std::vector<unsigned char>& tx_data, const COutPoint& prevout, const CAmount claim_amount, const CScript& claim_script, const std::vector<std::pair<CScript, CScript>>& fedpegscripts, PeginError& error)
+{
+    Sidechain::Bitcoin::CTransactionRef pegtx;
+    try {
+        CDataStream pegtx_stream(tx_data, SER_NETWORK, PROTOCOL_VERSION);
+        pegtx_stream >> pegtx;
+        if (!pegtx_stream.empty()) {
+            error = PeginError::TX;
+            return false;
+        }
+    } catch (std::exception& e) {
+        // Invalid encoding of transaction
+        error = PeginError::TX;
+        return false;
+    }
+
+    // Check that transaction matches txid
+    if (pegtx->GetHash() != prevout.hash) {
+        error = PeginError::TXID;
+        return false;
+    }
+
+    if (prevout.n >= pegtx->vout.size()) {
+        error = PeginError::TX;
+        return false;
+    }
+    // Check the transaction nout/value matches
+    if (claim_amount != pegtx->vout[prevout.n].nValue) {
+        error = PeginError::AMOUNT;
+        return false;
+    }
+
+    // Check that the witness program matches the p2ch on the (p2sh-)p2wsh
+    // transaction output. We support multiple scripts as a grace period for peg-in users
+    for (const auto& scripts : fedpegscripts) {
+        int fedpeg_version = 0;
+        std::vector<unsigned char> fedpeg_program;
+        scripts.first.IsWitnessProgram(fedpeg_version, fedpeg_program);
+        // We immediately return true if any fedpegscripts are unencumbered
+        // by currently-known parent chain segwit versions.
+        if (fedpeg_version > 0) {
+            return true;
+        }
+        CScript tweaked_fedpegscript = calculate_contract(scripts.second, claim_script);
+        uint256 script_hash;
+        CSHA256().Write(tweaked_fedpegscript.data(), tweaked_fedpegscript.size()).Finalize(script_hash.begin());
+        CScript expected_script = CScript() << OP_0 << ToByteVector(script_hash);
+        if (scripts.first.IsPayToScriptHash()) {
+            expected_script = CScript() << OP_HASH160 << ToByteVector(Hash160(expected_script)) << OP_EQUAL;
+        }
+        if (pegtx->vout[prevout.n].scriptPubKey == expected_script) {
+            return true;
+        }
+    }
+    error = PeginError::CLAIM_SCRIPT;
+    return false;
+}
+
+bool CheckBitcoinPeginWitness(const CScriptWitness& pegin_witness, const PeginParams& params, const COutPoint& prevout, uint32_t confirmations, PeginError& error)
+{
+    error = PeginError::OK;
+
+    // See IsValidPeginWitness for the format of the stack
+    const std::vector<std::vector<unsigned char> >& stack = pegin_witness.stack;
+    // Must include all elements
+    if (stack.size() != 6) {
+        error = PeginError::WITNESS;
+        return false;
+    }
+
+    CDataStream stream(stack[0], SER_NETWORK, PROTOCOL_VERSION);
+    CAmount value;
+    try {
+        stream >> value;
+    } catch (...) {
+        error = PeginError::WITNESS;
+        return false;
+    }
+
+    if (!MoneyRange(value)) {
+        error = PeginError::VALUE;
+        return false;
+    }
+
+    // Get asset type
+    if (stack[1].size() != 32) {
+        error = PeginError::WITNESS;
+        return false;
+    }
+    CAsset asset(stack[1]);
+
+    // Get genesis blockhash
+    if (stack[2].size() != 32) {
+        error = PeginError::WITNESS;
+        return false;
+    }
+    uint256 gen_hash(stack[2]);
+
+    // Get claim_script, sanity check size
+    CScript claim_script(stack[3].begin(), stack[3].end());
+    if (claim_script.size() > 100) {
+        error = PeginError::CLAIM_SCRIPT_SIZE;
+        return false;
+    }
+
+    // Get txout proof
+    Sidechain::Bitcoin::CMerkleBlock merkle_block;
+    uint256 block_hash;
+    uint256 tx_hash;
+    unsigned int tx_index = 0;
+    try {
+        std::vector<uint256> tx_hashes;
+        std::vector<unsigned int> tx_indices;
+        CDataStream merkle_block_stream(stack[5], SER_NETWORK, PROTOCOL_VERSION | SERIALIZE_TRANSACTION_NO_WITNESS);
+        merkle_block_stream >> merkle_block;
+        block_hash = merkle_block.header.GetHash();
+
+        if (!merkle_block_stream.empty()) {
+            error = PeginError::MERKLE_BLOCK;
+            return false;
+        }
+        if (merkle_block.txn.ExtractMatches(tx_hashes, tx_indices) != merkle_block.header.hashMerkleRoot || tx_hashes.size() != 1) {
+            error = PeginError::MERKLE_BLOCK;
+            return false;
+        }
+        tx_hash = tx_hashes[0];
+        tx_index = tx_indices[0];
+    } catch (std::exception& e) {
+        // Invalid encoding of merkle block
+        error = PeginError::MERKLE_BLOCK;
+        return false;
+    }
+
+    if (!CheckParentProofOfWork(block_hash, merkle_block.header.nBits, params.parent_pow_limit)) {
+        error = PeginError::POW;
+        return false;
+    }
+
+    if (!CheckBitcoinPeginTx(stack[4], prevout, value, claim_script, params.fedpegscripts, error)) {
+        return false;
+    }
+
+    // Check that the merkle proof corresponds to the txid
+    if (prevout.hash != tx_hash) {
+        error = PeginError::TXID;
+        return false;
+    }
+
+    // Check the genesis block corresponds to a valid peg (only one for now)
+    if (gen_hash != params.parent_genesis_hash) {
+        error = PeginError::GENESIS;
+        return false;
+    }
+
+    // Check the asset type corresponds to a valid pegged asset (only one for now)
+    if (asset != params.pegged_asset) {
+        error = PeginError::ASSET;
+        return false;
+    }
+
+    uint32_t required_depth = params.pegin_min_depth;
+    // Don't allow coinbase output claims before coinbase maturity
+    if (tx_index == 0) {
+        required_depth = std::max(required_depth, (uint32_t)COINBASE_MATURITY);
+    }
+    if (confirmations < required_depth) {
+        error = PeginError::DEPTH;
+        return false;
+    }
+
+    return true;
+}
@@ -0,0 +1,72 @@
+// Copyright (c) 2017-2021 The Bitcoin Core developers
+// Distributed under the MIT software license, see the accompanying
+// file COPYING or http://www.opensource.org/licenses/mit-license.php.
+
+#ifndef BITCOIN_CONSENSUS_PEGIN_CHECK_H
+#define BITCOIN_CONSENSUS_PEGIN_CHECK_H
+
+#include <asset.h>
+#include <script/script.h>
+#include <uint256.h>
+
+#include <utility>
+#include <vector>
+
+/**
+ * Peg-in checking code that does not depend on the chain parameters or a
+ * connection to the parent chain daemon, so that it can be called outside the
+ * elements server. IsValidPeginWitness in pegins.h is the server's entry point.
+ */
+
+class COutPoint;
+
+/** Calculates script necessary for p2ch peg-in transactions */
+CScript calculate_contract(const CScript& federationRedeemScript, const CScript& witnessProgram);
+
+/* Consensus-critical. Matching against telescoped multisig used on Liquid v1:
+ * Pseudo-structure:
+ * Check number of elements on stack
+ * If enough for federation multisig, push all multisig args onto stack except OP_CMS
+ * If not, check CSV timeout, then if successful, push emergency key multisig args on
+ * stack except OP_CMS. End if, then push OP_CMS.
+ */
+bool MatchLiquidWatchman(const CScript& script);
+
+/** Check whether a parent chain block hash satisfies the proof-of-work requirement specified by nBits */
+bool CheckParentProofOfWork(const uint256& hash, unsigned int nBits, const uint256& parent_pow_limit);
+
+/** The parts of the chain parameters and chain state that a peg-in from a proof-of-work parent chain is checked against. */
+struct PeginParams {
+    uint256 parent_genesis_hash;
+    uint256 parent_pow_limit;
+    CAsset pegged_asset;
+    //! The scriptPubKeys on the parent chain paired with their fedpegscripts, as returned by GetValidFedpegScripts
+    std::vector<std::pair<CScript, CScript>> fedpegscripts;
+    uint32_t pegin_min_depth;
+};
+
+/** The check of CheckBitcoinPeginWitness that a peg-in failed. */
+enum class PeginError {
+    OK,
+    WITNESS,            //!< the pegin witness does not have the expected items or their encoding is invalid
+    VALUE,              //!< the claimed value is out of range
+    CLAIM_SCRIPT_SIZE,  //!< the claim script is too large
+    MERKLE_BLOCK,       //!< the merkle block cannot be decoded or does not prove exactly one transaction
+    POW,                //!< the parent block's proof of work is invalid or insufficient
+    TX,                 //!< the parent transaction cannot be decoded or does not have the claimed output
+    TXID,               //!< the parent transaction or merkle proof does not match the claimed outpoint
+    AMOUNT,             //!< the claimed value does not match the parent output
+    CLAIM_SCRIPT,       //!< the parent output does not pay to a fedpegscript tweaked with the claim script
+    GENESIS,            //!< the parent genesis block hash does not match
+    ASSET,              //!< the claimed asset is not the pegged asset
+    DEPTH,              //!< the parent block does not have enough confirmations
+};
+
+/**
+ * Check the pegin witness of a peg-in claiming prevout from a parent chain with proof of work, like
+ * IsValidPeginWitness. Instead of asking the parent chain daemon, confirmations is the number of
+ * confirmations of the parent block that the merkle proof commits to.
+ */
+bool CheckBitcoinPeginWitness(const CScriptWitness& pegin_witness, const PeginParams& params, const COutPoint& prevout, uint32_t confirmations, PeginError& error);
+
+#endif // BITCOIN_CONSENSUS_PEGIN_CHECK_H
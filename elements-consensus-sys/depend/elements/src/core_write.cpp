@@ -332,7 +332,7 @@ void TxToUniv(const CTransaction& tx, const uint256& hashBlock, UniValue& entry,
             uint64_t maxv;
             const CTxOutWitness* ptxoutwit = tx.witness.vtxoutwit.size() <= i? NULL: &tx.witness.vtxoutwit[i];
             if (ptxoutwit) {
-                if (ptxoutwit->vchRangeproof.size() && rustsecp256k1_v0_4_1_rangeproof_info(rustsecp256k1_v0_4_1_blind_context, &exp, &mantissa, &minv, &maxv, &ptxoutwit->vchRangeproof[0], ptxoutwit->vchRangeproof.size())) {
+                if (ptxoutwit->vchRangeproof.size() && rustsecp256k1zkp_v0_4_0_rangeproof_info(rustsecp256k1_v0_4_1_blind_context, &exp, &mantissa, &minv, &maxv, &ptxoutwit->vchRangeproof[0], ptxoutwit->vchRangeproof.size())) {
                     if (exp == -1) {
                         out.pushKV("value", ValueFromAmount((CAmount)minv));
                     } else {
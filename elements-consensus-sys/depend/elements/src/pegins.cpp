@@ -26,27 +26,6 @@
 // ELEMENTS
 //
 
-namespace {
-static rustsecp256k1_v0_4_1_context* rustsecp256k1_v0_4_1_ctx_validation;
-
-class Secp256k1Ctx
-{
-public:
-    Secp256k1Ctx() {
-        assert(rustsecp256k1_v0_4_1_ctx_validation == NULL);
-        rustsecp256k1_v0_4_1_ctx_validation = rustsecp256k1_v0_4_1_context_create(SECP256K1_CONTEXT_VERIFY | SECP256K1_CONTEXT_SIGN);
-        assert(rustsecp256k1_v0_4_1_ctx_validation != NULL);
-    }
-
-    ~Secp256k1Ctx() {
-        assert(rustsecp256k1_v0_4_1_ctx_validation != NULL);
-        rustsecp256k1_v0_4_1_context_destroy(rustsecp256k1_v0_4_1_ctx_validation);
-        rustsecp256k1_v0_4_1_ctx_validation = NULL;
-    }
-};
-static Secp256k1Ctx instance_of_secp256k1ctx;
-}
-
 bool GetAmountFromParentChainPegin(CAmount& amount, const Sidechain::Bitcoin::CTransaction& txBTC, unsigned int nOut)
 {
     amount = txBTC.vout[nOut].nValue;
@@ -68,78 +47,6 @@ bool GetAmountFromParentChainPegin(CAmount& amount, const CTransaction& txBTC, u
     return true;
 }
 
-// Takes federation redeem script and adds HMAC_SHA256(pubkey, scriptPubKey) as a tweak to each pubkey
-CScript calculate_contract(const CScript& federation_script, const CScript& scriptPubKey) {
-    CScript scriptDestination;
-
-    bool is_liquidv1_watchman = MatchLiquidWatchman(federation_script);
-
-    CScript::const_iterator sdpc = federation_script.begin();
-    std::vector<unsigned char> vch;
-    opcodetype opcodeTmp;
-    bool liquid_op_else_found = false;
-    while (federation_script.GetOp(sdpc, opcodeTmp, vch))
-    {
-
-        // For liquidv1 initial watchman template, don't tweak emergency keys
-        if (is_liquidv1_watchman && opcodeTmp == OP_ELSE) {
-            liquid_op_else_found = true;
-        }
-
-        size_t pub_len = 33;
-        if (vch.size() == pub_len && !liquid_op_else_found)
-        {
-            unsigned char tweak[32];
-            CHMAC_SHA256(vch.data(), pub_len).Write(scriptPubKey.data(), scriptPubKey.size()).Finalize(tweak);
-            int ret;
-            rustsecp256k1_v0_4_1_pubkey watchman;
-            rustsecp256k1_v0_4_1_pubkey tweaked;
-            ret = rustsecp256k1_v0_4_1_ec_pubkey_parse(rustsecp256k1_v0_4_1_ctx_validation, &watchman, vch.data(), pub_len);
-            assert(ret == 1);
-            ret = rustsecp256k1_v0_4_1_ec_pubkey_parse(rustsecp256k1_v0_4_1_ctx_validation, &tweaked, vch.data(), pub_len);
-            assert(ret == 1);
-            // If someone creates a tweak that makes this fail, they broke SHA256
-            ret = rustsecp256k1_v0_4_1_ec_pubkey_tweak_add(rustsecp256k1_v0_4_1_ctx_validation, &tweaked, tweak);
-            assert(ret == 1);
-            unsigned char new_pub[33];
-            ret = rustsecp256k1_v0_4_1_ec_pubkey_serialize(rustsecp256k1_v0_4_1_ctx_validation, new_pub, &pub_len, &tweaked, SECP256K1_EC_COMPRESSED);
-            assert(ret == 1);
-            assert(pub_len == 33);
-
-            // push tweaked pubkey
-            std::vector<unsigned char> pub_vec(new_pub, new_pub + pub_len);
-            scriptDestination << pub_vec;
-
-            // Sanity checks to reduce pegin risk. If the tweaked
-            // value flips a bit, we may lose pegin funds irretrievably.
-            // We take the tweak, derive its pubkey and check that
-            // `tweaked - watchman = tweak` to check the computation
-            // two different ways
-            rustsecp256k1_v0_4_1_pubkey tweaked2;
-            ret = rustsecp256k1_v0_4_1_ec_pubkey_create(rustsecp256k1_v0_4_1_ctx_validation, &tweaked2, tweak);
-            assert(ret);
-            ret = rustsecp256k1_v0_4_1_ec_pubkey_negate(rustsecp256k1_v0_4_1_ctx_validation, &watchman);
-            assert(ret);
-            rustsecp256k1_v0_4_1_pubkey* pubkey_combined[2];
-            pubkey_combined[0] = &watchman;
-            pubkey_combined[1] = &tweaked;
-            rustsecp256k1_v0_4_1_pubkey maybe_tweaked2;
-            ret = rustsecp256k1_v0_4_1_ec_pubkey_combine(rustsecp256k1_v0_4_1_ctx_validation, &maybe_tweaked2, pubkey_combined, 2);
-            assert(ret);
-            assert(!memcmp(&maybe_tweaked2, &tweaked2, 64));
-        } else {
-            // add to script untouched
-            if (vch.size() > 0) {
-                scriptDestination << vch;
-            } else {
-                scriptDestination << opcodeTmp;
-            }
-        }
-    }
-
-    return scriptDestination;
-}
-
 template<typename T>
 static bool CheckPeginTx(const std::vector<unsigned char>& tx_data, T& pegtx, const COutPoint& prevout, const CAmount claim_amount, const CScript& claim_script, const std::vector<std::pair<CScript, CScript>>& fedpegscripts)
 {
@@ -223,21 +130,7 @@ static bool GetBlockAndTxFromMerkleBlock(uint256& block_hash, uint256& tx_hash,
 
 bool CheckParentProofOfWork(uint256 hash, unsigned int nBits, const Consensus::Params& params)
 {
-    bool fNegative;
-    bool fOverflow;
-    arith_uint256 bnTarget;
-
-    bnTarget.SetCompact(nBits, &fNegative, &fOverflow);
-
-    // Check range
-    if (fNegative || bnTarget == 0 || fOverflow || bnTarget > UintToArith256(params.parentChainPowLimit))
-        return false;
-
-    // Check proof of work matches claimed amount
-    if (UintToArith256(hash) > bnTarget)
-        return false;
-
-    return true;
+    return CheckParentProofOfWork(hash, nBits, params.parentChainPowLimit);
 }
 
 bool IsValidPeginWitness(const CScriptWitness& pegin_witness, const std::vector<std::pair<CScript, CScript>>& fedpegscripts, const COutPoint& prevout, std::string& err_msg, bool check_depth) {
@@ -378,78 +271,6 @@ bool IsValidPeginWitness(const CScriptWitness& pegin_witness, const std::vector<
     return true;
 }
 
-bool MatchLiquidWatchman(const CScript& script)
-{
-    CScript::const_iterator it = script.begin();
-    std::vector<unsigned char> data;
-    opcodetype opcode;
-
-    // Stack depth check for branch choice
-    if (!script.GetOp(it, opcode, data) || opcode != OP_DEPTH) {
-        return false;
-    }
-    // Take in value, then check equality
-    if (!script.GetOp(it, opcode, data) ||
-            !script.GetOp(it, opcode, data) ||
-            opcode != OP_EQUAL) {
-        return false;
-    }
-    // IF EQUAL
-    if (!script.GetOp(it, opcode, data) || opcode != OP_IF) {
-        return false;
-    }
-    // Take in value k, make sure minimally encoded number from 1 to 16
-    if (!script.GetOp(it, opcode, data) ||
-            opcode > OP_16 ||
-            (opcode < OP_1NEGATE && !CheckMinimalPush(data, opcode))) {
-        return false;
-    }
-    opcodetype opcode2 = opcode;
-    std::vector<unsigned char> num = data;
-    // Iterate through multisig stuff until ELSE is hit
-    while (opcode != OP_ELSE) {
-        if (!script.GetOp(it, opcode, data)) {
-            return false;
-        }
-    }
-    // Take minimally-encoded CSV push number k'
-    if (!script.GetOp(it, opcode, data) ||
-            opcode > OP_16 || (opcode < OP_1NEGATE && !CheckMinimalPush(data, opcode))) {
-        return false;
-    }
-    // CSV
-    if (!script.GetOp(it, opcode, data) || opcode != OP_CHECKSEQUENCEVERIFY) {
-        return false;
-    }
-    // Drop the CSV number
-    if (!script.GetOp(it, opcode, data) || opcode != OP_DROP) {
-        return false;
-    }
-    // Take the minimally-encoded n of k-of-n multisig arg
-    if (!script.GetOp(it, opcode, data) ||
-            opcode > OP_16 || (opcode < OP_1NEGATE && !CheckMinimalPush(data, opcode)) ) {
-        return false;
-    }
-
-    // The two multisig k-numbers must not match, otherwise ELSE branch can not be reached
-    if (opcode == opcode2 && num == data) {
-        return false;
-    }
-
-    // Find the ENDIF
-    while (opcode != OP_ENDIF) {
-        if (!script.GetOp(it, opcode, data)) {
-            return false;
-        }
-    }
-    // CHECKMULTISIG
-    if (!script.GetOp(it, opcode, data) || opcode != OP_CHECKMULTISIG) {
-        return false;
-    }
-    // No more pushes
-    return (it == script.end());
-}
-
 std::vector<std::pair<CScript, CScript>> GetValidFedpegScripts(const CBlockIndex* pblockindex, const Consensus::Params& params, bool nextblock_validation)
 {
     assert(pblockindex);
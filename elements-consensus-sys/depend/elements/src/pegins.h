@@ -7,6 +7,7 @@
 
 #include <amount.h>
 #include <consensus/params.h>
+#include <consensus/pegin_check.h>
 #include <merkleblock.h>
 #include <primitives/bitcoin/transaction.h>
 #include <primitives/bitcoin/merkleblock.h>
@@ -16,8 +17,6 @@
 
 #include <boost/variant.hpp>
 
-/** Calculates script necessary for p2ch peg-in transactions */
-CScript calculate_contract(const CScript& federationRedeemScript, const CScript& witnessProgram);
 bool GetAmountFromParentChainPegin(CAmount& amount, const Sidechain::Bitcoin::CTransaction& txBTC, unsigned int nOut);
 bool GetAmountFromParentChainPegin(CAmount& amount, const CTransaction& txBTC, unsigned int nOut);
 /** Check whether a parent chain block hash satisfies the proof-of-work requirement specified by nBits */
@@ -25,15 +24,6 @@ bool CheckParentProofOfWork(uint256 hash, unsigned int nBits, const Consensus::P
 /** Checks pegin witness for validity */
 bool IsValidPeginWitness(const CScriptWitness& pegin_witness, const std::vector<std::pair<CScript, CScript>>& fedpegscripts, const COutPoint& prevout, std::string& err_msg, bool check_depth);
 
-/* Consensus-critical. Matching against telescoped multisig used on Liquid v1:
- * Pseudo-structure:
- * Check number of elements on stack
- * If enough for federation multisig, push all multisig args onto stack except OP_CMS
- * If not, check CSV timeout, then if successful, push emergency key multisig args on
- * stack except OP_CMS. End if, then push OP_CMS.
- */
-bool MatchLiquidWatchman(const CScript& script);
-
 /** Get full fedpegscripts from two previous epoch starts based on given index
  * nextblock_validation is false when doing block validation, true for mempool
  * or "lookahead" purposes. Newest epochs first. The first returned element is the
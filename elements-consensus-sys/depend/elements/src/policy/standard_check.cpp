@@ -0,0 +1,400 @@
+// Copyright (c) 2009-2010 Satoshi Nakamoto
+// Copyright (c) 2009-2021 The Bitcoin Core developers
+// Distributed under the MIT software license, see the accompanying
+// file COPYING or http://www.opensource.org/licenses/mit-license.php.
+
+#include <policy/standard_check.h>
+
+#include <consensus/validation.h>
+#include <policy/feerate.h>
+#include <primitives/transaction.h>
+#include <pubkey.h>
+#include <script/script.h>
+#include <span.h>
+
+namespace {
+
+typedef std::vector<unsigned char> valtype;
+
+/** The maximum weight for transactions we're willing to relay/mine */
+static const unsigned int MAX_STANDARD_TX_WEIGHT = 400000;
+/** The minimum non-witness size for transactions we're willing to relay/mine (1 segwit input + 1 P2WPKH output = 82 bytes) */
+static const unsigned int MIN_STANDARD_TX_NONWITNESS_SIZE = 82;
+/** Maximum number of signature check operations in an IsStandard() P2SH script */
+static const unsigned int MAX_P2SH_SIGOPS = 15;
+/** The maximum number of witness stack items in a standard P2WSH script */
+static const unsigned int MAX_STANDARD_P2WSH_STACK_ITEMS = 100;
+/** The maximum size of each witness stack item in a standard P2WSH script */
+static const unsigned int MAX_STANDARD_P2WSH_STACK_ITEM_SIZE = 80;
+/** The maximum size of each witness stack item in a standard BIP 342 script (Taproot, leaf version 0xc0) */
+static const unsigned int MAX_STANDARD_TAPSCRIPT_STACK_ITEM_SIZE = 80;
+/** The maximum size of a standard witnessScript */
+static const unsigned int MAX_STANDARD_P2WSH_SCRIPT_SIZE = 3600;
+
+/** The output types of TxoutType in script/standard.h that standardness distinguishes. */
+enum class OutputType {
+    NONSTANDARD,
+    PUBKEY,
+    PUBKEYHASH,
+    SCRIPTHASH,
+    MULTISIG,
+    NULL_DATA,
+    WITNESS_V0_SCRIPTHASH,
+    WITNESS_V0_KEYHASH,
+    WITNESS_V1_TAPROOT,
+    WITNESS_UNKNOWN,
+    OP_TRUE,
+    FEE,
+};
+
+bool MatchPayToPubkey(const CScript& script)
+{
+    if (script.size() == CPubKey::SIZE + 2 && script[0] == CPubKey::SIZE && script.back() == OP_CHECKSIG) {
+        return CPubKey::ValidSize(valtype(script.begin() + 1, script.begin() + CPubKey::SIZE + 1));
+    }
+    if (script.size() == CPubKey::COMPRESSED_SIZE + 2 && script[0] == CPubKey::COMPRESSED_SIZE && script.back() == OP_CHECKSIG) {
+        return CPubKey::ValidSize(valtype(script.begin() + 1, script.begin() + CPubKey::COMPRESSED_SIZE + 1));
+    }
+    return false;
+}
+
+bool MatchPayToPubkeyHash(const CScript& script)
+{
+    return script.size() == 25 && script[0] == OP_DUP && script[1] == OP_HASH160 && script[2] == 20 && script[23] == OP_EQUALVERIFY && script[24] == OP_CHECKSIG;
+}
+
+/** Test for "small positive integer" script opcodes - OP_1 through OP_16. */
+constexpr bool IsSmallInteger(opcodetype opcode)
+{
+    return opcode >= OP_1 && opcode <= OP_16;
+}
+
+bool MatchMultisig(const CScript& script, unsigned int& required, unsigned int& keys)
+{
+    opcodetype opcode;
+    valtype data;
+    CScript::const_iterator it = script.begin();
+    if (script.size() < 1 || script.back() != OP_CHECKMULTISIG) return false;
+
+    if (!script.GetOp(it, opcode, data) || !IsSmallInteger(opcode)) return false;
+    required = CScript::DecodeOP_N(opcode);
+    unsigned int pubkeys = 0;
+    while (script.GetOp(it, opcode, data) && CPubKey::ValidSize(data)) {
+        pubkeys++;
+    }
+    if (!IsSmallInteger(opcode)) return false;
+    keys = CScript::DecodeOP_N(opcode);
+    if (pubkeys != keys || keys < required) return false;
+    return (it + 1 == script.end());
+}
+
+/** Classify scriptPubKey like Solver in script/standard.cpp, in elements mode. */
+OutputType Solver(const CScript& scriptPubKey, const StandardPolicy& policy, unsigned int& required, unsigned int& keys)
+{
+    if (policy.anyonecanspend_aremine && scriptPubKey == CScript() << OP_TRUE) {
+        return OutputType::OP_TRUE;
+    }
+
+    // Fee outputs are for elements-style transactions only
+    if (scriptPubKey == CScript()) {
+        return OutputType::FEE;
+    }
+
+    if (scriptPubKey.IsPayToScriptHash()) {
+        return OutputType::SCRIPTHASH;
+    }
+
+    int witnessversion;
+    std::vector<unsigned char> witnessprogram;
+    if (scriptPubKey.IsWitnessProgram(witnessversion, witnessprogram)) {
+        if (witnessversion == 0 && witnessprogram.size() == WITNESS_V0_KEYHASH_SIZE) {
+            return OutputType::WITNESS_V0_KEYHASH;
+        }
+        if (witnessversion == 0 && witnessprogram.size() == WITNESS_V0_SCRIPTHASH_SIZE) {
+            return OutputType::WITNESS_V0_SCRIPTHASH;
+        }
+        if (witnessversion == 1 && witnessprogram.size() == WITNESS_V1_TAPROOT_SIZE) {
+            return OutputType::WITNESS_V1_TAPROOT;
+        }
+        if (witnessversion != 0) {
+            return OutputType::WITNESS_UNKNOWN;
+        }
+        return OutputType::NONSTANDARD;
+    }
+
+    // Provably prunable, data-carrying output
+    if (scriptPubKey.size() >= 1 && scriptPubKey[0] == OP_RETURN && scriptPubKey.IsPushOnly(scriptPubKey.begin()+1)) {
+        return OutputType::NULL_DATA;
+    }
+
+    if (MatchPayToPubkey(scriptPubKey)) {
+        return OutputType::PUBKEY;
+    }
+
+    if (MatchPayToPubkeyHash(scriptPubKey)) {
+        return OutputType::PUBKEYHASH;
+    }
+
+    if (MatchMultisig(scriptPubKey, required, keys)) {
+        return OutputType::MULTISIG;
+    }
+
+    return OutputType::NONSTANDARD;
+}
+
+bool IsStandard(const CScript& scriptPubKey, const StandardPolicy& policy, OutputType& whichType)
+{
+    unsigned int m = 0;
+    unsigned int n = 0;
+    whichType = Solver(scriptPubKey, policy, m, n);
+
+    if (whichType == OutputType::NONSTANDARD) {
+        return false;
+    } else if (whichType == OutputType::MULTISIG) {
+        // Support up to x-of-3 multisig txns as standard
+        if (n < 1 || n > 3)
+            return false;
+        if (m < 1 || m > n)
+            return false;
+    } else if (whichType == OutputType::NULL_DATA && policy.enforce_pak &&
+            scriptPubKey.IsPegoutScript(policy.parent_genesis_hash)) {
+        // If we're enforcing pak let through larger peg-out scripts
+        return true;
+    } else if (whichType == OutputType::NULL_DATA &&
+               (!policy.accept_datacarrier || scriptPubKey.size() > policy.max_datacarrier_bytes)) {
+        return false;
+    }
+
+    return true;
+}
+
+CAmount GetDustThreshold(const CTxOut& txout, const CFeeRate& dustRelayFeeIn)
+{
+    // "Dust" is defined in terms of dustRelayFee,
+    // which has units satoshis-per-kilobyte.
+    // If you'd pay more in fees than the value of the output
+    // to spend something, then we consider it dust.
+    // A typical spendable non-segwit txout is 34 bytes big, and will
+    // need a CTxIn of at least 148 bytes to spend:
+    // so dust is a spendable txout less than
+    // 182*dustRelayFee/1000 (in satoshis).
+    // 546 satoshis at the default rate of 3000 sat/kB.
+    // A typical spendable segwit txout is 31 bytes big, and will
+    // need a CTxIn of at least 67 bytes to spend:
+    // so dust is a spendable txout less than
+    // 98*dustRelayFee/1000 (in satoshis).
+    // 294 satoshis at the default rate of 3000 sat/kB.
+    if (txout.scriptPubKey.IsUnspendable())
+        return 0;
+
+    size_t nSize = GetSerializeSize(txout);
+    int witnessversion = 0;
+    std::vector<unsigned char> witnessprogram;
+
+    if (txout.scriptPubKey.IsWitnessProgram(witnessversion, witnessprogram)) {
+        // sum the sizes of the parts of a transaction input
+        // with 75% segwit discount applied to the script size.
+        nSize += (32 + 4 + 1 + (107 / WITNESS_SCALE_FACTOR) + 4);
+    } else {
+        nSize += (32 + 4 + 1 + 107 + 4); // the 148 mentioned above
+    }
+
+    return dustRelayFeeIn.GetFee(nSize);
+}
+
+bool IsDust(const CTxOut& txout, const CFeeRate& dustRelayFeeIn)
+{
+    if (!txout.nValue.IsExplicit())
+        return false; // FIXME
+    if (!txout.nAsset.IsExplicit())
+        return false;
+    if (txout.IsFee())
+        return false;
+    return (txout.nValue.GetAmount() < GetDustThreshold(txout, dustRelayFeeIn));
+}
+
+/** Convert scriptSig into a stack, returning its last element as the P2SH redeemScript. */
+bool GetRedeemScript(const CScript& scriptSig, CScript& redeem_script)
+{
+    std::vector<std::vector<unsigned char> > stack;
+    if (!EvalScript(stack, scriptSig, SCRIPT_VERIFY_NONE, BaseSignatureChecker(), SigVersion::BASE))
+        return false;
+    if (stack.empty())
+        return false;
+    redeem_script = CScript(stack.back().begin(), stack.back().end());
+    return true;
+}
+
+} // namespace
+
+StandardError CheckStandardTx(const CTransaction& tx, const StandardPolicy& policy, size_t& index)
+{
+    if (tx.nVersion > CTransaction::MAX_STANDARD_VERSION || tx.nVersion < 1) {
+        return StandardError::VERSION;
+    }
+
+    // Limiting transactions to MAX_STANDARD_TX_WEIGHT mitigates CPU exhaustion attacks.
+    unsigned int sz = GetTransactionWeight(tx);
+    if (sz > MAX_STANDARD_TX_WEIGHT) {
+        return StandardError::TX_SIZE;
+    }
+
+    for (index = 0; index < tx.vin.size(); index++) {
+        const CTxIn& txin = tx.vin[index];
+        // Biggest 'standard' txin is a 15-of-15 P2SH multisig with compressed
+        // keys, rounded off to 1650 bytes of scriptSig.
+        if (txin.scriptSig.size() > 1650) {
+            return StandardError::SCRIPTSIG_SIZE;
+        }
+        if (!txin.scriptSig.IsPushOnly()) {
+            return StandardError::SCRIPTSIG_NOT_PUSHONLY;
+        }
+    }
+
+    const CFeeRate dust_relay_fee(policy.dust_relay_fee);
+    unsigned int nDataOut = 0;
+    OutputType whichType;
+    for (index = 0; index < tx.vout.size(); index++) {
+        const CTxOut& txout = tx.vout[index];
+        if (!IsStandard(txout.scriptPubKey, policy, whichType)) {
+            return StandardError::SCRIPTPUBKEY;
+        }
+
+        if (whichType == OutputType::NULL_DATA) {
+            nDataOut++;
+        } else if ((whichType == OutputType::MULTISIG) && (!policy.permit_bare_multisig)) {
+            return StandardError::BARE_MULTISIG;
+        } else if ((txout.nAsset.IsExplicit() && txout.nAsset.GetAsset() == policy.policy_asset) && IsDust(txout, dust_relay_fee)) {
+            return StandardError::DUST;
+        }
+    }
+    index = 0;
+
+    // only one OP_RETURN txout is permitted
+    if (!policy.multi_data_permitted && nDataOut > 1) {
+        return StandardError::MULTI_OP_RETURN;
+    }
+
+    // Transactions smaller than this are not relayed to mitigate CVE-2017-12842 by not relaying
+    // 64-byte transactions.
+    if (::GetSerializeSize(tx, PROTOCOL_VERSION | SERIALIZE_TRANSACTION_NO_WITNESS) < MIN_STANDARD_TX_NONWITNESS_SIZE) {
+        return StandardError::TX_SIZE_SMALL;
+    }
+
+    return StandardError::OK;
+}
+
+bool AreInputsStandard(const CTransaction& tx, const std::vector<CTxOut>& spent_outputs, const StandardPolicy& policy, size_t& index)
+{
+    if (tx.IsCoinBase())
+        return true; // Coinbases don't use vin normally
+
+    for (index = 0; index < tx.vin.size(); index++) {
+        if (tx.vin[index].m_is_pegin) {
+            // This deals with p2sh in general only
+            continue;
+        }
+
+        const CTxOut& prev = spent_outputs[index];
+
+        unsigned int required = 0;
+        unsigned int keys = 0;
+        OutputType whichType = Solver(prev.scriptPubKey, policy, required, keys);
+        if (whichType == OutputType::NONSTANDARD || whichType == OutputType::WITNESS_UNKNOWN) {
+            return false;
+        } else if (whichType == OutputType::SCRIPTHASH) {
+            CScript subscript;
+            if (!GetRedeemScript(tx.vin[index].scriptSig, subscript))
+                return false;
+            if (subscript.GetSigOpCount(true) > MAX_P2SH_SIGOPS) {
+                return false;
+            }
+        } else if (whichType == OutputType::WITNESS_V1_TAPROOT) {
+            // Don't allow Taproot spends unless Taproot is active.
+            if (!policy.taproot_active) return false;
+        }
+    }
+
+    index = 0;
+    return true;
+}
+
+bool IsWitnessStandard(const CTransaction& tx, const std::vector<CTxOut>& spent_outputs, size_t& index)
+{
+    if (tx.IsCoinBase())
+        return true; // Coinbases are skipped
+
+    for (index = 0; index < tx.vin.size(); index++) {
+        // We don't care if witness for this input is empty, since it must not be bloated.
+        // If the script is invalid without witness, it would be caught sooner or later during validation.
+        if (tx.witness.vtxinwit.size() <= index || tx.witness.vtxinwit[index].scriptWitness.IsNull()) {
+            continue;
+        }
+
+        // get the scriptPubKey corresponding to this input:
+        CScript prevScript = spent_outputs[index].scriptPubKey;
+
+        bool p2sh = false;
+        if (prevScript.IsPayToScriptHash()) {
+            // If the scriptPubKey is P2SH, we try to extract the redeemScript casually by converting the scriptSig
+            // into a stack. We do not check IsPushOnly nor compare the hash as these will be done later anyway.
+            if (!GetRedeemScript(tx.vin[index].scriptSig, prevScript))
+                return false;
+            p2sh = true;
+        }
+
+        int witnessversion = 0;
+        std::vector<unsigned char> witnessprogram;
+
+        // Non-witness program must not be associated with any witness
+        if (!prevScript.IsWitnessProgram(witnessversion, witnessprogram))
+            return false;
+
+        // Check P2WSH standard limits
+        if (witnessversion == 0 && witnessprogram.size() == WITNESS_V0_SCRIPTHASH_SIZE) {
+            const CScriptWitness& scriptWitness = tx.witness.vtxinwit[index].scriptWitness;
+            if (scriptWitness.stack.back().size() > MAX_STANDARD_P2WSH_SCRIPT_SIZE)
+                return false;
+            size_t sizeWitnessStack = scriptWitness.stack.size() - 1;
+            if (sizeWitnessStack > MAX_STANDARD_P2WSH_STACK_ITEMS)
+                return false;
+            for (unsigned int j = 0; j < sizeWitnessStack; j++) {
+                if (scriptWitness.stack[j].size() > MAX_STANDARD_P2WSH_STACK_ITEM_SIZE)
+                    return false;
+            }
+        }
+
+        // Check policy limits for Taproot spends:
+        // - MAX_STANDARD_TAPSCRIPT_STACK_ITEM_SIZE limit for stack item size
+        // - No annexes
+        if (witnessversion == 1 && witnessprogram.size() == WITNESS_V1_TAPROOT_SIZE && !p2sh) {
+            // Taproot spend (non-P2SH-wrapped, version 1, witness program size 32; see BIP 341)
+            auto stack = MakeSpan(tx.witness.vtxinwit[index].scriptWitness.stack);
+            if (stack.size() >= 2 && !stack.back().empty() && stack.back()[0] == ANNEX_TAG) {
+                // Annexes are nonstandard as long as no semantics are defined for them.
+                return false;
+            }
+            if (stack.size() >= 2) {
+                // Script path spend (2 or more stack elements after removing optional annex)
+                const auto& control_block = SpanPopBack(stack);
+                SpanPopBack(stack); // Ignore script
+                if (control_block.empty()) return false; // Empty control block is invalid
+                if ((control_block[0] & TAPROOT_LEAF_MASK) == TAPROOT_LEAF_TAPSCRIPT) {
+                    // Leaf version 0xc0 (aka Tapscript, see BIP 342)
+                    for (const auto& item : stack) {
+                        if (item.size() > MAX_STANDARD_TAPSCRIPT_STACK_ITEM_SIZE) return false;
+                    }
+                }
+            } else if (stack.size() == 1) {
+                // Key path spend (1 stack element after removing optional annex)
+                // (no policy rules apply)
+            } else {
+                // 0 stack elements; this is already invalid by consensus rules
+                return false;
+            }
+        }
+    }
+
+    index = 0;
+    return true;
+}
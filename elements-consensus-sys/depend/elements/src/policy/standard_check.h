@@ -0,0 +1,117 @@
+// Copyright (c) 2009-2010 Satoshi Nakamoto
+// Copyright (c) 2009-2021 The Bitcoin Core developers
+// Distributed under the MIT software license, see the accompanying
+// file COPYING or http://www.opensource.org/licenses/mit-license.php.
+
+#ifndef BITCOIN_POLICY_STANDARD_CHECK_H
+#define BITCOIN_POLICY_STANDARD_CHECK_H
+
+#include <amount.h>
+#include <asset.h>
+#include <consensus/consensus.h>
+#include <script/interpreter.h>
+#include <uint256.h>
+
+#include <vector>
+
+/**
+ * Standardness checks of policy.cpp and MemPoolAccept::PreChecks that do not
+ * depend on the chain parameters or the global settings of a node, so that they
+ * can be called outside the elements server. The settings are passed in a
+ * StandardPolicy instead. IsStandardTx and AreInputsStandard in policy.h are
+ * the server's entry points.
+ */
+
+class CTransaction;
+class CTxOut;
+
+/** STANDARD_SCRIPT_VERIFY_FLAGS of policy.h, which depends on script/standard.h */
+static constexpr unsigned int STANDARD_CHECK_SCRIPT_VERIFY_FLAGS = SCRIPT_VERIFY_P2SH |
+                                                                   SCRIPT_VERIFY_DERSIG |
+                                                                   SCRIPT_VERIFY_STRICTENC |
+                                                                   SCRIPT_VERIFY_MINIMALDATA |
+                                                                   SCRIPT_VERIFY_NULLDUMMY |
+                                                                   SCRIPT_VERIFY_DISCOURAGE_UPGRADABLE_NOPS |
+                                                                   SCRIPT_VERIFY_CLEANSTACK |
+                                                                   SCRIPT_VERIFY_MINIMALIF |
+                                                                   SCRIPT_VERIFY_NULLFAIL |
+                                                                   SCRIPT_VERIFY_CHECKLOCKTIMEVERIFY |
+                                                                   SCRIPT_VERIFY_CHECKSEQUENCEVERIFY |
+                                                                   SCRIPT_VERIFY_LOW_S |
+                                                                   SCRIPT_VERIFY_WITNESS |
+                                                                   SCRIPT_VERIFY_DISCOURAGE_UPGRADABLE_WITNESS_PROGRAM |
+                                                                   SCRIPT_VERIFY_WITNESS_PUBKEYTYPE |
+                                                                   SCRIPT_VERIFY_CONST_SCRIPTCODE |
+                                                                   SCRIPT_VERIFY_TAPROOT |
+                                                                   SCRIPT_VERIFY_DISCOURAGE_UPGRADABLE_TAPROOT_VERSION |
+                                                                   SCRIPT_VERIFY_DISCOURAGE_OP_SUCCESS |
+                                                                   SCRIPT_VERIFY_DISCOURAGE_UPGRADABLE_PUBKEYTYPE;
+
+/** MANDATORY_SCRIPT_VERIFY_FLAGS of script/standard.h, whose violation is a consensus failure rather than a policy one */
+static constexpr unsigned int STANDARD_CHECK_MANDATORY_VERIFY_FLAGS = SCRIPT_VERIFY_P2SH;
+
+/** MAX_STANDARD_TX_SIGOPS_COST of policy.h */
+static constexpr int64_t STANDARD_CHECK_MAX_TX_SIGOPS_COST = MAX_BLOCK_SIGOPS_COST/5;
+
+/** The node settings and chain parameters that standardness depends on. */
+struct StandardPolicy {
+    //! Only outputs of this asset can be dust
+    CAsset policy_asset;
+    //! Peg-outs to this chain may carry more data than -datacarriersize when PAK is enforced
+    uint256 parent_genesis_hash;
+    //! -dustrelayfee, in satoshi per kvB
+    CAmount dust_relay_fee{3000};
+    //! -permitbaremultisig
+    bool permit_bare_multisig{true};
+    //! -datacarrier
+    bool accept_datacarrier{true};
+    //! -datacarriersize
+    unsigned int max_datacarrier_bytes{83};
+    //! -multi_data_permitted
+    bool multi_data_permitted{false};
+    //! -enforce_pak
+    bool enforce_pak{false};
+    //! -anyonecanspendaremine, which makes OP_TRUE outputs standard
+    bool anyonecanspend_aremine{false};
+    //! Whether Taproot outputs may be spent
+    bool taproot_active{true};
+};
+
+/** The standardness check that a transaction failed, see IsStandardTx and MemPoolAccept::PreChecks. */
+enum class StandardError {
+    OK,
+    VERSION,                //!< version
+    TX_SIZE,                //!< tx-size
+    SCRIPTSIG_SIZE,         //!< scriptsig-size
+    SCRIPTSIG_NOT_PUSHONLY, //!< scriptsig-not-pushonly
+    SCRIPTPUBKEY,           //!< scriptpubkey
+    BARE_MULTISIG,          //!< bare-multisig
+    DUST,                   //!< dust
+    MULTI_OP_RETURN,        //!< multi-op-return
+    TX_SIZE_SMALL,          //!< tx-size-small
+    INPUTS,                 //!< bad-txns-nonstandard-inputs
+    WITNESS,                //!< bad-witness-nonstandard
+};
+
+/**
+ * Check for standard transaction types and sizes, like IsStandardTx followed by
+ * the minimum non-witness size check of MemPoolAccept::PreChecks.
+ * @param[out] index  The offending input or output, if the check is about one
+ */
+StandardError CheckStandardTx(const CTransaction& tx, const StandardPolicy& policy, size_t& index);
+
+/**
+ * Check for standard scriptSigs and spent scriptPubKeys, like AreInputsStandard.
+ * @param[in] spent_outputs  The outputs spent by all inputs of tx, including the ones claimed by peg-ins
+ * @param[out] index         The offending input
+ */
+bool AreInputsStandard(const CTransaction& tx, const std::vector<CTxOut>& spent_outputs, const StandardPolicy& policy, size_t& index);
+
+/**
+ * Check if the transaction is over standard P2WSH and Taproot resource limits, like IsWitnessStandard.
+ * @param[in] spent_outputs  The outputs spent by all inputs of tx, including the ones claimed by peg-ins
+ * @param[out] index         The offending input
+ */
+bool IsWitnessStandard(const CTransaction& tx, const std::vector<CTxOut>& spent_outputs, size_t& index);
+
+#endif // BITCOIN_POLICY_STANDARD_CHECK_H
@@ -9,8 +9,8 @@
 #include <tinyformat.h>
 
 
-bool g_con_blockheightinheader = false;
-bool g_signed_blocks = false;
+thread_local bool g_con_blockheightinheader = false;
+thread_local bool g_signed_blocks = false;
 
 std::string CProof::ToString() const
 {
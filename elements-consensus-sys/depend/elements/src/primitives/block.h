@@ -13,8 +13,8 @@
 
 // ELEMENTS:
 // Globals to avoid circular dependencies.
-extern bool g_con_blockheightinheader;
-extern bool g_signed_blocks;
+extern thread_local bool g_con_blockheightinheader;
+extern thread_local bool g_signed_blocks;
 
 class CProof
 {
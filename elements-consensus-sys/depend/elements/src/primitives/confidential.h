@@ -10,7 +10,7 @@
 #include <uint256.h>
 #include <util/strencodings.h>
 
-extern bool g_con_elementsmode;
+extern thread_local bool g_con_elementsmode;
 
 /**
  * Confidential assets, values, and nonces all share enough code in common
@@ -11,7 +11,9 @@
 
 #include <assert.h>
 
-bool g_con_elementsmode = true;
+// Thread-local so that the consensus library can verify Bitcoin transactions on one thread
+// while it verifies Elements transactions on another.
+thread_local bool g_con_elementsmode = true;
 
 const int32_t CTransaction::CURRENT_VERSION = 2;
 
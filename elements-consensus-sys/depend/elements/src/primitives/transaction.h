@@ -26,7 +26,7 @@ static const int SERIALIZE_TRANSACTION_NO_WITNESS = 0x40000000;
 
 // ELEMENTS:
 // Globals to avoid circular dependencies.
-extern bool g_con_elementsmode;
+extern thread_local bool g_con_elementsmode;
 
 /** An outpoint - a combination of a transaction hash and an index n into its vout */
 class COutPoint
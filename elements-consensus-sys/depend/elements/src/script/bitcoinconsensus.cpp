@@ -5,8 +5,18 @@
 
 #include <script/bitcoinconsensus.h>
 
+#include <confidential_validation.h>
+#include <consensus/merkle.h>
+#include <consensus/pegin_check.h>
+#include <consensus/tx_check.h>
+#include <consensus/validation.h>
+#include <crypto/sha256.h>
+#include <hash.h>
+#include <policy/standard_check.h>
+#include <primitives/block.h>
 #include <primitives/transaction.h>
 #include <pubkey.h>
+#include <secp256k1_whitelist.h>
 #include <script/interpreter.h>
 #include <streams.h>
 #include <version.h>
@@ -74,13 +84,30 @@ ECCryptoClosure instance_of_eccryptoclosure;
 /** Check that all specified flags are part of the libconsensus interface. */
 static bool verify_flags(unsigned int flags)
 {
-    return (flags & ~(bitcoinconsensus_SCRIPT_FLAGS_VERIFY_ALL)) == 0;
+    if ((flags & ~(bitcoinconsensus_SCRIPT_FLAGS_VERIFY_ALL | bitcoinconsensus_SCRIPT_FLAGS_VERIFY_SIGHASH_RANGEPROOF)) != 0) {
+        return false;
+    }
+    // The interpreter asserts that WITNESS implies P2SH, reject the combination instead of aborting.
+    if ((flags & bitcoinconsensus_SCRIPT_FLAGS_VERIFY_WITNESS) && !(flags & bitcoinconsensus_SCRIPT_FLAGS_VERIFY_P2SH)) {
+        return false;
+    }
+    return true;
+}
+
+/** Check whether verifying scriptPubKey under flags requires the outputs spent by all inputs of the transaction. */
+static bool requires_spent_outputs(const CScript& scriptPubKey, unsigned int flags)
+{
+    int witnessversion;
+    std::vector<unsigned char> witnessprogram;
+    return (flags & bitcoinconsensus_SCRIPT_FLAGS_VERIFY_TAPROOT) &&
+           scriptPubKey.IsWitnessProgram(witnessversion, witnessprogram) &&
+           witnessversion == 1 && witnessprogram.size() == WITNESS_V1_TAPROOT_SIZE;
 }
 
 static int verify_script(const unsigned char *hash_genesis_block,
                                     const unsigned char *scriptPubKey, unsigned int scriptPubKeyLen, CConfidentialValue amount,
                                     const unsigned char *txTo        , unsigned int txToLen,
-                                    unsigned int nIn, unsigned int flags, bitcoinconsensus_error* err)
+                                    unsigned int nIn, unsigned int flags, bitcoinconsensus_error* err, ScriptError* serror)
 {
     if (!verify_flags(flags)) {
         return set_error(err, bitcoinconsensus_ERR_INVALID_FLAGS);
@@ -92,6 +119,9 @@ static int verify_script(const unsigned char *hash_genesis_block,
             return set_error(err, bitcoinconsensus_ERR_TX_INDEX);
         if (GetSerializeSize(tx, PROTOCOL_VERSION) != txToLen)
             return set_error(err, bitcoinconsensus_ERR_TX_SIZE_MISMATCH);
+        const CScript spk(scriptPubKey, scriptPubKey + scriptPubKeyLen);
+        if (requires_spent_outputs(spk, flags))
+            return set_error(err, bitcoinconsensus_ERR_SPENT_OUTPUTS_REQUIRED);
 
         // Regardless of the verification result, the tx did not error.
         set_error(err, bitcoinconsensus_ERR_OK);
@@ -100,7 +130,7 @@ static int verify_script(const unsigned char *hash_genesis_block,
         PrecomputedTransactionData txdata(hash_genesis_block_);
         txdata.Init(tx, {});
         const CScriptWitness* pScriptWitness = (tx.witness.vtxinwit.size() > nIn ? &tx.witness.vtxinwit[nIn].scriptWitness : NULL);
-        return VerifyScript(tx.vin[nIn].scriptSig, CScript(scriptPubKey, scriptPubKey + scriptPubKeyLen), pScriptWitness, flags, TransactionSignatureChecker(&tx, nIn, amount, txdata), NULL);
+        return VerifyScript(tx.vin[nIn].scriptSig, spk, pScriptWitness, flags, TransactionSignatureChecker(&tx, nIn, amount, txdata), serror);
     } catch (const std::exception&) {
         return set_error(err, bitcoinconsensus_ERR_TX_DESERIALIZE); // Error deserializing
     }
@@ -112,17 +142,1287 @@ int bitcoinconsensus_verify_script_with_amount(const unsigned char *hash_genesis
                                     const unsigned char *txTo        , unsigned int txToLen,
                                     unsigned int nIn, unsigned int flags, bitcoinconsensus_error* err)
 {
+    return bitcoinconsensus_verify_script_with_amount_and_script_error(hash_genesis_block, scriptPubKey, scriptPubKeyLen, amount, amountLen, txTo, txToLen, nIn, flags, err, nullptr);
+}
+
+int bitcoinconsensus_verify_script_with_amount_and_script_error(const unsigned char *hash_genesis_block,
+                                    const unsigned char *scriptPubKey, unsigned int scriptPubKeyLen,
+                                    const unsigned char *amount, unsigned int amountLen,
+                                    const unsigned char *txTo        , unsigned int txToLen,
+                                    unsigned int nIn, unsigned int flags, bitcoinconsensus_error* err,
+                                    unsigned int* script_err)
+{
+    ScriptError serror = SCRIPT_ERR_UNKNOWN_ERROR;
+    int ret;
     try {
         TxInputStream stream(SER_NETWORK, PROTOCOL_VERSION, amount, amountLen);
         CConfidentialValue am;
         stream >> am;
 
-        return ::verify_script(hash_genesis_block, scriptPubKey, scriptPubKeyLen, am, txTo, txToLen, nIn, flags, err);
+        ret = ::verify_script(hash_genesis_block, scriptPubKey, scriptPubKeyLen, am, txTo, txToLen, nIn, flags, err, &serror);
+    } catch (const std::exception&) {
+        ret = set_error(err, bitcoinconsensus_ERR_TX_DESERIALIZE); // Error deserializing
+    }
+    if (script_err)
+        *script_err = serror;
+    return ret;
+}
+
+namespace {
+
+/** Switches Elements mode off for the calling thread for as long as it is in scope. */
+class BitcoinModeScope
+{
+public:
+    BitcoinModeScope() : m_previous(g_con_elementsmode) { g_con_elementsmode = false; }
+    ~BitcoinModeScope() { g_con_elementsmode = m_previous; }
+
+    BitcoinModeScope(const BitcoinModeScope&) = delete;
+    BitcoinModeScope& operator=(const BitcoinModeScope&) = delete;
+
+private:
+    const bool m_previous;
+};
+
+} // namespace
+
+int bitcoinconsensus_verify_script_bitcoin(const unsigned char *scriptPubKey, unsigned int scriptPubKeyLen, int64_t amount,
+                                    const unsigned char *txTo        , unsigned int txToLen,
+                                    unsigned int nIn, unsigned int flags, bitcoinconsensus_error* err,
+                                    unsigned int* script_err)
+{
+    ScriptError serror = SCRIPT_ERR_UNKNOWN_ERROR;
+    int ret;
+    // Taproot is implemented with the tagged hashes and signature hash of Elements only, and SIGHASH_RANGEPROOF does not exist in Bitcoin.
+    if (flags & (bitcoinconsensus_SCRIPT_FLAGS_VERIFY_TAPROOT | bitcoinconsensus_SCRIPT_FLAGS_VERIFY_SIGHASH_RANGEPROOF)) {
+        ret = set_error(err, bitcoinconsensus_ERR_INVALID_FLAGS);
+    } else {
+        BitcoinModeScope bitcoin_mode;
+        ret = ::verify_script(nullptr, scriptPubKey, scriptPubKeyLen, CConfidentialValue(amount), txTo, txToLen, nIn, flags, err, &serror);
+    }
+    if (script_err)
+        *script_err = serror;
+    return ret;
+}
+
+struct bitcoinconsensus_transaction
+{
+    const CTransaction tx;
+    PrecomputedTransactionData txdata;
+
+    bitcoinconsensus_transaction(CTransaction&& tx_in, std::vector<CTxOut>&& spent_outputs, const uint256& hash_genesis_block)
+        : tx(std::move(tx_in)), txdata(hash_genesis_block)
+    {
+        txdata.Init(tx, std::move(spent_outputs));
+    }
+};
+
+/** Deserialize the transaction and, if given, the outputs it spends. Returns nullptr and sets err on failure. */
+static bitcoinconsensus_transaction* create_transaction(const unsigned char *hash_genesis_block,
+                                    const unsigned char *txTo, unsigned int txToLen,
+                                    const unsigned char *spentOutputs, unsigned int spentOutputsLen,
+                                    bool with_spent_outputs, bitcoinconsensus_error* err)
+{
+    try {
+        TxInputStream stream(SER_NETWORK, PROTOCOL_VERSION, txTo, txToLen);
+        CTransaction tx(deserialize, stream);
+        if (GetSerializeSize(tx, PROTOCOL_VERSION) != txToLen) {
+            set_error(err, bitcoinconsensus_ERR_TX_SIZE_MISMATCH);
+            return nullptr;
+        }
+
+        std::vector<CTxOut> spent_outputs;
+        if (with_spent_outputs) {
+            TxInputStream spent_outputs_stream(SER_NETWORK, PROTOCOL_VERSION, spentOutputs, spentOutputsLen);
+            spent_outputs_stream >> spent_outputs;
+            if (GetSerializeSize(spent_outputs, PROTOCOL_VERSION) != spentOutputsLen) {
+                set_error(err, bitcoinconsensus_ERR_TX_DESERIALIZE);
+                return nullptr;
+            }
+            if (spent_outputs.size() != tx.vin.size()) {
+                set_error(err, bitcoinconsensus_ERR_SPENT_OUTPUTS_MISMATCH);
+                return nullptr;
+            }
+        }
+
+        auto hash_genesis_block_ = hash_genesis_block ? uint256{hash_genesis_block, 32} : uint256{};
+        auto* ret = new bitcoinconsensus_transaction(std::move(tx), std::move(spent_outputs), hash_genesis_block_);
+        set_error(err, bitcoinconsensus_ERR_OK);
+        return ret;
+    } catch (const std::exception&) {
+        set_error(err, bitcoinconsensus_ERR_TX_DESERIALIZE); // Error deserializing
+        return nullptr;
+    }
+}
+
+/** A signature checker that reports the execution of scripts to a bitcoinconsensus_tracer. */
+class TracingSignatureChecker : public TransactionSignatureChecker, private ScriptTracer
+{
+private:
+    const bitcoinconsensus_tracer& m_tracer;
+
+public:
+    TracingSignatureChecker(const CTransaction* txToIn, unsigned int nInIn, const CConfidentialValue& amountIn, const PrecomputedTransactionData& txdataIn, const bitcoinconsensus_tracer& tracer)
+        : TransactionSignatureChecker(txToIn, nInIn, amountIn, txdataIn), m_tracer(tracer) {}
+
+    const ScriptTracer* GetTracer() const override
+    {
+        return this;
+    }
+
+private:
+    void TraceScript(const CScript& script) const override
+    {
+        if (m_tracer.script)
+            m_tracer.script(m_tracer.ctx, script.data(), script.size());
+    }
+
+    void TraceStep(opcodetype opcode, uint32_t pc, bool executed, const std::vector<std::vector<unsigned char>>& stack, const std::vector<std::vector<unsigned char>>& altstack) const override
+    {
+        if (!m_tracer.step)
+            return;
+        CDataStream serialized_stack(SER_NETWORK, PROTOCOL_VERSION);
+        serialized_stack << stack;
+        CDataStream serialized_altstack(SER_NETWORK, PROTOCOL_VERSION);
+        serialized_altstack << altstack;
+        m_tracer.step(m_tracer.ctx, opcode, pc, executed,
+                      (const unsigned char*)serialized_stack.data(), serialized_stack.size(),
+                      (const unsigned char*)serialized_altstack.data(), serialized_altstack.size());
+    }
+};
+
+static int verify_input(const bitcoinconsensus_transaction* tx, const CScript& scriptPubKey, const CConfidentialValue& amount,
+                                    unsigned int nIn, unsigned int flags, const bitcoinconsensus_tracer* tracer,
+                                    bitcoinconsensus_error* err, ScriptError* serror)
+{
+    if (!verify_flags(flags)) {
+        return set_error(err, bitcoinconsensus_ERR_INVALID_FLAGS);
+    }
+    if (nIn >= tx->tx.vin.size()) {
+        return set_error(err, bitcoinconsensus_ERR_TX_INDEX);
+    }
+    if (!tx->txdata.m_spent_outputs_ready && requires_spent_outputs(scriptPubKey, flags)) {
+        return set_error(err, bitcoinconsensus_ERR_SPENT_OUTPUTS_REQUIRED);
+    }
+
+    // Regardless of the verification result, the tx did not error.
+    set_error(err, bitcoinconsensus_ERR_OK);
+
+    const CScriptWitness* pScriptWitness = (tx->tx.witness.vtxinwit.size() > nIn ? &tx->tx.witness.vtxinwit[nIn].scriptWitness : NULL);
+    if (tracer) {
+        return VerifyScript(tx->tx.vin[nIn].scriptSig, scriptPubKey, pScriptWitness, flags, TracingSignatureChecker(&tx->tx, nIn, amount, tx->txdata, *tracer), serror);
+    }
+    return VerifyScript(tx->tx.vin[nIn].scriptSig, scriptPubKey, pScriptWitness, flags, TransactionSignatureChecker(&tx->tx, nIn, amount, tx->txdata), serror);
+}
+
+int bitcoinconsensus_verify_script_with_spent_outputs(const unsigned char *hash_genesis_block,
+                                    const unsigned char *txTo        , unsigned int txToLen,
+                                    const unsigned char *spentOutputs, unsigned int spentOutputsLen,
+                                    unsigned int nIn, unsigned int flags, bitcoinconsensus_error* err,
+                                    unsigned int* script_err)
+{
+    ScriptError serror = SCRIPT_ERR_UNKNOWN_ERROR;
+    int ret;
+    if (!verify_flags(flags)) {
+        ret = set_error(err, bitcoinconsensus_ERR_INVALID_FLAGS);
+    } else {
+        std::unique_ptr<bitcoinconsensus_transaction> tx{create_transaction(hash_genesis_block, txTo, txToLen, spentOutputs, spentOutputsLen, true, err)};
+        if (!tx) {
+            ret = 0; // err is set
+        } else if (nIn >= tx->tx.vin.size()) {
+            ret = set_error(err, bitcoinconsensus_ERR_TX_INDEX);
+        } else {
+            const CTxOut& spent_output = tx->txdata.m_spent_outputs[nIn];
+            ret = ::verify_input(tx.get(), spent_output.scriptPubKey, spent_output.nValue, nIn, flags, nullptr, err, &serror);
+        }
+    }
+    if (script_err)
+        *script_err = serror;
+    return ret;
+}
+
+bitcoinconsensus_transaction* bitcoinconsensus_transaction_create(const unsigned char *hash_genesis_block,
+                                    const unsigned char *txTo, unsigned int txToLen, bitcoinconsensus_error* err)
+{
+    return create_transaction(hash_genesis_block, txTo, txToLen, nullptr, 0, false, err);
+}
+
+bitcoinconsensus_transaction* bitcoinconsensus_transaction_create_with_spent_outputs(const unsigned char *hash_genesis_block,
+                                    const unsigned char *txTo, unsigned int txToLen,
+                                    const unsigned char *spentOutputs, unsigned int spentOutputsLen,
+                                    bitcoinconsensus_error* err)
+{
+    return create_transaction(hash_genesis_block, txTo, txToLen, spentOutputs, spentOutputsLen, true, err);
+}
+
+void bitcoinconsensus_transaction_destroy(bitcoinconsensus_transaction* tx)
+{
+    delete tx;
+}
+
+int bitcoinconsensus_transaction_verify_input(const bitcoinconsensus_transaction* tx,
+                                    const unsigned char *scriptPubKey, unsigned int scriptPubKeyLen,
+                                    const unsigned char *amount, unsigned int amountLen,
+                                    unsigned int nIn, unsigned int flags, bitcoinconsensus_error* err,
+                                    unsigned int* script_err)
+{
+    return bitcoinconsensus_transaction_trace_input(tx, scriptPubKey, scriptPubKeyLen, amount, amountLen, nIn, flags, nullptr, err, script_err);
+}
+
+int bitcoinconsensus_transaction_trace_input(const bitcoinconsensus_transaction* tx,
+                                    const unsigned char *scriptPubKey, unsigned int scriptPubKeyLen,
+                                    const unsigned char *amount, unsigned int amountLen,
+                                    unsigned int nIn, unsigned int flags, const bitcoinconsensus_tracer* tracer,
+                                    bitcoinconsensus_error* err, unsigned int* script_err)
+{
+    ScriptError serror = SCRIPT_ERR_UNKNOWN_ERROR;
+    int ret;
+    try {
+        TxInputStream stream(SER_NETWORK, PROTOCOL_VERSION, amount, amountLen);
+        CConfidentialValue am;
+        stream >> am;
+
+        ret = ::verify_input(tx, CScript(scriptPubKey, scriptPubKey + scriptPubKeyLen), am, nIn, flags, tracer, err, &serror);
+    } catch (const std::exception&) {
+        ret = set_error(err, bitcoinconsensus_ERR_TX_DESERIALIZE); // Error deserializing
+    }
+    if (script_err)
+        *script_err = serror;
+    return ret;
+}
+
+int bitcoinconsensus_signature_hash(const unsigned char *hash_genesis_block,
+                                    const unsigned char *scriptCode, unsigned int scriptCodeLen,
+                                    const unsigned char *amount, unsigned int amountLen,
+                                    const unsigned char *txTo        , unsigned int txToLen,
+                                    unsigned int nIn, int nHashType, unsigned int sigversion,
+                                    unsigned char *hash, bitcoinconsensus_error* err)
+{
+    if (sigversion != bitcoinconsensus_SIGVERSION_BASE && sigversion != bitcoinconsensus_SIGVERSION_WITNESS_V0) {
+        return set_error(err, bitcoinconsensus_ERR_INVALID_SIGHASH);
+    }
+    try {
+        TxInputStream amount_stream(SER_NETWORK, PROTOCOL_VERSION, amount, amountLen);
+        CConfidentialValue am;
+        if (sigversion == bitcoinconsensus_SIGVERSION_WITNESS_V0) {
+            amount_stream >> am;
+        }
+
+        TxInputStream stream(SER_NETWORK, PROTOCOL_VERSION, txTo, txToLen);
+        CTransaction tx(deserialize, stream);
+        if (nIn >= tx.vin.size())
+            return set_error(err, bitcoinconsensus_ERR_TX_INDEX);
+        if (GetSerializeSize(tx, PROTOCOL_VERSION) != txToLen)
+            return set_error(err, bitcoinconsensus_ERR_TX_SIZE_MISMATCH);
+
+        auto hash_genesis_block_ = hash_genesis_block ? uint256{hash_genesis_block, 32} : uint256{};
+        PrecomputedTransactionData txdata(hash_genesis_block_);
+        txdata.Init(tx, {});
+        const uint256 sighash = SignatureHash(CScript(scriptCode, scriptCode + scriptCodeLen), tx, nIn, nHashType, am, static_cast<SigVersion>(sigversion), 0, &txdata);
+        memcpy(hash, sighash.begin(), sighash.size());
+        set_error(err, bitcoinconsensus_ERR_OK);
+        return 1;
     } catch (const std::exception&) {
         return set_error(err, bitcoinconsensus_ERR_TX_DESERIALIZE); // Error deserializing
     }
 }
 
+int bitcoinconsensus_signature_hash_taproot(const unsigned char *hash_genesis_block,
+                                    const unsigned char *txTo        , unsigned int txToLen,
+                                    const unsigned char *spentOutputs, unsigned int spentOutputsLen,
+                                    unsigned int nIn, unsigned char hashType,
+                                    const unsigned char *tapleafHash, unsigned int codeseparatorPos,
+                                    unsigned char *hash, bitcoinconsensus_error* err)
+{
+    try {
+        TxInputStream stream(SER_NETWORK, PROTOCOL_VERSION, txTo, txToLen);
+        CMutableTransaction mtx(deserialize, stream);
+        if (nIn >= mtx.vin.size())
+            return set_error(err, bitcoinconsensus_ERR_TX_INDEX);
+        if (GetSerializeSize(mtx, PROTOCOL_VERSION) != txToLen)
+            return set_error(err, bitcoinconsensus_ERR_TX_SIZE_MISMATCH);
+        // The signature hash commits to the issuance and output witnesses, which an unsigned
+        // transaction lacks altogether. Hash them as the empty witnesses of the signed transaction.
+        mtx.witness.vtxinwit.resize(mtx.vin.size());
+        mtx.witness.vtxoutwit.resize(mtx.vout.size());
+        const CTransaction tx(mtx);
+
+        std::vector<CTxOut> spent_outputs;
+        TxInputStream spent_outputs_stream(SER_NETWORK, PROTOCOL_VERSION, spentOutputs, spentOutputsLen);
+        spent_outputs_stream >> spent_outputs;
+        if (GetSerializeSize(spent_outputs, PROTOCOL_VERSION) != spentOutputsLen)
+            return set_error(err, bitcoinconsensus_ERR_TX_DESERIALIZE);
+        if (spent_outputs.size() != tx.vin.size())
+            return set_error(err, bitcoinconsensus_ERR_SPENT_OUTPUTS_MISMATCH);
+
+        auto hash_genesis_block_ = hash_genesis_block ? uint256{hash_genesis_block, 32} : uint256{};
+        PrecomputedTransactionData txdata(hash_genesis_block_);
+        // Force the precomputation, the transaction may not be signed yet.
+        txdata.Init(tx, std::move(spent_outputs), /* force */ true);
+
+        ScriptExecutionData execdata;
+        // A missing witness has no annex.
+        const std::vector<std::vector<unsigned char>> no_witness;
+        const std::vector<std::vector<unsigned char>>& stack = nIn < tx.witness.vtxinwit.size() ? tx.witness.vtxinwit[nIn].scriptWitness.stack : no_witness;
+        execdata.m_annex_init = true;
+        execdata.m_annex_present = stack.size() >= 2 && !stack.back().empty() && stack.back()[0] == ANNEX_TAG;
+        if (execdata.m_annex_present) {
+            execdata.m_annex_hash = (CHashWriter(SER_GETHASH, 0) << stack.back()).GetSHA256();
+        }
+        SigVersion sigversion = SigVersion::TAPROOT;
+        if (tapleafHash) {
+            sigversion = SigVersion::TAPSCRIPT;
+            execdata.m_tapleaf_hash_init = true;
+            execdata.m_tapleaf_hash = uint256{tapleafHash, 32};
+            execdata.m_codeseparator_pos_init = true;
+            execdata.m_codeseparator_pos = codeseparatorPos;
+        }
+
+        uint256 sighash;
+        if (!SignatureHashSchnorr(sighash, execdata, tx, nIn, hashType, sigversion, txdata))
+            return set_error(err, bitcoinconsensus_ERR_INVALID_SIGHASH);
+        memcpy(hash, sighash.begin(), sighash.size());
+        set_error(err, bitcoinconsensus_ERR_OK);
+        return 1;
+    } catch (const std::exception&) {
+        return set_error(err, bitcoinconsensus_ERR_TX_DESERIALIZE); // Error deserializing
+    }
+}
+
+static unsigned int amounts_error(AmountsError error)
+{
+    switch (error) {
+    case AmountsError::OK: return bitcoinconsensus_AMOUNTS_ERR_OK;
+    case AmountsError::INPUT: return bitcoinconsensus_AMOUNTS_ERR_INPUT;
+    case AmountsError::ISSUANCE: return bitcoinconsensus_AMOUNTS_ERR_ISSUANCE;
+    case AmountsError::OUTPUT: return bitcoinconsensus_AMOUNTS_ERR_OUTPUT;
+    case AmountsError::BALANCE: return bitcoinconsensus_AMOUNTS_ERR_BALANCE;
+    case AmountsError::RANGEPROOF: return bitcoinconsensus_AMOUNTS_ERR_RANGEPROOF;
+    case AmountsError::SURJECTIONPROOF: return bitcoinconsensus_AMOUNTS_ERR_SURJECTIONPROOF;
+    } // no default case, so the compiler can warn about missing cases
+    return bitcoinconsensus_AMOUNTS_ERR_UNKNOWN;
+}
+
+int bitcoinconsensus_verify_amounts(const unsigned char *txTo        , unsigned int txToLen,
+                                    const unsigned char *spentOutputs, unsigned int spentOutputsLen,
+                                    bitcoinconsensus_error* err, unsigned int* amounts_err,
+                                    unsigned int* amounts_err_index)
+{
+    unsigned int aerror = bitcoinconsensus_AMOUNTS_ERR_OK;
+    size_t aerror_index = 0;
+    int ret;
+    try {
+        TxInputStream stream(SER_NETWORK, PROTOCOL_VERSION, txTo, txToLen);
+        CTransaction tx(deserialize, stream);
+        std::vector<CTxOut> spent_outputs;
+        TxInputStream spent_outputs_stream(SER_NETWORK, PROTOCOL_VERSION, spentOutputs, spentOutputsLen);
+        spent_outputs_stream >> spent_outputs;
+
+        if (GetSerializeSize(tx, PROTOCOL_VERSION) != txToLen) {
+            ret = set_error(err, bitcoinconsensus_ERR_TX_SIZE_MISMATCH);
+        } else if (GetSerializeSize(spent_outputs, PROTOCOL_VERSION) != spentOutputsLen) {
+            ret = set_error(err, bitcoinconsensus_ERR_TX_DESERIALIZE);
+        } else if (tx.IsCoinBase()) {
+            ret = set_error(err, bitcoinconsensus_ERR_TX_COINBASE);
+        } else if (spent_outputs.size() != tx.vin.size()) {
+            ret = set_error(err, bitcoinconsensus_ERR_SPENT_OUTPUTS_MISMATCH);
+        } else {
+            // Regardless of the verification result, the tx did not error.
+            set_error(err, bitcoinconsensus_ERR_OK);
+            AmountsError error;
+            if (!HasValidFee(tx)) {
+                ret = 0;
+                aerror = bitcoinconsensus_AMOUNTS_ERR_FEE;
+            } else if (!VerifyAmounts(spent_outputs, tx, nullptr, false, &error, &aerror_index)) {
+                ret = 0;
+                aerror = amounts_error(error);
+            } else {
+                ret = 1;
+            }
+        }
+    } catch (const std::exception&) {
+        ret = set_error(err, bitcoinconsensus_ERR_TX_DESERIALIZE); // Error deserializing
+    }
+    if (amounts_err)
+        *amounts_err = aerror;
+    if (amounts_err_index)
+        *amounts_err_index = aerror_index;
+    return ret;
+}
+
+int bitcoinconsensus_verify_output_proofs(const unsigned char *txTo        , unsigned int txToLen,
+                                    const unsigned char *spentOutputs, unsigned int spentOutputsLen,
+                                    unsigned char *outputProofs, unsigned int outputProofsLen,
+                                    bitcoinconsensus_error* err)
+{
+    try {
+        TxInputStream stream(SER_NETWORK, PROTOCOL_VERSION, txTo, txToLen);
+        CTransaction tx(deserialize, stream);
+        std::vector<CTxOut> spent_outputs;
+        TxInputStream spent_outputs_stream(SER_NETWORK, PROTOCOL_VERSION, spentOutputs, spentOutputsLen);
+        spent_outputs_stream >> spent_outputs;
+
+        if (GetSerializeSize(tx, PROTOCOL_VERSION) != txToLen)
+            return set_error(err, bitcoinconsensus_ERR_TX_SIZE_MISMATCH);
+        if (GetSerializeSize(spent_outputs, PROTOCOL_VERSION) != spentOutputsLen)
+            return set_error(err, bitcoinconsensus_ERR_TX_DESERIALIZE);
+        if (tx.IsCoinBase())
+            return set_error(err, bitcoinconsensus_ERR_TX_COINBASE);
+        if (spent_outputs.size() != tx.vin.size())
+            return set_error(err, bitcoinconsensus_ERR_SPENT_OUTPUTS_MISMATCH);
+        if (outputProofsLen != tx.vout.size())
+            return set_error(err, bitcoinconsensus_ERR_OUTPUT_PROOFS_MISMATCH);
+
+        // Regardless of the verification result, the tx did not error.
+        set_error(err, bitcoinconsensus_ERR_OK);
+        std::vector<bool> valid_rangeproofs;
+        std::vector<bool> valid_surjectionproofs;
+        VerifyOutputProofs(spent_outputs, tx, valid_rangeproofs, valid_surjectionproofs);
+
+        int ret = 1;
+        for (size_t i = 0; i < tx.vout.size(); i++) {
+            outputProofs[i] = bitcoinconsensus_OUTPUT_PROOFS_VALID;
+            if (!valid_rangeproofs[i])
+                outputProofs[i] |= bitcoinconsensus_OUTPUT_PROOFS_INVALID_RANGEPROOF;
+            if (!valid_surjectionproofs[i])
+                outputProofs[i] |= bitcoinconsensus_OUTPUT_PROOFS_INVALID_SURJECTIONPROOF;
+            if (outputProofs[i] != bitcoinconsensus_OUTPUT_PROOFS_VALID)
+                ret = 0;
+        }
+        return ret;
+    } catch (const std::exception&) {
+        return set_error(err, bitcoinconsensus_ERR_TX_DESERIALIZE); // Error deserializing
+    }
+}
+
+static unsigned int tx_check_error(const std::string& reject_reason)
+{
+    static const std::pair<const char*, unsigned int> reasons[] = {
+        {"bad-txns-vin-empty", bitcoinconsensus_TX_CHECK_ERR_VIN_EMPTY},
+        {"bad-txns-vout-empty", bitcoinconsensus_TX_CHECK_ERR_VOUT_EMPTY},
+        {"bad-txns-oversize", bitcoinconsensus_TX_CHECK_ERR_OVERSIZE},
+        {"bad-txns-vout-amount-invalid", bitcoinconsensus_TX_CHECK_ERR_VOUT_AMOUNT_INVALID},
+        {"bad-txns-vout-negative", bitcoinconsensus_TX_CHECK_ERR_VOUT_NEGATIVE},
+        {"bad-txns-vout-toolarge", bitcoinconsensus_TX_CHECK_ERR_VOUT_TOOLARGE},
+        {"bad-txns-txouttotal-toolarge", bitcoinconsensus_TX_CHECK_ERR_TXOUTTOTAL_TOOLARGE},
+        {"bad-txns-inputs-duplicate", bitcoinconsensus_TX_CHECK_ERR_INPUTS_DUPLICATE},
+        {"bad-cb-length", bitcoinconsensus_TX_CHECK_ERR_CB_LENGTH},
+        {"bad-cb-fee", bitcoinconsensus_TX_CHECK_ERR_CB_FEE},
+        {"bad-txns-prevout-null", bitcoinconsensus_TX_CHECK_ERR_PREVOUT_NULL},
+    };
+    for (const auto& reason : reasons) {
+        if (reject_reason == reason.first)
+            return reason.second;
+    }
+    // every reject reason of CheckTransaction should be listed above
+    return bitcoinconsensus_TX_CHECK_ERR_UNKNOWN;
+}
+
+int bitcoinconsensus_check_transaction(const unsigned char *txTo, unsigned int txToLen,
+                                    bitcoinconsensus_error* err, unsigned int* tx_check_err)
+{
+    unsigned int cerror = bitcoinconsensus_TX_CHECK_ERR_OK;
+    int ret;
+    try {
+        TxInputStream stream(SER_NETWORK, PROTOCOL_VERSION, txTo, txToLen);
+        CTransaction tx(deserialize, stream);
+        if (GetSerializeSize(tx, PROTOCOL_VERSION) != txToLen) {
+            ret = set_error(err, bitcoinconsensus_ERR_TX_SIZE_MISMATCH);
+        } else {
+            // Regardless of the verification result, the tx did not error.
+            set_error(err, bitcoinconsensus_ERR_OK);
+            TxValidationState state;
+            ret = CheckTransaction(tx, state);
+            if (!ret)
+                cerror = tx_check_error(state.GetRejectReason());
+        }
+    } catch (const std::exception&) {
+        ret = set_error(err, bitcoinconsensus_ERR_TX_DESERIALIZE); // Error deserializing
+    }
+    if (tx_check_err)
+        *tx_check_err = cerror;
+    return ret;
+}
+
+static unsigned int issuance_error(IssuanceError error)
+{
+    switch (error) {
+    case IssuanceError::OK: return bitcoinconsensus_ISSUANCE_ERR_OK;
+    case IssuanceError::WITNESS: return bitcoinconsensus_ISSUANCE_ERR_WITNESS;
+    case IssuanceError::AMOUNT: return bitcoinconsensus_ISSUANCE_ERR_AMOUNT;
+    case IssuanceError::AMOUNT_RANGEPROOF: return bitcoinconsensus_ISSUANCE_ERR_AMOUNT_RANGEPROOF;
+    case IssuanceError::INFLATION_KEYS: return bitcoinconsensus_ISSUANCE_ERR_INFLATION_KEYS;
+    case IssuanceError::INFLATION_KEYS_RANGEPROOF: return bitcoinconsensus_ISSUANCE_ERR_INFLATION_KEYS_RANGEPROOF;
+    case IssuanceError::REISSUANCE_INFLATION_KEYS: return bitcoinconsensus_ISSUANCE_ERR_REISSUANCE_INFLATION_KEYS;
+    case IssuanceError::REISSUANCE_TOKEN: return bitcoinconsensus_ISSUANCE_ERR_REISSUANCE_TOKEN;
+    } // no default case, so the compiler can warn about missing cases
+    return bitcoinconsensus_ISSUANCE_ERR_UNKNOWN;
+}
+
+int bitcoinconsensus_verify_issuance(const unsigned char *txTo        , unsigned int txToLen,
+                                    const unsigned char *spentOutput, unsigned int spentOutputLen,
+                                    unsigned int nIn, unsigned char *entropy, unsigned char *asset,
+                                    unsigned char *token, bitcoinconsensus_error* err, unsigned int* issuance_err)
+{
+    IssuanceError error = IssuanceError::OK;
+    int ret;
+    try {
+        TxInputStream stream(SER_NETWORK, PROTOCOL_VERSION, txTo, txToLen);
+        CTransaction tx(deserialize, stream);
+        CTxOut spent_output;
+        TxInputStream spent_output_stream(SER_NETWORK, PROTOCOL_VERSION, spentOutput, spentOutputLen);
+        spent_output_stream >> spent_output;
+
+        if (nIn >= tx.vin.size()) {
+            ret = set_error(err, bitcoinconsensus_ERR_TX_INDEX);
+        } else if (GetSerializeSize(tx, PROTOCOL_VERSION) != txToLen) {
+            ret = set_error(err, bitcoinconsensus_ERR_TX_SIZE_MISMATCH);
+        } else if (GetSerializeSize(spent_output, PROTOCOL_VERSION) != spentOutputLen) {
+            ret = set_error(err, bitcoinconsensus_ERR_TX_DESERIALIZE);
+        } else if (tx.vin[nIn].assetIssuance.IsNull()) {
+            ret = set_error(err, bitcoinconsensus_ERR_TX_NO_ISSUANCE);
+        } else {
+            // Regardless of the verification result, the tx did not error.
+            set_error(err, bitcoinconsensus_ERR_OK);
+            uint256 issuance_entropy;
+            CAsset issued_asset;
+            CAsset reissuance_token;
+            ret = VerifyIssuance(spent_output, tx, nIn, issuance_entropy, issued_asset, reissuance_token, error);
+            memcpy(entropy, issuance_entropy.begin(), 32);
+            memcpy(asset, issued_asset.begin(), 32);
+            memcpy(token, reissuance_token.begin(), 32);
+        }
+    } catch (const std::exception&) {
+        ret = set_error(err, bitcoinconsensus_ERR_TX_DESERIALIZE); // Error deserializing
+    }
+    if (issuance_err)
+        *issuance_err = issuance_error(error);
+    return ret;
+}
+
+static unsigned int pegin_error(PeginError error)
+{
+    switch (error) {
+    case PeginError::OK: return bitcoinconsensus_PEGIN_ERR_OK;
+    case PeginError::WITNESS: return bitcoinconsensus_PEGIN_ERR_WITNESS;
+    case PeginError::VALUE: return bitcoinconsensus_PEGIN_ERR_VALUE;
+    case PeginError::CLAIM_SCRIPT_SIZE: return bitcoinconsensus_PEGIN_ERR_CLAIM_SCRIPT_SIZE;
+    case PeginError::MERKLE_BLOCK: return bitcoinconsensus_PEGIN_ERR_MERKLE_BLOCK;
+    case PeginError::POW: return bitcoinconsensus_PEGIN_ERR_POW;
+    case PeginError::TX: return bitcoinconsensus_PEGIN_ERR_TX;
+    case PeginError::TXID: return bitcoinconsensus_PEGIN_ERR_TXID;
+    case PeginError::AMOUNT: return bitcoinconsensus_PEGIN_ERR_AMOUNT;
+    case PeginError::CLAIM_SCRIPT: return bitcoinconsensus_PEGIN_ERR_CLAIM_SCRIPT;
+    case PeginError::GENESIS: return bitcoinconsensus_PEGIN_ERR_GENESIS;
+    case PeginError::ASSET: return bitcoinconsensus_PEGIN_ERR_ASSET;
+    case PeginError::DEPTH: return bitcoinconsensus_PEGIN_ERR_DEPTH;
+    } // no default case, so the compiler can warn about missing cases
+    return bitcoinconsensus_PEGIN_ERR_UNKNOWN;
+}
+
+int bitcoinconsensus_verify_pegin(const unsigned char *txTo, unsigned int txToLen, unsigned int nIn,
+                                    const unsigned char *parentGenesisHash, const unsigned char *parentPowLimit,
+                                    const unsigned char *peggedAsset,
+                                    const unsigned char *fedpegProgram, unsigned int fedpegProgramLen,
+                                    const unsigned char *fedpegScript, unsigned int fedpegScriptLen,
+                                    unsigned int peginMinDepth, unsigned int confirmations,
+                                    bitcoinconsensus_error* err, unsigned int* pegin_err)
+{
+    PeginError error = PeginError::OK;
+    int ret;
+    try {
+        TxInputStream stream(SER_NETWORK, PROTOCOL_VERSION, txTo, txToLen);
+        CTransaction tx(deserialize, stream);
+        if (nIn >= tx.vin.size()) {
+            ret = set_error(err, bitcoinconsensus_ERR_TX_INDEX);
+        } else if (GetSerializeSize(tx, PROTOCOL_VERSION) != txToLen) {
+            ret = set_error(err, bitcoinconsensus_ERR_TX_SIZE_MISMATCH);
+        } else if (!tx.vin[nIn].m_is_pegin) {
+            ret = set_error(err, bitcoinconsensus_ERR_TX_NOT_PEGIN);
+        } else {
+            // Regardless of the verification result, the tx did not error.
+            set_error(err, bitcoinconsensus_ERR_OK);
+            PeginParams params;
+            params.parent_genesis_hash = uint256(std::vector<unsigned char>(parentGenesisHash, parentGenesisHash + 32));
+            params.parent_pow_limit = uint256(std::vector<unsigned char>(parentPowLimit, parentPowLimit + 32));
+            params.pegged_asset = CAsset(std::vector<unsigned char>(peggedAsset, peggedAsset + 32));
+            params.fedpegscripts.emplace_back(CScript(fedpegProgram, fedpegProgram + fedpegProgramLen), CScript(fedpegScript, fedpegScript + fedpegScriptLen));
+            params.pegin_min_depth = peginMinDepth;
+            const CScriptWitness& pegin_witness = tx.witness.vtxinwit.size() > nIn ? tx.witness.vtxinwit[nIn].m_pegin_witness : CScriptWitness();
+            ret = CheckBitcoinPeginWitness(pegin_witness, params, tx.vin[nIn].prevout, confirmations, error);
+        }
+    } catch (const std::exception&) {
+        ret = set_error(err, bitcoinconsensus_ERR_TX_DESERIALIZE); // Error deserializing
+    }
+    if (pegin_err)
+        *pegin_err = pegin_error(error);
+    return ret;
+}
+
+namespace {
+
+/** Switches the block header serialization of the calling thread to signed blocks with the height in the header for as long as it is in scope. */
+class SignedBlocksScope
+{
+public:
+    SignedBlocksScope() : m_signed_blocks(g_signed_blocks), m_height_in_header(g_con_blockheightinheader)
+    {
+        g_signed_blocks = true;
+        g_con_blockheightinheader = true;
+    }
+    ~SignedBlocksScope()
+    {
+        g_signed_blocks = m_signed_blocks;
+        g_con_blockheightinheader = m_height_in_header;
+    }
+
+    SignedBlocksScope(const SignedBlocksScope&) = delete;
+    SignedBlocksScope& operator=(const SignedBlocksScope&) = delete;
+
+private:
+    const bool m_signed_blocks;
+    const bool m_height_in_header;
+};
+
+/** Checks block signatures against the block hash, like SimpleSignatureChecker in script/generic.hpp. */
+class BlockSignatureChecker : public BaseSignatureChecker
+{
+public:
+    BlockSignatureChecker(const uint256& hash, bool sighash_byte) : m_hash(hash), m_sighash_byte(sighash_byte) {}
+
+    bool CheckECDSASignature(const std::vector<unsigned char>& vchSig, const std::vector<unsigned char>& vchPubKey, const CScript& scriptCode, SigVersion sigversion, unsigned int flags) const override
+    {
+        CPubKey pubkey(vchPubKey);
+        if (!pubkey.IsValid())
+            return false;
+        if (vchSig.empty())
+            return false;
+        // Only SIGHASH_ALL is accepted, even though the block hash does not commit to it.
+        if (m_sighash_byte && vchSig.back() != SIGHASH_ALL)
+            return false;
+        // Like elementsd, verify the signature including the sighash byte, which lax DER parsing ignores.
+        return pubkey.Verify(m_hash, vchSig);
+    }
+
+private:
+    const uint256 m_hash;
+    const bool m_sighash_byte;
+};
+
+/** Checks the block signature of header like CheckProof in block_proof.cpp. */
+bool check_block_proof(const CBlockHeader& header, const CScript& signblockscript, unsigned int max_block_signature_size, unsigned int& header_err, ScriptError& serror)
+{
+    const DynaFedParams& dynafed_params = header.m_dynafed_params;
+    // Legacy blocks have an empty witness, dynafed blocks have an empty scriptSig
+    const bool is_dyna = !dynafed_params.IsNull();
+    const CScript& challenge = is_dyna ? dynafed_params.m_current.m_signblockscript : signblockscript;
+    const CScript scriptSig = is_dyna ? CScript() : header.proof.solution;
+    const CScriptWitness witness = is_dyna ? header.m_signblock_witness : CScriptWitness();
+
+    // Like CheckChallenge, every block of the chain commits to the signblockscript of its genesis block.
+    if (!is_dyna && header.proof.challenge != signblockscript) {
+        header_err = bitcoinconsensus_HEADER_ERR_CHALLENGE;
+        return false;
+    }
+
+    if (is_dyna ? witness.GetSerializedSize() > dynafed_params.m_current.m_signblock_witness_limit : scriptSig.size() > max_block_signature_size) {
+        header_err = bitcoinconsensus_HEADER_ERR_SIGNATURE_SIZE;
+        return false;
+    }
+
+    const unsigned int proof_flags = SCRIPT_VERIFY_P2SH // For cleanstack evalution under segwit flag
+        | SCRIPT_VERIFY_STRICTENC // Minimally-sized DER sigs
+        | SCRIPT_VERIFY_NULLDUMMY // No extra data stuffed into OP_CMS witness
+        | SCRIPT_VERIFY_CLEANSTACK // No extra pushes leftover in witness
+        | SCRIPT_VERIFY_MINIMALDATA // Pushes are minimally-sized
+        | SCRIPT_VERIFY_SIGPUSHONLY // Witness is push-only
+        | SCRIPT_VERIFY_LOW_S // Stop easiest signature fiddling
+        | SCRIPT_VERIFY_WITNESS // Witness and to enforce cleanstack
+        | (is_dyna ? 0 : SCRIPT_NO_SIGHASH_BYTE); // Non-dynafed blocks do not have sighash byte
+    if (!VerifyScript(scriptSig, challenge, &witness, proof_flags, BlockSignatureChecker(header.GetHash(), is_dyna), &serror)) {
+        header_err = bitcoinconsensus_HEADER_ERR_SIGNATURE;
+        return false;
+    }
+
+    return true;
+}
+
+} // namespace
+
+int bitcoinconsensus_verify_block_header(const unsigned char *header, unsigned int headerLen,
+                                    const unsigned char *signblockscript, unsigned int signblockscriptLen,
+                                    unsigned int maxBlockSignatureSize, bitcoinconsensus_error* err,
+                                    unsigned int* header_err, unsigned int* script_err)
+{
+    unsigned int error = bitcoinconsensus_HEADER_ERR_OK;
+    ScriptError serror = SCRIPT_ERR_OK;
+    int ret;
+    try {
+        SignedBlocksScope signed_blocks;
+        TxInputStream stream(SER_NETWORK, PROTOCOL_VERSION, header, headerLen);
+        CBlockHeader block_header;
+        stream >> block_header;
+        if (GetSerializeSize(block_header, PROTOCOL_VERSION) != headerLen) {
+            ret = set_error(err, bitcoinconsensus_ERR_TX_SIZE_MISMATCH);
+        } else {
+            // Regardless of the verification result, the header did not error.
+            set_error(err, bitcoinconsensus_ERR_OK);
+            ret = check_block_proof(block_header, CScript(signblockscript, signblockscript + signblockscriptLen), maxBlockSignatureSize, error, serror);
+        }
+    } catch (const std::exception&) {
+        ret = set_error(err, bitcoinconsensus_ERR_TX_DESERIALIZE); // Error deserializing
+    }
+    if (header_err)
+        *header_err = error;
+    if (script_err)
+        *script_err = serror;
+    return ret;
+}
+
+namespace {
+
+/** Counts the signature operations of tx like GetLegacySigOpCount in consensus/tx_verify.cpp. */
+unsigned int legacy_sigop_count(const CTransaction& tx)
+{
+    unsigned int sigops = 0;
+    for (const auto& txin : tx.vin)
+        sigops += txin.scriptSig.GetSigOpCount(false);
+    for (const auto& txout : tx.vout)
+        sigops += txout.scriptPubKey.GetSigOpCount(false);
+    return sigops;
+}
+
+/** Counts the signature operation cost of tx like GetTransactionSigOpCost in consensus/tx_verify.cpp, taking the outputs spent by its inputs from spent_outputs instead of a coins view. */
+int64_t sigop_cost(const CTransaction& tx, const CTxOut* spent_outputs, unsigned int flags)
+{
+    int64_t sigops = legacy_sigop_count(tx) * WITNESS_SCALE_FACTOR;
+
+    if (tx.IsCoinBase())
+        return sigops;
+
+    if (flags & SCRIPT_VERIFY_P2SH) {
+        for (size_t i = 0; i < tx.vin.size(); i++) {
+            // Peg-in inputs are segwit-only
+            if (!tx.vin[i].m_is_pegin && spent_outputs[i].scriptPubKey.IsPayToScriptHash())
+                sigops += spent_outputs[i].scriptPubKey.GetSigOpCount(tx.vin[i].scriptSig) * WITNESS_SCALE_FACTOR;
+        }
+    }
+
+    for (size_t i = 0; i < tx.vin.size(); i++) {
+        CScript scriptPubKey;
+        if (tx.vin[i].m_is_pegin) {
+            // Like elementsd, only count the claim script of peg-ins with enough peg-in witness fields
+            if (tx.witness.vtxinwit.size() != tx.vin.size() ||
+                    tx.witness.vtxinwit[i].m_pegin_witness.stack.size() < 4) {
+                continue;
+            }
+            const auto& pegin_witness = tx.witness.vtxinwit[i].m_pegin_witness;
+            scriptPubKey = CScript(pegin_witness.stack[3].begin(), pegin_witness.stack[3].end());
+        } else {
+            scriptPubKey = spent_outputs[i].scriptPubKey;
+        }
+
+        const CScriptWitness* witness = tx.witness.vtxinwit.size() > i ? &tx.witness.vtxinwit[i].scriptWitness : nullptr;
+        sigops += CountWitnessSigOps(tx.vin[i].scriptSig, scriptPubKey, witness, flags);
+    }
+    return sigops;
+}
+
+/** Checks block like CheckBlock and ContextualCheckBlock in validation.cpp, skipping what depends on the chain. */
+bool check_block_structure(const CBlock& block, unsigned int& block_err, size_t& block_err_index, unsigned int& tx_check_err)
+{
+    bool mutated;
+    if (block.hashMerkleRoot != BlockMerkleRoot(block, &mutated)) {
+        block_err = bitcoinconsensus_BLOCK_ERR_MERKLE_ROOT;
+        return false;
+    }
+    // Repeated sequences of transactions do not change the merkle root (CVE-2012-2459)
+    if (mutated) {
+        block_err = bitcoinconsensus_BLOCK_ERR_DUPLICATE;
+        return false;
+    }
+
+    if (block.vtx.empty() || block.vtx.size() * WITNESS_SCALE_FACTOR > MAX_BLOCK_WEIGHT || ::GetSerializeSize(block, PROTOCOL_VERSION | SERIALIZE_TRANSACTION_NO_WITNESS) * WITNESS_SCALE_FACTOR > MAX_BLOCK_WEIGHT) {
+        block_err = bitcoinconsensus_BLOCK_ERR_LENGTH;
+        return false;
+    }
+
+    if (!block.vtx[0]->IsCoinBase()) {
+        block_err = bitcoinconsensus_BLOCK_ERR_CB_MISSING;
+        return false;
+    }
+    for (size_t i = 1; i < block.vtx.size(); i++) {
+        if (block.vtx[i]->IsCoinBase()) {
+            block_err = bitcoinconsensus_BLOCK_ERR_CB_MULTIPLE;
+            block_err_index = i;
+            return false;
+        }
+    }
+
+    for (size_t i = 0; i < block.vtx.size(); i++) {
+        TxValidationState state;
+        if (!CheckTransaction(*block.vtx[i], state)) {
+            block_err = bitcoinconsensus_BLOCK_ERR_TX_CHECK;
+            block_err_index = i;
+            tx_check_err = tx_check_error(state.GetRejectReason());
+            return false;
+        }
+    }
+
+    unsigned int sigops = 0;
+    for (size_t i = 0; i < block.vtx.size(); i++) {
+        sigops += legacy_sigop_count(*block.vtx[i]);
+        if (sigops * WITNESS_SCALE_FACTOR > MAX_BLOCK_SIGOPS_COST) {
+            block_err = bitcoinconsensus_BLOCK_ERR_SIGOPS;
+            block_err_index = i;
+            return false;
+        }
+    }
+
+    // The coinbase input witness may only hold the witness reserved value, which is not committed to
+    for (const auto& inwit : block.vtx[0]->witness.vtxinwit) {
+        if (!inwit.vchIssuanceAmountRangeproof.empty() ||
+                !inwit.vchInflationKeysRangeproof.empty() ||
+                !inwit.m_pegin_witness.IsNull()) {
+            block_err = bitcoinconsensus_BLOCK_ERR_CB_WITNESS;
+            return false;
+        }
+    }
+
+    // Segwit is active from the genesis block of every Elements chain
+    bool have_witness = false;
+    const int commitpos = GetWitnessCommitmentIndex(block);
+    if (commitpos != NO_WITNESS_COMMITMENT) {
+        const CTransaction& coinbase = *block.vtx[0];
+        if (coinbase.witness.vtxinwit.empty() ||
+                coinbase.witness.vtxinwit[0].scriptWitness.stack.size() != 1 ||
+                coinbase.witness.vtxinwit[0].scriptWitness.stack[0].size() != 32) {
+            block_err = bitcoinconsensus_BLOCK_ERR_WITNESS_NONCE_SIZE;
+            return false;
+        }
+        uint256 witness_root = BlockWitnessMerkleRoot(block);
+        CHash256().Write(witness_root).Write(coinbase.witness.vtxinwit[0].scriptWitness.stack[0]).Finalize(witness_root);
+        if (memcmp(witness_root.begin(), &coinbase.vout[commitpos].scriptPubKey[6], 32)) {
+            block_err = bitcoinconsensus_BLOCK_ERR_WITNESS_MERKLE_MATCH;
+            return false;
+        }
+        have_witness = true;
+    }
+
+    if (!have_witness) {
+        for (const auto& tx : block.vtx) {
+            if (tx->HasWitness()) {
+                block_err = bitcoinconsensus_BLOCK_ERR_UNEXPECTED_WITNESS;
+                return false;
+            }
+        }
+    }
+
+    if (GetBlockWeight(block) > MAX_BLOCK_WEIGHT) {
+        block_err = bitcoinconsensus_BLOCK_ERR_WEIGHT;
+        return false;
+    }
+
+    return true;
+}
+
+/** Checks the signature operation cost and the coinbase amount of block like ConnectBlock in validation.cpp. */
+bool check_block_inputs(const CBlock& block, const std::vector<CTxOut>& spent_outputs, unsigned int& block_err, size_t& block_err_index)
+{
+    int64_t sigops_cost = 0;
+    CAmountMap fees;
+    size_t spent_outputs_pos = 0;
+    for (size_t i = 0; i < block.vtx.size(); i++) {
+        const CTransaction& tx = *block.vtx[i];
+        sigops_cost += sigop_cost(tx, spent_outputs.data() + spent_outputs_pos, SCRIPT_VERIFY_P2SH | SCRIPT_VERIFY_WITNESS);
+        if (sigops_cost > MAX_BLOCK_SIGOPS_COST) {
+            block_err = bitcoinconsensus_BLOCK_ERR_SIGOPS;
+            block_err_index = i;
+            return false;
+        }
+        if (!tx.IsCoinBase()) {
+            fees += GetFeeMap(tx);
+            spent_outputs_pos += tx.vin.size();
+        }
+    }
+
+    if (!MoneyRange(fees)) {
+        block_err = bitcoinconsensus_BLOCK_ERR_REWARD_OUT_OF_RANGE;
+        return false;
+    }
+    if (!VerifyCoinbaseAmount(*block.vtx[0], fees)) {
+        block_err = bitcoinconsensus_BLOCK_ERR_CB_AMOUNT;
+        return false;
+    }
+
+    return true;
+}
+
+} // namespace
+
+int bitcoinconsensus_check_block(const unsigned char *block, unsigned int blockLen,
+                                    const unsigned char *spentOutputs, unsigned int spentOutputsLen,
+                                    bitcoinconsensus_error* err, unsigned int* block_err,
+                                    unsigned int* block_err_index, unsigned int* tx_check_err)
+{
+    unsigned int berror = bitcoinconsensus_BLOCK_ERR_OK;
+    size_t berror_index = 0;
+    unsigned int cerror = bitcoinconsensus_TX_CHECK_ERR_OK;
+    int ret;
+    try {
+        SignedBlocksScope signed_blocks;
+        TxInputStream stream(SER_NETWORK, PROTOCOL_VERSION, block, blockLen);
+        CBlock full_block;
+        stream >> full_block;
+        std::vector<CTxOut> spent_outputs;
+        if (spentOutputs) {
+            TxInputStream spent_outputs_stream(SER_NETWORK, PROTOCOL_VERSION, spentOutputs, spentOutputsLen);
+            spent_outputs_stream >> spent_outputs;
+        }
+
+        size_t spent_outputs_count = 0;
+        for (const auto& tx : full_block.vtx) {
+            if (!tx->IsCoinBase())
+                spent_outputs_count += tx->vin.size();
+        }
+
+        if (GetSerializeSize(full_block, PROTOCOL_VERSION) != blockLen) {
+            ret = set_error(err, bitcoinconsensus_ERR_TX_SIZE_MISMATCH);
+        } else if (spentOutputs && GetSerializeSize(spent_outputs, PROTOCOL_VERSION) != spentOutputsLen) {
+            ret = set_error(err, bitcoinconsensus_ERR_TX_DESERIALIZE);
+        } else if (spentOutputs && spent_outputs.size() != spent_outputs_count) {
+            ret = set_error(err, bitcoinconsensus_ERR_SPENT_OUTPUTS_MISMATCH);
+        } else {
+            // Regardless of the verification result, the block did not error.
+            set_error(err, bitcoinconsensus_ERR_OK);
+            ret = check_block_structure(full_block, berror, berror_index, cerror) &&
+                (!spentOutputs || check_block_inputs(full_block, spent_outputs, berror, berror_index));
+        }
+    } catch (const std::exception&) {
+        ret = set_error(err, bitcoinconsensus_ERR_TX_DESERIALIZE); // Error deserializing
+    }
+    if (block_err)
+        *block_err = berror;
+    if (block_err_index)
+        *block_err_index = berror_index;
+    if (tx_check_err)
+        *tx_check_err = cerror;
+    return ret;
+}
+
+namespace {
+
+/** Deserializes a dynamic federation parameter entry, returning false if it has trailing data. */
+bool deserialize_dynafed_params(const unsigned char *params, unsigned int paramsLen, DynaFedParamEntry& entry)
+{
+    TxInputStream stream(SER_NETWORK, PROTOCOL_VERSION, params, paramsLen);
+    stream >> entry;
+    return GetSerializeSize(entry, PROTOCOL_VERSION) == paramsLen;
+}
+
+/** Whether extension_space is a list of PAK entries, like CreatePAKListFromExtensionSpace in primitives/pak.cpp. */
+bool is_pak_list(const std::vector<std::vector<unsigned char>>& extension_space)
+{
+    if (extension_space.empty() || extension_space.size() > SECP256K1_WHITELIST_MAX_N_KEYS)
+        return false;
+    for (const auto& entry : extension_space) {
+        // An offline and an online public key
+        if (entry.size() != 66)
+            return false;
+        if (!CPubKey(entry.begin(), entry.begin() + 33).IsFullyValid() || !CPubKey(entry.begin() + 33, entry.end()).IsFullyValid())
+            return false;
+    }
+    return true;
+}
+
+/** Checks the dynamic federation parameters of header like ContextualCheckDynaFedHeader in validation.cpp, given the parameters that the previous blocks imply. */
+bool check_dynafed_params(const CBlockHeader& header, const DynaFedParamEntry& expected_current, const DynaFedParamEntry& current, bool enforce_pak, unsigned int& dynafed_err)
+{
+    const DynaFedParams& dynafed_params = header.m_dynafed_params;
+
+    // Dynamic blocks must at least publish current signblockscript in full
+    if (dynafed_params.m_current.IsNull()) {
+        dynafed_err = bitcoinconsensus_DYNAFED_ERR_CURRENT_NULL;
+        return false;
+    }
+
+    // Make sure extension bits aren't active, reserved for future HF
+    const uint32_t reserved_mask = (1<<23) | (1<<24) | (1<<25) | (1<<26);
+    if ((header.nVersion & reserved_mask) != 0) {
+        dynafed_err = bitcoinconsensus_DYNAFED_ERR_EXTENSION_BITS;
+        return false;
+    }
+
+    if (expected_current != dynafed_params.m_current) {
+        dynafed_err = bitcoinconsensus_DYNAFED_ERR_CURRENT_MISMATCH;
+        return false;
+    }
+
+    const DynaFedParamEntry& proposed = dynafed_params.m_proposed;
+    if (proposed.IsNull())
+        return true;
+
+    int version = 0;
+    std::vector<unsigned char> program;
+    // signblockscript proposals *must* be segwit versions
+    if (proposed.m_signblockscript != current.m_signblockscript && !proposed.m_signblockscript.IsWitnessProgram(version, program)) {
+        dynafed_err = bitcoinconsensus_DYNAFED_ERR_SIGNBLOCKSCRIPT;
+        return false;
+    }
+
+    if (proposed.m_fedpeg_program != current.m_fedpeg_program || proposed.m_fedpegscript != current.m_fedpegscript) {
+        if (!proposed.m_fedpeg_program.IsWitnessProgram(version, program)) {
+            dynafed_err = bitcoinconsensus_DYNAFED_ERR_FEDPEG_PROGRAM;
+            return false;
+        }
+
+        // for v0, fedpegscript's scriptPubKey must match. v1+ is unencumbered.
+        if (version == 0) {
+            uint256 fedpegscript_hash;
+            CSHA256().Write(proposed.m_fedpegscript.data(), proposed.m_fedpegscript.size()).Finalize(fedpegscript_hash.begin());
+            if (CScript() << OP_0 << ToByteVector(fedpegscript_hash) != proposed.m_fedpeg_program) {
+                dynafed_err = bitcoinconsensus_DYNAFED_ERR_FEDPEGSCRIPT;
+                return false;
+            }
+
+            // This forbids the first Liquid watchman script
+            if (!proposed.m_fedpegscript.empty() && proposed.m_fedpegscript.front() == OP_DEPTH) {
+                dynafed_err = bitcoinconsensus_DYNAFED_ERR_FEDPEGSCRIPT_OP_DEPTH;
+                return false;
+            }
+        }
+    }
+
+    // A non-empty extension space must not hold "junk" entries when enforcing PAK
+    if (proposed.m_extension_space != current.m_extension_space && enforce_pak &&
+            !proposed.m_extension_space.empty() && !is_pak_list(proposed.m_extension_space)) {
+        dynafed_err = bitcoinconsensus_DYNAFED_ERR_EXTENSION_SPACE;
+        return false;
+    }
+
+    return true;
+}
+
+} // namespace
+
+int bitcoinconsensus_dynafed_params_root(const unsigned char *params, unsigned int paramsLen,
+                                    unsigned char *root, unsigned char *extraRoot, bitcoinconsensus_error* err)
+{
+    try {
+        DynaFedParamEntry entry;
+        if (!deserialize_dynafed_params(params, paramsLen, entry))
+            return set_error(err, bitcoinconsensus_ERR_TX_SIZE_MISMATCH);
+
+        const uint256 entry_root = entry.CalculateRoot();
+        uint256 extra_root;
+        if (entry.m_serialize_type == 1) {
+            extra_root = entry.m_elided_root;
+        } else if (entry.m_serialize_type == 2) {
+            extra_root = entry.CalculateExtraRoot();
+        }
+        std::copy(entry_root.begin(), entry_root.end(), root);
+        std::copy(extra_root.begin(), extra_root.end(), extraRoot);
+        set_error(err, bitcoinconsensus_ERR_OK);
+        return 1;
+    } catch (const std::exception&) {
+        return set_error(err, bitcoinconsensus_ERR_TX_DESERIALIZE); // Error deserializing
+    }
+}
+
+int bitcoinconsensus_verify_dynafed_params(const unsigned char *header, unsigned int headerLen,
+                                    const unsigned char *expectedCurrent, unsigned int expectedCurrentLen,
+                                    const unsigned char *fullCurrent, unsigned int fullCurrentLen,
+                                    int enforcePak, bitcoinconsensus_error* err, unsigned int* dynafed_err)
+{
+    unsigned int error = bitcoinconsensus_DYNAFED_ERR_OK;
+    int ret;
+    try {
+        SignedBlocksScope signed_blocks;
+        TxInputStream stream(SER_NETWORK, PROTOCOL_VERSION, header, headerLen);
+        CBlockHeader block_header;
+        stream >> block_header;
+        DynaFedParamEntry expected_current;
+        DynaFedParamEntry full_current;
+        if (GetSerializeSize(block_header, PROTOCOL_VERSION) != headerLen ||
+                !deserialize_dynafed_params(expectedCurrent, expectedCurrentLen, expected_current) ||
+                !deserialize_dynafed_params(fullCurrent, fullCurrentLen, full_current)) {
+            ret = set_error(err, bitcoinconsensus_ERR_TX_SIZE_MISMATCH);
+        } else {
+            // Regardless of the verification result, the header did not error.
+            set_error(err, bitcoinconsensus_ERR_OK);
+            ret = check_dynafed_params(block_header, expected_current, full_current, enforcePak != 0, error);
+        }
+    } catch (const std::exception&) {
+        ret = set_error(err, bitcoinconsensus_ERR_TX_DESERIALIZE); // Error deserializing
+    }
+    if (dynafed_err)
+        *dynafed_err = error;
+    return ret;
+}
+
+namespace {
+
+unsigned int standard_error(StandardError error)
+{
+    switch (error) {
+    case StandardError::OK: return bitcoinconsensus_STANDARD_ERR_OK;
+    case StandardError::VERSION: return bitcoinconsensus_STANDARD_ERR_VERSION;
+    case StandardError::TX_SIZE: return bitcoinconsensus_STANDARD_ERR_TX_SIZE;
+    case StandardError::SCRIPTSIG_SIZE: return bitcoinconsensus_STANDARD_ERR_SCRIPTSIG_SIZE;
+    case StandardError::SCRIPTSIG_NOT_PUSHONLY: return bitcoinconsensus_STANDARD_ERR_SCRIPTSIG_NOT_PUSHONLY;
+    case StandardError::SCRIPTPUBKEY: return bitcoinconsensus_STANDARD_ERR_SCRIPTPUBKEY;
+    case StandardError::BARE_MULTISIG: return bitcoinconsensus_STANDARD_ERR_BARE_MULTISIG;
+    case StandardError::DUST: return bitcoinconsensus_STANDARD_ERR_DUST;
+    case StandardError::MULTI_OP_RETURN: return bitcoinconsensus_STANDARD_ERR_MULTI_OP_RETURN;
+    case StandardError::TX_SIZE_SMALL: return bitcoinconsensus_STANDARD_ERR_TX_SIZE_SMALL;
+    case StandardError::INPUTS: return bitcoinconsensus_STANDARD_ERR_INPUTS;
+    case StandardError::WITNESS: return bitcoinconsensus_STANDARD_ERR_WITNESS;
+    }
+    return bitcoinconsensus_STANDARD_ERR_UNKNOWN;
+}
+
+/** Runs the policy checks of MemPoolAccept::PreChecks and the scripts of PolicyScriptChecks, in their order. */
+bool check_standard(const CTransaction& tx, const PrecomputedTransactionData& txdata, const StandardPolicy& policy,
+                    bool dynafed_active, unsigned int& standard_err, size_t& index, ScriptError& serror)
+{
+    const std::vector<CTxOut>& spent_outputs = txdata.m_spent_outputs;
+    const StandardError error = CheckStandardTx(tx, policy, index);
+    if (error != StandardError::OK) {
+        standard_err = standard_error(error);
+        return false;
+    }
+    if (!AreInputsStandard(tx, spent_outputs, policy, index)) {
+        standard_err = bitcoinconsensus_STANDARD_ERR_INPUTS;
+        return false;
+    }
+    if (tx.HasWitness() && !IsWitnessStandard(tx, spent_outputs, index)) {
+        standard_err = bitcoinconsensus_STANDARD_ERR_WITNESS;
+        return false;
+    }
+    if (sigop_cost(tx, spent_outputs.data(), STANDARD_CHECK_SCRIPT_VERIFY_FLAGS) > STANDARD_CHECK_MAX_TX_SIGOPS_COST) {
+        standard_err = bitcoinconsensus_STANDARD_ERR_SIGOPS;
+        return false;
+    }
+
+    unsigned int flags = STANDARD_CHECK_SCRIPT_VERIFY_FLAGS;
+    if (dynafed_active)
+        flags |= SCRIPT_SIGHASH_RANGEPROOF;
+    const unsigned int mandatory_flags = flags & ~(STANDARD_CHECK_SCRIPT_VERIFY_FLAGS & ~STANDARD_CHECK_MANDATORY_VERIFY_FLAGS);
+
+    for (index = 0; index < tx.vin.size(); index++) {
+        const CScriptWitness* witness = (tx.witness.vtxinwit.size() > index ? &tx.witness.vtxinwit[index].scriptWitness : NULL);
+        const CTxOut& spent_output = spent_outputs[index];
+        const TransactionSignatureChecker checker(&tx, index, spent_output.nValue, txdata);
+        if (VerifyScript(tx.vin[index].scriptSig, spent_output.scriptPubKey, witness, flags, checker, &serror))
+            continue;
+
+        // Failures that only violate standard flags make the transaction non-standard rather than invalid
+        ScriptError mandatory_serror;
+        if (VerifyScript(tx.vin[index].scriptSig, spent_output.scriptPubKey, witness, mandatory_flags, checker, &mandatory_serror)) {
+            standard_err = bitcoinconsensus_STANDARD_ERR_NON_MANDATORY_SCRIPT;
+        } else {
+            standard_err = bitcoinconsensus_STANDARD_ERR_MANDATORY_SCRIPT;
+        }
+        return false;
+    }
+
+    index = 0;
+    serror = SCRIPT_ERR_OK;
+    return true;
+}
+
+} // namespace
+
+int bitcoinconsensus_check_standard(const unsigned char *hash_genesis_block,
+                                    const unsigned char *txTo, unsigned int txToLen,
+                                    const unsigned char *spentOutputs, unsigned int spentOutputsLen,
+                                    const unsigned char *policyAsset, const unsigned char *parentGenesisHash,
+                                    unsigned int dustRelayFee, unsigned int maxDatacarrierBytes, unsigned int policyFlags,
+                                    bitcoinconsensus_error* err, unsigned int* standard_err,
+                                    unsigned int* standard_err_index, unsigned int* script_err)
+{
+    unsigned int error = bitcoinconsensus_STANDARD_ERR_OK;
+    size_t error_index = 0;
+    ScriptError serror = SCRIPT_ERR_OK;
+    int ret;
+    std::unique_ptr<bitcoinconsensus_transaction> tx{create_transaction(hash_genesis_block, txTo, txToLen, spentOutputs, spentOutputsLen, true, err)};
+    if (!tx) {
+        ret = 0; // err is set
+    } else if (tx->tx.IsCoinBase()) {
+        ret = set_error(err, bitcoinconsensus_ERR_TX_COINBASE);
+    } else {
+        StandardPolicy policy;
+        policy.policy_asset = CAsset(uint256{policyAsset, 32});
+        policy.parent_genesis_hash = uint256{parentGenesisHash, 32};
+        policy.dust_relay_fee = dustRelayFee;
+        policy.permit_bare_multisig = policyFlags & bitcoinconsensus_POLICY_PERMIT_BARE_MULTISIG;
+        policy.accept_datacarrier = policyFlags & bitcoinconsensus_POLICY_DATACARRIER;
+        policy.max_datacarrier_bytes = maxDatacarrierBytes;
+        policy.multi_data_permitted = policyFlags & bitcoinconsensus_POLICY_MULTI_DATA_PERMITTED;
+        policy.enforce_pak = policyFlags & bitcoinconsensus_POLICY_ENFORCE_PAK;
+        policy.anyonecanspend_aremine = policyFlags & bitcoinconsensus_POLICY_ANYONECANSPEND_AREMINE;
+        policy.taproot_active = policyFlags & bitcoinconsensus_POLICY_TAPROOT_ACTIVE;
+        const bool dynafed_active = policyFlags & bitcoinconsensus_POLICY_DYNAFED_ACTIVE;
+
+        // Regardless of the verification result, the tx did not error.
+        set_error(err, bitcoinconsensus_ERR_OK);
+        ret = check_standard(tx->tx, tx->txdata, policy, dynafed_active, error, error_index, serror);
+    }
+    if (standard_err)
+        *standard_err = error;
+    if (standard_err_index)
+        *standard_err_index = error_index;
+    if (script_err)
+        *script_err = serror;
+    return ret;
+}
+
+int bitcoinconsensus_transaction_weight(const unsigned char *txTo, unsigned int txToLen,
+                                    int64_t* weight, bitcoinconsensus_error* err)
+{
+    try {
+        TxInputStream stream(SER_NETWORK, PROTOCOL_VERSION, txTo, txToLen);
+        CTransaction tx(deserialize, stream);
+        if (GetSerializeSize(tx, PROTOCOL_VERSION) != txToLen)
+            return set_error(err, bitcoinconsensus_ERR_TX_SIZE_MISMATCH);
+
+        *weight = GetTransactionWeight(tx);
+        set_error(err, bitcoinconsensus_ERR_OK);
+        return 1;
+    } catch (const std::exception&) {
+        return set_error(err, bitcoinconsensus_ERR_TX_DESERIALIZE); // Error deserializing
+    }
+}
+
+int bitcoinconsensus_transaction_sigop_cost(const unsigned char *txTo, unsigned int txToLen,
+                                    const unsigned char *spentOutputs, unsigned int spentOutputsLen,
+                                    unsigned int flags, int64_t* sigopCost, bitcoinconsensus_error* err)
+{
+    if (!verify_flags(flags)) {
+        return set_error(err, bitcoinconsensus_ERR_INVALID_FLAGS);
+    }
+    try {
+        TxInputStream stream(SER_NETWORK, PROTOCOL_VERSION, txTo, txToLen);
+        CTransaction tx(deserialize, stream);
+        std::vector<CTxOut> spent_outputs;
+        TxInputStream spent_outputs_stream(SER_NETWORK, PROTOCOL_VERSION, spentOutputs, spentOutputsLen);
+        spent_outputs_stream >> spent_outputs;
+
+        if (GetSerializeSize(tx, PROTOCOL_VERSION) != txToLen)
+            return set_error(err, bitcoinconsensus_ERR_TX_SIZE_MISMATCH);
+        if (GetSerializeSize(spent_outputs, PROTOCOL_VERSION) != spentOutputsLen)
+            return set_error(err, bitcoinconsensus_ERR_TX_DESERIALIZE);
+        // The coinbase spends no outputs, its inputs are only counted for their scriptSig.
+        if (spent_outputs.size() != (tx.IsCoinBase() ? 0 : tx.vin.size()))
+            return set_error(err, bitcoinconsensus_ERR_SPENT_OUTPUTS_MISMATCH);
+
+        *sigopCost = sigop_cost(tx, spent_outputs.data(), flags);
+        set_error(err, bitcoinconsensus_ERR_OK);
+        return 1;
+    } catch (const std::exception&) {
+        return set_error(err, bitcoinconsensus_ERR_TX_DESERIALIZE); // Error deserializing
+    }
+}
 
 int bitcoinconsensus_verify_script(const unsigned char *hash_genesis_block,
                                    const unsigned char *scriptPubKey, unsigned int scriptPubKeyLen,
@@ -134,7 +1434,7 @@ int bitcoinconsensus_verify_script(const unsigned char *hash_genesis_block,
     }
 
     CConfidentialValue am(0);
-    return ::verify_script(hash_genesis_block, scriptPubKey, scriptPubKeyLen, am, txTo, txToLen, nIn, flags, err);
+    return ::verify_script(hash_genesis_block, scriptPubKey, scriptPubKeyLen, am, txTo, txToLen, nIn, flags, err, nullptr);
 }
 
 unsigned int bitcoinconsensus_version()
@@ -43,6 +43,14 @@ typedef enum bitcoinconsensus_error_t
     bitcoinconsensus_ERR_TX_DESERIALIZE,
     bitcoinconsensus_ERR_AMOUNT_REQUIRED,
     bitcoinconsensus_ERR_INVALID_FLAGS,
+    bitcoinconsensus_ERR_SPENT_OUTPUTS_REQUIRED,
+    bitcoinconsensus_ERR_SPENT_OUTPUTS_MISMATCH,
+    bitcoinconsensus_ERR_INVALID_SIGHASH,
+    bitcoinconsensus_ERR_TX_COINBASE,
+    bitcoinconsensus_ERR_OUTPUT_PROOFS_MISMATCH,
+    bitcoinconsensus_ERR_TX_NOT_PEGIN,
+    bitcoinconsensus_ERR_TX_NO_ISSUANCE,
+    bitcoinconsensus_ERR_EXCEPTION,
 } bitcoinconsensus_error;
 
 /** Script verification flags */
@@ -55,9 +63,12 @@ enum
     bitcoinconsensus_SCRIPT_FLAGS_VERIFY_CHECKLOCKTIMEVERIFY = (1U << 9), // enable CHECKLOCKTIMEVERIFY (BIP65)
     bitcoinconsensus_SCRIPT_FLAGS_VERIFY_CHECKSEQUENCEVERIFY = (1U << 10), // enable CHECKSEQUENCEVERIFY (BIP112)
     bitcoinconsensus_SCRIPT_FLAGS_VERIFY_WITNESS             = (1U << 11), // enable WITNESS (BIP141)
+    bitcoinconsensus_SCRIPT_FLAGS_VERIFY_TAPROOT             = (1U << 17), // enable TAPROOT (BIPs 341 & 342)
+    bitcoinconsensus_SCRIPT_FLAGS_VERIFY_SIGHASH_RANGEPROOF  = (1U << 22), // allow SIGHASH_RANGEPROOF (dynamic federations), not part of VERIFY_ALL
     bitcoinconsensus_SCRIPT_FLAGS_VERIFY_ALL                 = bitcoinconsensus_SCRIPT_FLAGS_VERIFY_P2SH | bitcoinconsensus_SCRIPT_FLAGS_VERIFY_DERSIG |
                                                                bitcoinconsensus_SCRIPT_FLAGS_VERIFY_NULLDUMMY | bitcoinconsensus_SCRIPT_FLAGS_VERIFY_CHECKLOCKTIMEVERIFY |
-                                                               bitcoinconsensus_SCRIPT_FLAGS_VERIFY_CHECKSEQUENCEVERIFY | bitcoinconsensus_SCRIPT_FLAGS_VERIFY_WITNESS
+                                                               bitcoinconsensus_SCRIPT_FLAGS_VERIFY_CHECKSEQUENCEVERIFY | bitcoinconsensus_SCRIPT_FLAGS_VERIFY_WITNESS |
+                                                               bitcoinconsensus_SCRIPT_FLAGS_VERIFY_TAPROOT
 };
 
 /// Returns 1 if the input nIn of the serialized transaction pointed to by
@@ -75,6 +86,430 @@ EXPORT_SYMBOL int bitcoinconsensus_verify_script_with_amount(const unsigned char
                                     const unsigned char *txTo        , unsigned int txToLen,
                                     unsigned int nIn, unsigned int flags, bitcoinconsensus_error* err);
 
+/// Same as bitcoinconsensus_verify_script_with_amount.
+/// Spending a Taproot output requires all spent outputs, so if flags contain
+/// bitcoinconsensus_SCRIPT_FLAGS_VERIFY_TAPROOT and scriptPubKey is a Taproot output,
+/// err will be set to bitcoinconsensus_ERR_SPENT_OUTPUTS_REQUIRED.
+/// If not nullptr, script_err will contain the ScriptError reported by the interpreter,
+/// see script/script_error.h for the possible values.
+EXPORT_SYMBOL int bitcoinconsensus_verify_script_with_amount_and_script_error(const unsigned char *hash_genesis_block,
+                                    const unsigned char *scriptPubKey, unsigned int scriptPubKeyLen,
+                                    const unsigned char *amount, unsigned int amountLen,
+                                    const unsigned char *txTo        , unsigned int txToLen,
+                                    unsigned int nIn, unsigned int flags, bitcoinconsensus_error* err,
+                                    unsigned int* script_err);
+
+/// Same as bitcoinconsensus_verify_script_with_amount_and_script_error, but for a Bitcoin transaction.
+/// txTo is deserialized and its signatures are hashed like in Bitcoin, amount is the value of the
+/// spent output in satoshi, and the opcodes that Elements adds or re-enables are rejected.
+/// Elements mode is only switched off for the calling thread, so Elements transactions can be
+/// verified on other threads at the same time.
+/// Taproot is implemented for Elements only, so flags containing
+/// bitcoinconsensus_SCRIPT_FLAGS_VERIFY_TAPROOT are rejected with bitcoinconsensus_ERR_INVALID_FLAGS.
+/// So are flags containing bitcoinconsensus_SCRIPT_FLAGS_VERIFY_SIGHASH_RANGEPROOF, which Bitcoin does not have.
+EXPORT_SYMBOL int bitcoinconsensus_verify_script_bitcoin(const unsigned char *scriptPubKey, unsigned int scriptPubKeyLen, int64_t amount,
+                                    const unsigned char *txTo        , unsigned int txToLen,
+                                    unsigned int nIn, unsigned int flags, bitcoinconsensus_error* err,
+                                    unsigned int* script_err);
+
+/// Returns 1 if the input nIn of the serialized transaction pointed to by
+/// txTo correctly spends its spent output under the additional constraints
+/// specified by flags.
+/// spentOutputs points to the serialized vector of all outputs spent by txTo,
+/// in the order of its inputs. The scriptPubKey and amount of input nIn are
+/// taken from it, and the Taproot signature hash commits to all of them.
+/// If not nullptr, err will contain an error/success code for the operation
+/// and script_err will contain the ScriptError reported by the interpreter.
+EXPORT_SYMBOL int bitcoinconsensus_verify_script_with_spent_outputs(const unsigned char *hash_genesis_block,
+                                    const unsigned char *txTo        , unsigned int txToLen,
+                                    const unsigned char *spentOutputs, unsigned int spentOutputsLen,
+                                    unsigned int nIn, unsigned int flags, bitcoinconsensus_error* err,
+                                    unsigned int* script_err);
+
+/// A transaction that has been deserialized once and whose signature hashing data has been precomputed,
+/// so that many of its inputs can be verified without repeating that work.
+typedef struct bitcoinconsensus_transaction bitcoinconsensus_transaction;
+
+/// Deserializes the transaction pointed to by txTo and precomputes the data shared by all of its inputs.
+/// Returns nullptr on failure, in which case err will contain the reason if not nullptr.
+/// The returned transaction must be released with bitcoinconsensus_transaction_destroy.
+EXPORT_SYMBOL bitcoinconsensus_transaction* bitcoinconsensus_transaction_create(const unsigned char *hash_genesis_block,
+                                    const unsigned char *txTo, unsigned int txToLen, bitcoinconsensus_error* err);
+
+/// Same as bitcoinconsensus_transaction_create, but also takes the serialized vector of all outputs spent by txTo,
+/// in the order of its inputs, which is required to verify Taproot spends.
+EXPORT_SYMBOL bitcoinconsensus_transaction* bitcoinconsensus_transaction_create_with_spent_outputs(const unsigned char *hash_genesis_block,
+                                    const unsigned char *txTo, unsigned int txToLen,
+                                    const unsigned char *spentOutputs, unsigned int spentOutputsLen,
+                                    bitcoinconsensus_error* err);
+
+EXPORT_SYMBOL void bitcoinconsensus_transaction_destroy(bitcoinconsensus_transaction* tx);
+
+/// Same as bitcoinconsensus_verify_script_with_amount_and_script_error, but for a transaction created through
+/// bitcoinconsensus_transaction_create or bitcoinconsensus_transaction_create_with_spent_outputs.
+/// Taproot outputs can only be verified if the transaction was created with its spent outputs.
+EXPORT_SYMBOL int bitcoinconsensus_transaction_verify_input(const bitcoinconsensus_transaction* tx,
+                                    const unsigned char *scriptPubKey, unsigned int scriptPubKeyLen,
+                                    const unsigned char *amount, unsigned int amountLen,
+                                    unsigned int nIn, unsigned int flags, bitcoinconsensus_error* err,
+                                    unsigned int* script_err);
+
+/// Callbacks through which bitcoinconsensus_transaction_trace_input reports the execution of scripts.
+/// script is called before each script is executed, step after each opcode of it, with the byte offset pc
+/// of the opcode, whether it was executed or skipped in an unexecuted branch, and the resulting main and
+/// alt stacks, each serialized as a vector of byte vectors. ctx is passed through to both callbacks.
+typedef struct bitcoinconsensus_tracer
+{
+    void* ctx;
+    void (*script)(void* ctx, const unsigned char *script, unsigned int scriptLen);
+    void (*step)(void* ctx, unsigned int opcode, unsigned int pc, int executed,
+                 const unsigned char *stack, unsigned int stackLen,
+                 const unsigned char *altstack, unsigned int altstackLen);
+} bitcoinconsensus_tracer;
+
+/// Same as bitcoinconsensus_transaction_verify_input, but reports every step of the interpreter to tracer.
+EXPORT_SYMBOL int bitcoinconsensus_transaction_trace_input(const bitcoinconsensus_transaction* tx,
+                                    const unsigned char *scriptPubKey, unsigned int scriptPubKeyLen,
+                                    const unsigned char *amount, unsigned int amountLen,
+                                    unsigned int nIn, unsigned int flags, const bitcoinconsensus_tracer* tracer,
+                                    bitcoinconsensus_error* err, unsigned int* script_err);
+
+/** Signature versions for bitcoinconsensus_signature_hash */
+enum
+{
+    bitcoinconsensus_SIGVERSION_BASE       = 0, // bare scripts and P2SH redeem scripts
+    bitcoinconsensus_SIGVERSION_WITNESS_V0 = 1, // P2WPKH and P2WSH (BIP143)
+};
+
+/// Computes the signature hash that a signature of the given sigversion in input nIn of the serialized
+/// transaction pointed to by txTo commits to, and writes its 32 bytes to hash.
+/// scriptCode and amount are those of the output spent by input nIn; amount is ignored for bitcoinconsensus_SIGVERSION_BASE.
+/// Returns 1 on success. Otherwise, err will contain the reason if not nullptr.
+EXPORT_SYMBOL int bitcoinconsensus_signature_hash(const unsigned char *hash_genesis_block,
+                                    const unsigned char *scriptCode, unsigned int scriptCodeLen,
+                                    const unsigned char *amount, unsigned int amountLen,
+                                    const unsigned char *txTo        , unsigned int txToLen,
+                                    unsigned int nIn, int nHashType, unsigned int sigversion,
+                                    unsigned char *hash, bitcoinconsensus_error* err);
+
+/// Computes the Taproot signature hash (BIP341) that a Schnorr signature in input nIn of the serialized
+/// transaction pointed to by txTo commits to, and writes its 32 bytes to hash.
+/// spentOutputs points to the serialized vector of all outputs spent by txTo, in the order of its inputs.
+/// For key path spends, tapleafHash must be nullptr. For script path spends (BIP342), it points to the
+/// 32 byte tapleaf hash of the executed script and codeseparatorPos is the opcode position of the last
+/// executed OP_CODESEPARATOR, or 0xFFFFFFFF if none was executed.
+/// The annex, if any, is taken from the witness of input nIn.
+/// Returns 1 on success. Otherwise, err will contain the reason if not nullptr.
+EXPORT_SYMBOL int bitcoinconsensus_signature_hash_taproot(const unsigned char *hash_genesis_block,
+                                    const unsigned char *txTo        , unsigned int txToLen,
+                                    const unsigned char *spentOutputs, unsigned int spentOutputsLen,
+                                    unsigned int nIn, unsigned char hashType,
+                                    const unsigned char *tapleafHash, unsigned int codeseparatorPos,
+                                    unsigned char *hash, bitcoinconsensus_error* err);
+
+/** Checks of bitcoinconsensus_verify_amounts */
+enum
+{
+    bitcoinconsensus_AMOUNTS_ERR_OK              = 0,
+    bitcoinconsensus_AMOUNTS_ERR_FEE             = 1, // explicit fee outputs are zero, out of range or overflow
+    bitcoinconsensus_AMOUNTS_ERR_INPUT           = 2, // the asset or value of a spent output is invalid
+    bitcoinconsensus_AMOUNTS_ERR_ISSUANCE        = 3, // the issuance of an input is invalid, including its rangeproofs
+    bitcoinconsensus_AMOUNTS_ERR_OUTPUT          = 4, // the asset, value or nonce of an output is invalid, or a spendable output has zero value
+    bitcoinconsensus_AMOUNTS_ERR_BALANCE         = 5, // inputs and issuances do not balance outputs and fees
+    bitcoinconsensus_AMOUNTS_ERR_RANGEPROOF      = 6, // the rangeproof of an output is missing or invalid
+    bitcoinconsensus_AMOUNTS_ERR_SURJECTIONPROOF = 7, // the surjection proof of an output is missing or invalid
+    bitcoinconsensus_AMOUNTS_ERR_UNKNOWN         = 8, // the check failed for a reason this library does not know
+};
+
+/// Returns 1 if the value commitments of the inputs, issuances, outputs and fees of the serialized
+/// transaction pointed to by txTo balance and the rangeproofs and surjection proofs of its outputs are valid.
+/// spentOutputs points to the serialized vector of all outputs spent by txTo, in the order of its inputs.
+/// If not nullptr, err will contain an error/success code for the operation, amounts_err the failed
+/// check and amounts_err_index the index of the input or output it failed for.
+/// Coinbase transactions are rejected with bitcoinconsensus_ERR_TX_COINBASE.
+EXPORT_SYMBOL int bitcoinconsensus_verify_amounts(const unsigned char *txTo        , unsigned int txToLen,
+                                    const unsigned char *spentOutputs, unsigned int spentOutputsLen,
+                                    bitcoinconsensus_error* err, unsigned int* amounts_err,
+                                    unsigned int* amounts_err_index);
+
+/** Proofs reported as invalid per output by bitcoinconsensus_verify_output_proofs */
+enum
+{
+    bitcoinconsensus_OUTPUT_PROOFS_VALID                   = 0,
+    bitcoinconsensus_OUTPUT_PROOFS_INVALID_RANGEPROOF      = (1U << 0), // missing or invalid, or present on an explicit value
+    bitcoinconsensus_OUTPUT_PROOFS_INVALID_SURJECTIONPROOF = (1U << 1), // missing or invalid, or present on an explicit asset
+};
+
+/// Returns 1 if the rangeproofs and surjection proofs of all outputs of the serialized transaction
+/// pointed to by txTo are valid, without checking that its amounts balance.
+/// spentOutputs points to the serialized vector of all outputs spent by txTo, in the order of its inputs,
+/// whose assets are the ones the surjection proofs are verified against.
+/// outputProofs must point to one byte per output of txTo, which is set to the bitcoinconsensus_OUTPUT_PROOFS_*
+/// flags of the invalid proofs of that output; otherwise err is set to bitcoinconsensus_ERR_OUTPUT_PROOFS_MISMATCH.
+/// If not nullptr, err will contain an error/success code for the operation.
+/// Coinbase transactions are rejected with bitcoinconsensus_ERR_TX_COINBASE.
+EXPORT_SYMBOL int bitcoinconsensus_verify_output_proofs(const unsigned char *txTo        , unsigned int txToLen,
+                                    const unsigned char *spentOutputs, unsigned int spentOutputsLen,
+                                    unsigned char *outputProofs, unsigned int outputProofsLen,
+                                    bitcoinconsensus_error* err);
+
+/** Reject reasons of bitcoinconsensus_check_transaction, see consensus/tx_check.cpp */
+enum
+{
+    bitcoinconsensus_TX_CHECK_ERR_OK                  = 0,
+    bitcoinconsensus_TX_CHECK_ERR_VIN_EMPTY           = 1,  // bad-txns-vin-empty
+    bitcoinconsensus_TX_CHECK_ERR_VOUT_EMPTY          = 2,  // bad-txns-vout-empty
+    bitcoinconsensus_TX_CHECK_ERR_OVERSIZE            = 3,  // bad-txns-oversize
+    bitcoinconsensus_TX_CHECK_ERR_VOUT_AMOUNT_INVALID = 4,  // bad-txns-vout-amount-invalid
+    bitcoinconsensus_TX_CHECK_ERR_VOUT_NEGATIVE       = 5,  // bad-txns-vout-negative
+    bitcoinconsensus_TX_CHECK_ERR_VOUT_TOOLARGE       = 6,  // bad-txns-vout-toolarge
+    bitcoinconsensus_TX_CHECK_ERR_TXOUTTOTAL_TOOLARGE = 7,  // bad-txns-txouttotal-toolarge
+    bitcoinconsensus_TX_CHECK_ERR_INPUTS_DUPLICATE    = 8,  // bad-txns-inputs-duplicate
+    bitcoinconsensus_TX_CHECK_ERR_CB_LENGTH           = 9,  // bad-cb-length
+    bitcoinconsensus_TX_CHECK_ERR_CB_FEE              = 10, // bad-cb-fee
+    bitcoinconsensus_TX_CHECK_ERR_PREVOUT_NULL        = 11, // bad-txns-prevout-null
+    bitcoinconsensus_TX_CHECK_ERR_UNKNOWN             = 12, // a reject reason this library does not know
+};
+
+/// Returns 1 if the serialized transaction pointed to by txTo passes the context-free checks
+/// that nodes run on every transaction (CheckTransaction), such as non-empty inputs and outputs,
+/// no duplicate inputs, output values in range and size limits.
+/// If not nullptr, err will contain an error/success code for the operation and tx_check_err
+/// the reject reason.
+EXPORT_SYMBOL int bitcoinconsensus_check_transaction(const unsigned char *txTo, unsigned int txToLen,
+                                    bitcoinconsensus_error* err, unsigned int* tx_check_err);
+
+/** Checks of bitcoinconsensus_verify_pegin, see consensus/pegin_check.h */
+enum
+{
+    bitcoinconsensus_PEGIN_ERR_OK                = 0,
+    bitcoinconsensus_PEGIN_ERR_WITNESS           = 1,  // the pegin witness does not have the expected items or their encoding is invalid
+    bitcoinconsensus_PEGIN_ERR_VALUE             = 2,  // the claimed value is out of range
+    bitcoinconsensus_PEGIN_ERR_CLAIM_SCRIPT_SIZE = 3,  // the claim script is too large
+    bitcoinconsensus_PEGIN_ERR_MERKLE_BLOCK      = 4,  // the merkle block cannot be decoded or does not prove exactly one transaction
+    bitcoinconsensus_PEGIN_ERR_POW               = 5,  // the parent block's proof of work is invalid or insufficient
+    bitcoinconsensus_PEGIN_ERR_TX                = 6,  // the parent transaction cannot be decoded or does not have the claimed output
+    bitcoinconsensus_PEGIN_ERR_TXID              = 7,  // the parent transaction or merkle proof does not match the claimed outpoint
+    bitcoinconsensus_PEGIN_ERR_AMOUNT            = 8,  // the claimed value does not match the parent output
+    bitcoinconsensus_PEGIN_ERR_CLAIM_SCRIPT      = 9,  // the parent output does not pay to the fedpegscript tweaked with the claim script
+    bitcoinconsensus_PEGIN_ERR_GENESIS           = 10, // the parent genesis block hash does not match
+    bitcoinconsensus_PEGIN_ERR_ASSET             = 11, // the claimed asset is not the pegged asset
+    bitcoinconsensus_PEGIN_ERR_DEPTH             = 12, // the parent block does not have enough confirmations
+    bitcoinconsensus_PEGIN_ERR_UNKNOWN           = 13, // the check failed for a reason this library does not know
+};
+
+/// Returns 1 if input nIn of the serialized transaction pointed to by txTo is a peg-in whose pegin witness
+/// proves the claimed output on a parent chain with proof of work.
+/// parentGenesisHash, parentPowLimit and peggedAsset point to 32 bytes each, in internal byte order.
+/// fedpegProgram is the scriptPubKey the federation is paid to on the parent chain and fedpegScript the
+/// script it commits to, which is tweaked with the claim script.
+/// confirmations is the number of confirmations of the parent block that the merkle proof commits to. It must
+/// be at least peginMinDepth, and at least the coinbase maturity for claims of coinbase outputs.
+/// If not nullptr, err will contain an error/success code for the operation and pegin_err the failed check.
+/// Inputs that are not peg-ins are rejected with bitcoinconsensus_ERR_TX_NOT_PEGIN.
+EXPORT_SYMBOL int bitcoinconsensus_verify_pegin(const unsigned char *txTo, unsigned int txToLen, unsigned int nIn,
+                                    const unsigned char *parentGenesisHash, const unsigned char *parentPowLimit,
+                                    const unsigned char *peggedAsset,
+                                    const unsigned char *fedpegProgram, unsigned int fedpegProgramLen,
+                                    const unsigned char *fedpegScript, unsigned int fedpegScriptLen,
+                                    unsigned int peginMinDepth, unsigned int confirmations,
+                                    bitcoinconsensus_error* err, unsigned int* pegin_err);
+
+/** Checks of bitcoinconsensus_verify_issuance */
+enum
+{
+    bitcoinconsensus_ISSUANCE_ERR_OK                        = 0,
+    bitcoinconsensus_ISSUANCE_ERR_WITNESS                   = 1, // the transaction has no witness, which issuances of a non-null amount require
+    bitcoinconsensus_ISSUANCE_ERR_AMOUNT                    = 2, // the issued amount is invalid, zero, out of range or explicit but has a rangeproof
+    bitcoinconsensus_ISSUANCE_ERR_AMOUNT_RANGEPROOF         = 3, // the rangeproof of the blinded issued amount is invalid
+    bitcoinconsensus_ISSUANCE_ERR_INFLATION_KEYS            = 4, // the amount of issued reissuance tokens is invalid, zero, out of range or explicit but has a rangeproof
+    bitcoinconsensus_ISSUANCE_ERR_INFLATION_KEYS_RANGEPROOF = 5, // the rangeproof of the blinded amount of issued reissuance tokens is invalid
+    bitcoinconsensus_ISSUANCE_ERR_REISSUANCE_INFLATION_KEYS = 6, // a reissuance issues reissuance tokens
+    bitcoinconsensus_ISSUANCE_ERR_REISSUANCE_TOKEN          = 7, // the spent output is not the reissuance token blinded with the assetBlindingNonce
+    bitcoinconsensus_ISSUANCE_ERR_UNKNOWN                   = 8, // the check failed for a reason this library does not know
+};
+
+/// Returns 1 if the asset issuance of input nIn of the serialized transaction pointed to by txTo is valid.
+/// spentOutput points to the serialized output spent by input nIn.
+/// Regardless of the result, entropy, asset and token receive the 32 bytes of the issuance entropy, the
+/// issued asset and its reissuance token, unless err is set to anything but bitcoinconsensus_ERR_OK.
+/// If not nullptr, err will contain an error/success code for the operation and issuance_err the failed check.
+/// Inputs without an issuance are rejected with bitcoinconsensus_ERR_TX_NO_ISSUANCE.
+EXPORT_SYMBOL int bitcoinconsensus_verify_issuance(const unsigned char *txTo        , unsigned int txToLen,
+                                    const unsigned char *spentOutput, unsigned int spentOutputLen,
+                                    unsigned int nIn, unsigned char *entropy, unsigned char *asset,
+                                    unsigned char *token, bitcoinconsensus_error* err, unsigned int* issuance_err);
+
+/** Checks of bitcoinconsensus_verify_block_header, see block_proof.cpp */
+enum
+{
+    bitcoinconsensus_HEADER_ERR_OK             = 0,
+    bitcoinconsensus_HEADER_ERR_CHALLENGE      = 1, // the challenge of a header without dynamic federation parameters is not the signblockscript
+    bitcoinconsensus_HEADER_ERR_SIGNATURE_SIZE = 2, // the block signature is larger than the limit
+    bitcoinconsensus_HEADER_ERR_SIGNATURE      = 3, // the block signature does not satisfy the signblockscript, see script_err
+};
+
+/// Returns 1 if the serialized block header pointed to by header is signed by the federation.
+/// The header is serialized like on a chain with signed blocks and the block height in the header,
+/// i.e. -con_signed_blocks=1 -con_blockheightinheader=1.
+/// Headers without dynamic federation parameters are checked against signblockscript, whose solution
+/// must be at most maxBlockSignatureSize bytes. Headers with dynamic federation parameters are
+/// checked against the signblockscript and witness limit of their current parameters instead.
+/// If not nullptr, err will contain an error/success code for the operation, header_err the failed check
+/// and script_err the reason why the signature does not satisfy the signblockscript.
+EXPORT_SYMBOL int bitcoinconsensus_verify_block_header(const unsigned char *header, unsigned int headerLen,
+                                    const unsigned char *signblockscript, unsigned int signblockscriptLen,
+                                    unsigned int maxBlockSignatureSize, bitcoinconsensus_error* err,
+                                    unsigned int* header_err, unsigned int* script_err);
+
+/** Reject reasons of bitcoinconsensus_check_block, see CheckBlock, ContextualCheckBlock and ConnectBlock in validation.cpp */
+enum
+{
+    bitcoinconsensus_BLOCK_ERR_OK                   = 0,
+    bitcoinconsensus_BLOCK_ERR_MERKLE_ROOT          = 1,  // bad-txnmrklroot
+    bitcoinconsensus_BLOCK_ERR_DUPLICATE            = 2,  // bad-txns-duplicate
+    bitcoinconsensus_BLOCK_ERR_LENGTH               = 3,  // bad-blk-length
+    bitcoinconsensus_BLOCK_ERR_CB_MISSING           = 4,  // bad-cb-missing
+    bitcoinconsensus_BLOCK_ERR_CB_MULTIPLE          = 5,  // bad-cb-multiple
+    bitcoinconsensus_BLOCK_ERR_TX_CHECK             = 6,  // the reject reason of CheckTransaction, see tx_check_err
+    bitcoinconsensus_BLOCK_ERR_SIGOPS               = 7,  // bad-blk-sigops
+    bitcoinconsensus_BLOCK_ERR_CB_WITNESS           = 8,  // bad-cb-witness
+    bitcoinconsensus_BLOCK_ERR_WITNESS_NONCE_SIZE   = 9,  // bad-witness-nonce-size
+    bitcoinconsensus_BLOCK_ERR_WITNESS_MERKLE_MATCH = 10, // bad-witness-merkle-match
+    bitcoinconsensus_BLOCK_ERR_UNEXPECTED_WITNESS   = 11, // unexpected-witness
+    bitcoinconsensus_BLOCK_ERR_WEIGHT               = 12, // bad-blk-weight
+    bitcoinconsensus_BLOCK_ERR_REWARD_OUT_OF_RANGE  = 13, // bad-blockreward-outofrange
+    bitcoinconsensus_BLOCK_ERR_CB_AMOUNT            = 14, // bad-cb-amount
+};
+
+/// Returns 1 if the serialized block pointed to by block passes the checks that do not depend on the chain:
+/// its merkle root, size and weight, coinbase, witness commitment, the context-free checks of every
+/// transaction (CheckTransaction), and its signature operations.
+/// The block is serialized like bitcoinconsensus_verify_block_header expects, and its header is not checked.
+/// spentOutputs points to the serialized vector of the outputs spent by all transactions but the coinbase,
+/// in the order of the transactions and their inputs. They are used to count the signature operations of
+/// P2SH and witness spends and, since that means the inputs of the block are known, the coinbase is then
+/// also checked to pay at most the fees of the block in each asset, without a block subsidy.
+/// If spentOutputs is nullptr, only the checks that do not need them are done.
+/// Neither the scripts nor the amounts of the transactions are verified.
+/// If not nullptr, err will contain an error/success code for the operation, block_err the reject reason,
+/// block_err_index the index of the transaction it was found in for bitcoinconsensus_BLOCK_ERR_CB_MULTIPLE,
+/// bitcoinconsensus_BLOCK_ERR_TX_CHECK and bitcoinconsensus_BLOCK_ERR_SIGOPS, and tx_check_err the
+/// bitcoinconsensus_TX_CHECK_ERR_* reject reason for bitcoinconsensus_BLOCK_ERR_TX_CHECK.
+EXPORT_SYMBOL int bitcoinconsensus_check_block(const unsigned char *block, unsigned int blockLen,
+                                    const unsigned char *spentOutputs, unsigned int spentOutputsLen,
+                                    bitcoinconsensus_error* err, unsigned int* block_err,
+                                    unsigned int* block_err_index, unsigned int* tx_check_err);
+
+/// Computes the root that a block header commits to for the serialized dynamic federation parameter entry
+/// pointed to by params, and writes its 32 bytes to root. extraRoot receives the 32 bytes of the root of its
+/// fedpeg program, fedpegscript and extension space, which compact entries carry as their elided root.
+/// Both are zero for the null entry.
+/// Returns 1 on success. Otherwise, err will contain the reason if not nullptr.
+EXPORT_SYMBOL int bitcoinconsensus_dynafed_params_root(const unsigned char *params, unsigned int paramsLen,
+                                    unsigned char *root, unsigned char *extraRoot, bitcoinconsensus_error* err);
+
+/** Checks of bitcoinconsensus_verify_dynafed_params, see ContextualCheckDynaFedHeader in validation.cpp. Elements rejects all of them as invalid-dyna-fed */
+enum
+{
+    bitcoinconsensus_DYNAFED_ERR_OK                    = 0,
+    bitcoinconsensus_DYNAFED_ERR_CURRENT_NULL          = 1, // the header has no current parameters
+    bitcoinconsensus_DYNAFED_ERR_EXTENSION_BITS        = 2, // the header version sets bits reserved for future hard forks
+    bitcoinconsensus_DYNAFED_ERR_CURRENT_MISMATCH      = 3, // the current parameters are not the expected ones
+    bitcoinconsensus_DYNAFED_ERR_SIGNBLOCKSCRIPT       = 4, // a new proposed signblockscript is not a native segwit scriptPubKey
+    bitcoinconsensus_DYNAFED_ERR_FEDPEG_PROGRAM        = 5, // a new proposed fedpeg program is not a native segwit scriptPubKey
+    bitcoinconsensus_DYNAFED_ERR_FEDPEGSCRIPT          = 6, // a new proposed v0 fedpeg program is not the P2WSH of the proposed fedpegscript
+    bitcoinconsensus_DYNAFED_ERR_FEDPEGSCRIPT_OP_DEPTH = 7, // a new proposed v0 fedpegscript starts with OP_DEPTH
+    bitcoinconsensus_DYNAFED_ERR_EXTENSION_SPACE       = 8, // a new proposed extension space is not a list of valid PAK entries
+};
+
+/// Returns 1 if the dynamic federation parameters of the serialized block header pointed to by header are
+/// valid on a chain that has moved to dynamic federations. The header is serialized like
+/// bitcoinconsensus_verify_block_header expects.
+/// expectedCurrent points to the serialized parameter entry that the header must publish as its current
+/// parameters, and fullCurrent to the full parameters in effect, which proposals that change them are
+/// checked against. Both depend on the previous blocks of the chain, see ComputeNextBlockCurrentParameters
+/// and ComputeNextBlockFullCurrentParameters in dynafed.cpp.
+/// If enforcePak is not 0, a proposed extension space must be a list of PAK entries, like with -enforce_pak.
+/// If not nullptr, err will contain an error/success code for the operation and dynafed_err the failed check.
+EXPORT_SYMBOL int bitcoinconsensus_verify_dynafed_params(const unsigned char *header, unsigned int headerLen,
+                                    const unsigned char *expectedCurrent, unsigned int expectedCurrentLen,
+                                    const unsigned char *fullCurrent, unsigned int fullCurrentLen,
+                                    int enforcePak, bitcoinconsensus_error* err, unsigned int* dynafed_err);
+
+/** Node settings of bitcoinconsensus_check_standard, see policy/standard_check.h */
+enum
+{
+    bitcoinconsensus_POLICY_NONE                   = 0,
+    bitcoinconsensus_POLICY_PERMIT_BARE_MULTISIG   = (1U << 0), // -permitbaremultisig
+    bitcoinconsensus_POLICY_DATACARRIER            = (1U << 1), // -datacarrier
+    bitcoinconsensus_POLICY_MULTI_DATA_PERMITTED   = (1U << 2), // -multi_data_permitted
+    bitcoinconsensus_POLICY_ENFORCE_PAK            = (1U << 3), // -enforce_pak
+    bitcoinconsensus_POLICY_ANYONECANSPEND_AREMINE = (1U << 4), // -anyonecanspendaremine
+    bitcoinconsensus_POLICY_TAPROOT_ACTIVE         = (1U << 5), // Taproot outputs may be spent
+    bitcoinconsensus_POLICY_DYNAFED_ACTIVE         = (1U << 6), // dynamic federations are active, so signatures may commit to rangeproofs
+};
+
+/** Checks of bitcoinconsensus_check_standard, see MemPoolAccept::PreChecks and PolicyScriptChecks in validation.cpp */
+enum
+{
+    bitcoinconsensus_STANDARD_ERR_OK                     = 0,
+    bitcoinconsensus_STANDARD_ERR_VERSION                = 1,  // version
+    bitcoinconsensus_STANDARD_ERR_TX_SIZE                = 2,  // tx-size
+    bitcoinconsensus_STANDARD_ERR_SCRIPTSIG_SIZE         = 3,  // scriptsig-size
+    bitcoinconsensus_STANDARD_ERR_SCRIPTSIG_NOT_PUSHONLY = 4,  // scriptsig-not-pushonly
+    bitcoinconsensus_STANDARD_ERR_SCRIPTPUBKEY           = 5,  // scriptpubkey
+    bitcoinconsensus_STANDARD_ERR_BARE_MULTISIG          = 6,  // bare-multisig
+    bitcoinconsensus_STANDARD_ERR_DUST                   = 7,  // dust
+    bitcoinconsensus_STANDARD_ERR_MULTI_OP_RETURN        = 8,  // multi-op-return
+    bitcoinconsensus_STANDARD_ERR_TX_SIZE_SMALL          = 9,  // tx-size-small
+    bitcoinconsensus_STANDARD_ERR_INPUTS                 = 10, // bad-txns-nonstandard-inputs
+    bitcoinconsensus_STANDARD_ERR_WITNESS                = 11, // bad-witness-nonstandard
+    bitcoinconsensus_STANDARD_ERR_SIGOPS                 = 12, // bad-txns-too-many-sigops
+    bitcoinconsensus_STANDARD_ERR_NON_MANDATORY_SCRIPT   = 13, // non-mandatory-script-verify-flag
+    bitcoinconsensus_STANDARD_ERR_MANDATORY_SCRIPT       = 14, // mandatory-script-verify-flag-failed
+    bitcoinconsensus_STANDARD_ERR_UNKNOWN                = 15, // a reject reason this library does not know
+};
+
+/// Returns 1 if the serialized transaction pointed to by txTo is standard, i.e. passes the policy checks
+/// that elementsd runs before accepting it to its mempool, and all its inputs verify under the standard
+/// script verification flags.
+/// spentOutputs points to the serialized vector of all outputs spent by txTo, in the order of its inputs,
+/// including the outputs claimed by peg-ins. Only outputs of the 32 byte policyAsset can be dust, and
+/// peg-outs to the chain with the 32 byte parentGenesisHash may carry more data than maxDatacarrierBytes
+/// when PAK is enforced. dustRelayFee is in satoshi per kvB and policyFlags are bitcoinconsensus_POLICY_*.
+/// Consensus checks like bitcoinconsensus_check_transaction and bitcoinconsensus_verify_amounts are not repeated.
+/// Coinbase transactions are rejected with bitcoinconsensus_ERR_TX_COINBASE.
+/// If not nullptr, err will contain an error/success code for the operation, standard_err the failed check,
+/// standard_err_index the offending input or output, and script_err the ScriptError of a failed input.
+EXPORT_SYMBOL int bitcoinconsensus_check_standard(const unsigned char *hash_genesis_block,
+                                    const unsigned char *txTo, unsigned int txToLen,
+                                    const unsigned char *spentOutputs, unsigned int spentOutputsLen,
+                                    const unsigned char *policyAsset, const unsigned char *parentGenesisHash,
+                                    unsigned int dustRelayFee, unsigned int maxDatacarrierBytes, unsigned int policyFlags,
+                                    bitcoinconsensus_error* err, unsigned int* standard_err,
+                                    unsigned int* standard_err_index, unsigned int* script_err);
+
+/// Returns 1 if the weight of the serialized transaction pointed to by txTo was computed like
+/// GetTransactionWeight, i.e. its size without witness data times three plus its total size.
+/// The witness data of Elements includes the rangeproofs and surjection proofs of confidential outputs,
+/// which are therefore discounted.
+/// Regardless of the result, weight receives the weight, unless err is set to anything but bitcoinconsensus_ERR_OK.
+/// If not nullptr, err will contain an error/success code for the operation.
+EXPORT_SYMBOL int bitcoinconsensus_transaction_weight(const unsigned char *txTo, unsigned int txToLen,
+                                    int64_t* weight, bitcoinconsensus_error* err);
+
+/// Returns 1 if the signature operation cost of the serialized transaction pointed to by txTo was computed
+/// like GetTransactionSigOpCost, counting P2SH and witness signature operations only if flags enable
+/// bitcoinconsensus_SCRIPT_FLAGS_VERIFY_P2SH and bitcoinconsensus_SCRIPT_FLAGS_VERIFY_WITNESS respectively.
+/// spentOutputs points to the serialized vector of all outputs spent by txTo, in the order of its inputs,
+/// which must be empty for coinbase transactions. Peg-ins are counted by the claim script in their peg-in witness.
+/// Regardless of the result, sigopCost receives the cost, unless err is set to anything but bitcoinconsensus_ERR_OK.
+/// If not nullptr, err will contain an error/success code for the operation.
+EXPORT_SYMBOL int bitcoinconsensus_transaction_sigop_cost(const unsigned char *txTo, unsigned int txToLen,
+                                    const unsigned char *spentOutputs, unsigned int spentOutputsLen,
+                                    unsigned int flags, int64_t* sigopCost, bitcoinconsensus_error* err);
+
 EXPORT_SYMBOL unsigned int bitcoinconsensus_version();
 
 #ifdef __cplusplus
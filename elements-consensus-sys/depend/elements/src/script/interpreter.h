@@ -192,7 +192,7 @@ struct PrecomputedTransactionData
     explicit PrecomputedTransactionData() : PrecomputedTransactionData(uint256{}) {}
 
     template <class T>
-    void Init(const T& tx, std::vector<CTxOut>&& spent_outputs);
+    void Init(const T& tx, std::vector<CTxOut>&& spent_outputs, bool force = false);
 
     template <class T>
     explicit PrecomputedTransactionData(const T& tx);
@@ -246,6 +246,22 @@ static constexpr size_t TAPROOT_CONTROL_MAX_SIZE = TAPROOT_CONTROL_BASE_SIZE + T
 template <class T>
 uint256 SignatureHash(const CScript& scriptCode, const T& txTo, unsigned int nIn, int nHashType, const CConfidentialValue& amount, SigVersion sigversion, unsigned int flags, const PrecomputedTransactionData* cache = nullptr);
 
+template <class T>
+bool SignatureHashSchnorr(uint256& hash_out, const ScriptExecutionData& execdata, const T& tx_to, uint32_t in_pos, uint8_t hash_type, SigVersion sigversion, const PrecomputedTransactionData& cache);
+
+/** Receives the execution of scripts by EvalScript, see BaseSignatureChecker::GetTracer. */
+class ScriptTracer
+{
+public:
+    /** Called by EvalScript before executing script. */
+    virtual void TraceScript(const CScript& script) const = 0;
+
+    /** Called by EvalScript after each step, with the opcode at byte offset pc and the resulting stacks. */
+    virtual void TraceStep(opcodetype opcode, uint32_t pc, bool executed, const std::vector<std::vector<unsigned char>>& stack, const std::vector<std::vector<unsigned char>>& altstack) const = 0;
+
+    virtual ~ScriptTracer() {}
+};
+
 class BaseSignatureChecker
 {
 public:
@@ -269,6 +285,12 @@ public:
          return false;
     }
 
+    /** The tracer EvalScript reports its steps to, queried once per script. nullptr if scripts are not traced. */
+    virtual const ScriptTracer* GetTracer() const
+    {
+        return nullptr;
+    }
+
     virtual ~BaseSignatureChecker() {}
 };
 
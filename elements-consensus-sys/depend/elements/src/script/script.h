@@ -21,7 +21,7 @@
 #include <vector>
 
 // IsUnspendable() compatibility
-extern bool g_con_elementsmode;
+extern thread_local bool g_con_elementsmode;
 
 // Maximum number of bytes pushable to the stack
 static const unsigned int MAX_SCRIPT_ELEMENT_SIZE = 520;
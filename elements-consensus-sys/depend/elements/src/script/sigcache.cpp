@@ -5,6 +5,7 @@
 
 #include <script/sigcache.h>
 
+#include <confidential_validation.h>
 #include <pubkey.h>
 #include <random.h>
 #include <uint256.h>
@@ -169,7 +170,7 @@ void InitSurjectionproofCache()
             (nElems*sizeof(uint256)) >>20, nMaxCacheSize>>20, nElems);
 }
 
-bool CachingRangeProofChecker::VerifyRangeProof(const std::vector<unsigned char>& vchRangeProof, const std::vector<unsigned char>& vchValueCommitment, const std::vector<unsigned char>& vchAssetCommitment, const CScript& scriptPubKey, const rustsecp256k1_v0_4_1_context* rustsecp256k1_v0_4_1_ctx_verify_amounts) const
+bool CachingRangeProofChecker::VerifyRangeProof(const std::vector<unsigned char>& vchRangeProof, const std::vector<unsigned char>& vchValueCommitment, const std::vector<unsigned char>& vchAssetCommitment, const CScript& scriptPubKey, const rustsecp256k1zkp_v0_4_0_context* rustsecp256k1zkp_v0_4_0_ctx_verify_amounts) const
 {
     uint256 entry;
     rangeProofCache.ComputeEntryRangeProof(entry, vchRangeProof, vchValueCommitment);
@@ -178,29 +179,7 @@ bool CachingRangeProofChecker::VerifyRangeProof(const std::vector<unsigned char>
         return true;
     }
 
-    if (vchRangeProof.size() == 0) {
-        return false;
-    }
-
-    uint64_t min_value, max_value;
-    rustsecp256k1_v0_4_1_pedersen_commitment commit;
-    if (rustsecp256k1_v0_4_1_pedersen_commitment_parse(rustsecp256k1_v0_4_1_ctx_verify_amounts, &commit, &vchValueCommitment[0]) != 1)
-            return false;
-
-    rustsecp256k1_v0_4_1_generator tag;
-    if (rustsecp256k1_v0_4_1_generator_parse(rustsecp256k1_v0_4_1_ctx_verify_amounts, &tag, &vchAssetCommitment[0]) != 1)
-        return false;
-
-    if (!rustsecp256k1_v0_4_1_rangeproof_verify(rustsecp256k1_v0_4_1_ctx_verify_amounts, &min_value, &max_value, &commit, vchRangeProof.data(), vchRangeProof.size(), scriptPubKey.size() ? &scriptPubKey.front() : NULL, scriptPubKey.size(), &tag)) {
-        return false;
-    }
-
-    // An rangeproof is not valid if the output is spendable but the minimum number
-    // is 0. This is to prevent people passing 0-value tokens around, or conjuring
-    // reissuance tokens from nothing then attempting to reissue an asset.
-    // ie reissuance doesn't require revealing value of reissuance output
-    // Issuances proofs are always "unspendable" as they commit to an empty script.
-    if (min_value == 0 && !scriptPubKey.IsUnspendable()) {
+    if (!::VerifyRangeProof(vchRangeProof, vchValueCommitment, vchAssetCommitment, scriptPubKey, rustsecp256k1zkp_v0_4_0_ctx_verify_amounts)) {
         return false;
     }
 
@@ -211,14 +190,14 @@ bool CachingRangeProofChecker::VerifyRangeProof(const std::vector<unsigned char>
     return true;
 }
 
-bool CachingSurjectionProofChecker::VerifySurjectionProof(rustsecp256k1_v0_4_1_surjectionproof& proof, std::vector<rustsecp256k1_v0_4_1_generator>& vTags, rustsecp256k1_v0_4_1_generator& gen, const rustsecp256k1_v0_4_1_context* rustsecp256k1_v0_4_1_ctx_verify_amounts, const uint256& wtxid) const
+bool CachingSurjectionProofChecker::VerifySurjectionProof(rustsecp256k1zkp_v0_4_0_surjectionproof& proof, std::vector<rustsecp256k1zkp_v0_4_0_generator>& vTags, rustsecp256k1zkp_v0_4_0_generator& gen, const rustsecp256k1zkp_v0_4_0_context* rustsecp256k1zkp_v0_4_0_ctx_verify_amounts, const uint256& wtxid) const
 {
 
     // Serialize proof
     std::vector<unsigned char> vchproof;
-    size_t proof_len = rustsecp256k1_v0_4_1_surjectionproof_serialized_size(rustsecp256k1_v0_4_1_ctx_verify_amounts, &proof);
+    size_t proof_len = rustsecp256k1zkp_v0_4_0_surjectionproof_serialized_size(rustsecp256k1zkp_v0_4_0_ctx_verify_amounts, &proof);
     vchproof.resize(proof_len);
-    assert(rustsecp256k1_v0_4_1_surjectionproof_serialize(rustsecp256k1_v0_4_1_ctx_verify_amounts, vchproof.data(), &proof_len, &proof) == 1);
+    assert(rustsecp256k1zkp_v0_4_0_surjectionproof_serialize(rustsecp256k1zkp_v0_4_0_ctx_verify_amounts, vchproof.data(), &proof_len, &proof) == 1);
 
     // wtxid commits to all data including surj targets
     // we need to specify the proof and output asset point to be unique
@@ -229,7 +208,7 @@ bool CachingSurjectionProofChecker::VerifySurjectionProof(rustsecp256k1_v0_4_1_s
         return true;
     }
 
-    if (rustsecp256k1_v0_4_1_surjectionproof_verify(rustsecp256k1_v0_4_1_ctx_verify_amounts, &proof, vTags.data(), vTags.size(), &gen) != 1) {
+    if (!::VerifySurjectionProof(proof, vTags, gen, rustsecp256k1zkp_v0_4_0_ctx_verify_amounts)) {
         return false;
     }
 
@@ -70,7 +70,7 @@ public:
         store = storeIn;
     };
 
-    bool VerifyRangeProof(const std::vector<unsigned char>& vchRangeProof, const std::vector<unsigned char>& vchValueCommitment, const std::vector<unsigned char>& vchAssetCommitment, const CScript& scriptPubKey, const rustsecp256k1_v0_4_1_context* ctx) const;
+    bool VerifyRangeProof(const std::vector<unsigned char>& vchRangeProof, const std::vector<unsigned char>& vchValueCommitment, const std::vector<unsigned char>& vchAssetCommitment, const CScript& scriptPubKey, const rustsecp256k1zkp_v0_4_0_context* ctx) const;
 
 };
 
@@ -83,7 +83,7 @@ public:
         store = storeIn;
     };
 
-    bool VerifySurjectionProof(rustsecp256k1_v0_4_1_surjectionproof& proof, std::vector<rustsecp256k1_v0_4_1_generator>& vTags, rustsecp256k1_v0_4_1_generator& gen, const rustsecp256k1_v0_4_1_context* ctx, const uint256& wtxid) const;
+    bool VerifySurjectionProof(rustsecp256k1zkp_v0_4_0_surjectionproof& proof, std::vector<rustsecp256k1zkp_v0_4_0_generator>& vTags, rustsecp256k1zkp_v0_4_0_generator& gen, const rustsecp256k1zkp_v0_4_0_context* ctx, const uint256& wtxid) const;
 
 };
 
@@ -0,0 +1,279 @@
+#include "shim.h"
+
+#include <ios>
+
+namespace {
+
+/** Runs f, turning any exception it throws into an error code and on_exception. */
+template <typename R, typename F>
+R guard(bitcoinconsensus_error* err, R on_exception, F f)
+{
+    try {
+        return f();
+    } catch (const std::ios_base::failure&) {
+        if (err)
+            *err = bitcoinconsensus_ERR_TX_DESERIALIZE;
+    } catch (...) {
+        if (err)
+            *err = bitcoinconsensus_ERR_EXCEPTION;
+    }
+    return on_exception;
+}
+
+} // namespace
+
+int shim_bitcoinconsensus_verify_script(const unsigned char *hash_genesis_block,
+                                    const unsigned char *scriptPubKey, unsigned int scriptPubKeyLen,
+                                    const unsigned char *txTo        , unsigned int txToLen,
+                                    unsigned int nIn, unsigned int flags, bitcoinconsensus_error* err)
+{
+    return guard(err, 0, [&] {
+        return bitcoinconsensus_verify_script(hash_genesis_block, scriptPubKey, scriptPubKeyLen, txTo, txToLen, nIn, flags, err);
+    });
+}
+
+int shim_bitcoinconsensus_verify_script_with_amount(const unsigned char *hash_genesis_block,
+                                    const unsigned char *scriptPubKey, unsigned int scriptPubKeyLen,
+                                    const unsigned char *amount, unsigned int amountLen,
+                                    const unsigned char *txTo        , unsigned int txToLen,
+                                    unsigned int nIn, unsigned int flags, bitcoinconsensus_error* err)
+{
+    return guard(err, 0, [&] {
+        return bitcoinconsensus_verify_script_with_amount(hash_genesis_block, scriptPubKey, scriptPubKeyLen, amount, amountLen, txTo, txToLen, nIn, flags, err);
+    });
+}
+
+int shim_bitcoinconsensus_verify_script_with_amount_and_script_error(const unsigned char *hash_genesis_block,
+                                    const unsigned char *scriptPubKey, unsigned int scriptPubKeyLen,
+                                    const unsigned char *amount, unsigned int amountLen,
+                                    const unsigned char *txTo        , unsigned int txToLen,
+                                    unsigned int nIn, unsigned int flags, bitcoinconsensus_error* err,
+                                    unsigned int* script_err)
+{
+    return guard(err, 0, [&] {
+        return bitcoinconsensus_verify_script_with_amount_and_script_error(hash_genesis_block, scriptPubKey, scriptPubKeyLen, amount, amountLen, txTo, txToLen, nIn, flags, err, script_err);
+    });
+}
+
+int shim_bitcoinconsensus_verify_script_bitcoin(const unsigned char *scriptPubKey, unsigned int scriptPubKeyLen, int64_t amount,
+                                    const unsigned char *txTo        , unsigned int txToLen,
+                                    unsigned int nIn, unsigned int flags, bitcoinconsensus_error* err,
+                                    unsigned int* script_err)
+{
+    return guard(err, 0, [&] {
+        return bitcoinconsensus_verify_script_bitcoin(scriptPubKey, scriptPubKeyLen, amount, txTo, txToLen, nIn, flags, err, script_err);
+    });
+}
+
+int shim_bitcoinconsensus_verify_script_with_spent_outputs(const unsigned char *hash_genesis_block,
+                                    const unsigned char *txTo        , unsigned int txToLen,
+                                    const unsigned char *spentOutputs, unsigned int spentOutputsLen,
+                                    unsigned int nIn, unsigned int flags, bitcoinconsensus_error* err,
+                                    unsigned int* script_err)
+{
+    return guard(err, 0, [&] {
+        return bitcoinconsensus_verify_script_with_spent_outputs(hash_genesis_block, txTo, txToLen, spentOutputs, spentOutputsLen, nIn, flags, err, script_err);
+    });
+}
+
+bitcoinconsensus_transaction* shim_bitcoinconsensus_transaction_create(const unsigned char *hash_genesis_block,
+                                    const unsigned char *txTo, unsigned int txToLen, bitcoinconsensus_error* err)
+{
+    return guard(err, static_cast<bitcoinconsensus_transaction*>(nullptr), [&] {
+        return bitcoinconsensus_transaction_create(hash_genesis_block, txTo, txToLen, err);
+    });
+}
+
+bitcoinconsensus_transaction* shim_bitcoinconsensus_transaction_create_with_spent_outputs(const unsigned char *hash_genesis_block,
+                                    const unsigned char *txTo, unsigned int txToLen,
+                                    const unsigned char *spentOutputs, unsigned int spentOutputsLen,
+                                    bitcoinconsensus_error* err)
+{
+    return guard(err, static_cast<bitcoinconsensus_transaction*>(nullptr), [&] {
+        return bitcoinconsensus_transaction_create_with_spent_outputs(hash_genesis_block, txTo, txToLen, spentOutputs, spentOutputsLen, err);
+    });
+}
+
+void shim_bitcoinconsensus_transaction_destroy(bitcoinconsensus_transaction* tx)
+{
+    guard(nullptr, 0, [&] {
+        bitcoinconsensus_transaction_destroy(tx);
+        return 0;
+    });
+}
+
+int shim_bitcoinconsensus_transaction_verify_input(const bitcoinconsensus_transaction* tx,
+                                    const unsigned char *scriptPubKey, unsigned int scriptPubKeyLen,
+                                    const unsigned char *amount, unsigned int amountLen,
+                                    unsigned int nIn, unsigned int flags, bitcoinconsensus_error* err,
+                                    unsigned int* script_err)
+{
+    return guard(err, 0, [&] {
+        return bitcoinconsensus_transaction_verify_input(tx, scriptPubKey, scriptPubKeyLen, amount, amountLen, nIn, flags, err, script_err);
+    });
+}
+
+int shim_bitcoinconsensus_transaction_trace_input(const bitcoinconsensus_transaction* tx,
+                                    const unsigned char *scriptPubKey, unsigned int scriptPubKeyLen,
+                                    const unsigned char *amount, unsigned int amountLen,
+                                    unsigned int nIn, unsigned int flags, const bitcoinconsensus_tracer* tracer,
+                                    bitcoinconsensus_error* err, unsigned int* script_err)
+{
+    return guard(err, 0, [&] {
+        return bitcoinconsensus_transaction_trace_input(tx, scriptPubKey, scriptPubKeyLen, amount, amountLen, nIn, flags, tracer, err, script_err);
+    });
+}
+
+int shim_bitcoinconsensus_signature_hash(const unsigned char *hash_genesis_block,
+                                    const unsigned char *scriptCode, unsigned int scriptCodeLen,
+                                    const unsigned char *amount, unsigned int amountLen,
+                                    const unsigned char *txTo        , unsigned int txToLen,
+                                    unsigned int nIn, int nHashType, unsigned int sigversion,
+                                    unsigned char *hash, bitcoinconsensus_error* err)
+{
+    return guard(err, 0, [&] {
+        return bitcoinconsensus_signature_hash(hash_genesis_block, scriptCode, scriptCodeLen, amount, amountLen, txTo, txToLen, nIn, nHashType, sigversion, hash, err);
+    });
+}
+
+int shim_bitcoinconsensus_signature_hash_taproot(const unsigned char *hash_genesis_block,
+                                    const unsigned char *txTo        , unsigned int txToLen,
+                                    const unsigned char *spentOutputs, unsigned int spentOutputsLen,
+                                    unsigned int nIn, unsigned char hashType,
+                                    const unsigned char *tapleafHash, unsigned int codeseparatorPos,
+                                    unsigned char *hash, bitcoinconsensus_error* err)
+{
+    return guard(err, 0, [&] {
+        return bitcoinconsensus_signature_hash_taproot(hash_genesis_block, txTo, txToLen, spentOutputs, spentOutputsLen, nIn, hashType, tapleafHash, codeseparatorPos, hash, err);
+    });
+}
+
+int shim_bitcoinconsensus_verify_amounts(const unsigned char *txTo        , unsigned int txToLen,
+                                    const unsigned char *spentOutputs, unsigned int spentOutputsLen,
+                                    bitcoinconsensus_error* err, unsigned int* amounts_err,
+                                    unsigned int* amounts_err_index)
+{
+    return guard(err, 0, [&] {
+        return bitcoinconsensus_verify_amounts(txTo, txToLen, spentOutputs, spentOutputsLen, err, amounts_err, amounts_err_index);
+    });
+}
+
+int shim_bitcoinconsensus_verify_output_proofs(const unsigned char *txTo        , unsigned int txToLen,
+                                    const unsigned char *spentOutputs, unsigned int spentOutputsLen,
+                                    unsigned char *outputProofs, unsigned int outputProofsLen,
+                                    bitcoinconsensus_error* err)
+{
+    return guard(err, 0, [&] {
+        return bitcoinconsensus_verify_output_proofs(txTo, txToLen, spentOutputs, spentOutputsLen, outputProofs, outputProofsLen, err);
+    });
+}
+
+int shim_bitcoinconsensus_check_transaction(const unsigned char *txTo, unsigned int txToLen,
+                                    bitcoinconsensus_error* err, unsigned int* tx_check_err)
+{
+    return guard(err, 0, [&] {
+        return bitcoinconsensus_check_transaction(txTo, txToLen, err, tx_check_err);
+    });
+}
+
+int shim_bitcoinconsensus_verify_pegin(const unsigned char *txTo, unsigned int txToLen, unsigned int nIn,
+                                    const unsigned char *parentGenesisHash, const unsigned char *parentPowLimit,
+                                    const unsigned char *peggedAsset,
+                                    const unsigned char *fedpegProgram, unsigned int fedpegProgramLen,
+                                    const unsigned char *fedpegScript, unsigned int fedpegScriptLen,
+                                    unsigned int peginMinDepth, unsigned int confirmations,
+                                    bitcoinconsensus_error* err, unsigned int* pegin_err)
+{
+    return guard(err, 0, [&] {
+        return bitcoinconsensus_verify_pegin(txTo, txToLen, nIn, parentGenesisHash, parentPowLimit, peggedAsset, fedpegProgram, fedpegProgramLen, fedpegScript, fedpegScriptLen, peginMinDepth, confirmations, err, pegin_err);
+    });
+}
+
+int shim_bitcoinconsensus_verify_issuance(const unsigned char *txTo        , unsigned int txToLen,
+                                    const unsigned char *spentOutput, unsigned int spentOutputLen,
+                                    unsigned int nIn, unsigned char *entropy, unsigned char *asset,
+                                    unsigned char *token, bitcoinconsensus_error* err, unsigned int* issuance_err)
+{
+    return guard(err, 0, [&] {
+        return bitcoinconsensus_verify_issuance(txTo, txToLen, spentOutput, spentOutputLen, nIn, entropy, asset, token, err, issuance_err);
+    });
+}
+
+int shim_bitcoinconsensus_verify_block_header(const unsigned char *header, unsigned int headerLen,
+                                    const unsigned char *signblockscript, unsigned int signblockscriptLen,
+                                    unsigned int maxBlockSignatureSize, bitcoinconsensus_error* err,
+                                    unsigned int* header_err, unsigned int* script_err)
+{
+    return guard(err, 0, [&] {
+        return bitcoinconsensus_verify_block_header(header, headerLen, signblockscript, signblockscriptLen, maxBlockSignatureSize, err, header_err, script_err);
+    });
+}
+
+int shim_bitcoinconsensus_check_block(const unsigned char *block, unsigned int blockLen,
+                                    const unsigned char *spentOutputs, unsigned int spentOutputsLen,
+                                    bitcoinconsensus_error* err, unsigned int* block_err,
+                                    unsigned int* block_err_index, unsigned int* tx_check_err)
+{
+    return guard(err, 0, [&] {
+        return bitcoinconsensus_check_block(block, blockLen, spentOutputs, spentOutputsLen, err, block_err, block_err_index, tx_check_err);
+    });
+}
+
+int shim_bitcoinconsensus_dynafed_params_root(const unsigned char *params, unsigned int paramsLen,
+                                    unsigned char *root, unsigned char *extraRoot, bitcoinconsensus_error* err)
+{
+    return guard(err, 0, [&] {
+        return bitcoinconsensus_dynafed_params_root(params, paramsLen, root, extraRoot, err);
+    });
+}
+
+int shim_bitcoinconsensus_verify_dynafed_params(const unsigned char *header, unsigned int headerLen,
+                                    const unsigned char *expectedCurrent, unsigned int expectedCurrentLen,
+                                    const unsigned char *fullCurrent, unsigned int fullCurrentLen,
+                                    int enforcePak, bitcoinconsensus_error* err, unsigned int* dynafed_err)
+{
+    return guard(err, 0, [&] {
+        return bitcoinconsensus_verify_dynafed_params(header, headerLen, expectedCurrent, expectedCurrentLen, fullCurrent, fullCurrentLen, enforcePak, err, dynafed_err);
+    });
+}
+
+int shim_bitcoinconsensus_check_standard(const unsigned char *hash_genesis_block,
+                                    const unsigned char *txTo, unsigned int txToLen,
+                                    const unsigned char *spentOutputs, unsigned int spentOutputsLen,
+                                    const unsigned char *policyAsset, const unsigned char *parentGenesisHash,
+                                    unsigned int dustRelayFee, unsigned int maxDatacarrierBytes, unsigned int policyFlags,
+                                    bitcoinconsensus_error* err, unsigned int* standard_err,
+                                    unsigned int* standard_err_index, unsigned int* script_err)
+{
+    return guard(err, 0, [&] {
+        return bitcoinconsensus_check_standard(hash_genesis_block, txTo, txToLen, spentOutputs, spentOutputsLen, policyAsset, parentGenesisHash, dustRelayFee, maxDatacarrierBytes, policyFlags, err, standard_err, standard_err_index, script_err);
+    });
+}
+
+int shim_bitcoinconsensus_transaction_weight(const unsigned char *txTo, unsigned int txToLen,
+                                    int64_t* weight, bitcoinconsensus_error* err)
+{
+    return guard(err, 0, [&] {
+        return bitcoinconsensus_transaction_weight(txTo, txToLen, weight, err);
+    });
+}
+
+int shim_bitcoinconsensus_transaction_sigop_cost(const unsigned char *txTo, unsigned int txToLen,
+                                    const unsigned char *spentOutputs, unsigned int spentOutputsLen,
+                                    unsigned int flags, int64_t* sigopCost, bitcoinconsensus_error* err)
+{
+    return guard(err, 0, [&] {
+        return bitcoinconsensus_transaction_sigop_cost(txTo, txToLen, spentOutputs, spentOutputsLen, flags, sigopCost, err);
+    });
+}
+
+int shim_test_guard(int kind, bitcoinconsensus_error* err)
+{
+    return guard(err, 0, [&] {
+        if (kind == 1)
+            throw std::ios_base::failure("test");
+        if (kind != 0)
+            throw kind;
+        return 1;
+    });
+}
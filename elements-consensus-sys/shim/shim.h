@@ -0,0 +1,148 @@
+#ifndef ELEMENTS_CONSENSUS_SYS_SHIM_H
+#define ELEMENTS_CONSENSUS_SYS_SHIM_H
+
+#include "../depend/elements/src/script/bitcoinconsensus.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/// Every entry point of bitcoinconsensus.h, prefixed with shim_, wrapped so that no C++ exception unwinds
+/// into the caller. An exception is turned into bitcoinconsensus_ERR_TX_DESERIALIZE if it is a
+/// std::ios_base::failure, which the serialization code throws on malformed input, and into
+/// bitcoinconsensus_ERR_EXCEPTION otherwise. The call then returns 0, or nullptr for the functions
+/// that create a transaction.
+
+int shim_bitcoinconsensus_verify_script(const unsigned char *hash_genesis_block,
+                                    const unsigned char *scriptPubKey, unsigned int scriptPubKeyLen,
+                                    const unsigned char *txTo        , unsigned int txToLen,
+                                    unsigned int nIn, unsigned int flags, bitcoinconsensus_error* err);
+
+int shim_bitcoinconsensus_verify_script_with_amount(const unsigned char *hash_genesis_block,
+                                    const unsigned char *scriptPubKey, unsigned int scriptPubKeyLen,
+                                    const unsigned char *amount, unsigned int amountLen,
+                                    const unsigned char *txTo        , unsigned int txToLen,
+                                    unsigned int nIn, unsigned int flags, bitcoinconsensus_error* err);
+
+int shim_bitcoinconsensus_verify_script_with_amount_and_script_error(const unsigned char *hash_genesis_block,
+                                    const unsigned char *scriptPubKey, unsigned int scriptPubKeyLen,
+                                    const unsigned char *amount, unsigned int amountLen,
+                                    const unsigned char *txTo        , unsigned int txToLen,
+                                    unsigned int nIn, unsigned int flags, bitcoinconsensus_error* err,
+                                    unsigned int* script_err);
+
+int shim_bitcoinconsensus_verify_script_bitcoin(const unsigned char *scriptPubKey, unsigned int scriptPubKeyLen, int64_t amount,
+                                    const unsigned char *txTo        , unsigned int txToLen,
+                                    unsigned int nIn, unsigned int flags, bitcoinconsensus_error* err,
+                                    unsigned int* script_err);
+
+int shim_bitcoinconsensus_verify_script_with_spent_outputs(const unsigned char *hash_genesis_block,
+                                    const unsigned char *txTo        , unsigned int txToLen,
+                                    const unsigned char *spentOutputs, unsigned int spentOutputsLen,
+                                    unsigned int nIn, unsigned int flags, bitcoinconsensus_error* err,
+                                    unsigned int* script_err);
+
+bitcoinconsensus_transaction* shim_bitcoinconsensus_transaction_create(const unsigned char *hash_genesis_block,
+                                    const unsigned char *txTo, unsigned int txToLen, bitcoinconsensus_error* err);
+
+bitcoinconsensus_transaction* shim_bitcoinconsensus_transaction_create_with_spent_outputs(const unsigned char *hash_genesis_block,
+                                    const unsigned char *txTo, unsigned int txToLen,
+                                    const unsigned char *spentOutputs, unsigned int spentOutputsLen,
+                                    bitcoinconsensus_error* err);
+
+void shim_bitcoinconsensus_transaction_destroy(bitcoinconsensus_transaction* tx);
+
+int shim_bitcoinconsensus_transaction_verify_input(const bitcoinconsensus_transaction* tx,
+                                    const unsigned char *scriptPubKey, unsigned int scriptPubKeyLen,
+                                    const unsigned char *amount, unsigned int amountLen,
+                                    unsigned int nIn, unsigned int flags, bitcoinconsensus_error* err,
+                                    unsigned int* script_err);
+
+int shim_bitcoinconsensus_transaction_trace_input(const bitcoinconsensus_transaction* tx,
+                                    const unsigned char *scriptPubKey, unsigned int scriptPubKeyLen,
+                                    const unsigned char *amount, unsigned int amountLen,
+                                    unsigned int nIn, unsigned int flags, const bitcoinconsensus_tracer* tracer,
+                                    bitcoinconsensus_error* err, unsigned int* script_err);
+
+int shim_bitcoinconsensus_signature_hash(const unsigned char *hash_genesis_block,
+                                    const unsigned char *scriptCode, unsigned int scriptCodeLen,
+                                    const unsigned char *amount, unsigned int amountLen,
+                                    const unsigned char *txTo        , unsigned int txToLen,
+                                    unsigned int nIn, int nHashType, unsigned int sigversion,
+                                    unsigned char *hash, bitcoinconsensus_error* err);
+
+int shim_bitcoinconsensus_signature_hash_taproot(const unsigned char *hash_genesis_block,
+                                    const unsigned char *txTo        , unsigned int txToLen,
+                                    const unsigned char *spentOutputs, unsigned int spentOutputsLen,
+                                    unsigned int nIn, unsigned char hashType,
+                                    const unsigned char *tapleafHash, unsigned int codeseparatorPos,
+                                    unsigned char *hash, bitcoinconsensus_error* err);
+
+int shim_bitcoinconsensus_verify_amounts(const unsigned char *txTo        , unsigned int txToLen,
+                                    const unsigned char *spentOutputs, unsigned int spentOutputsLen,
+                                    bitcoinconsensus_error* err, unsigned int* amounts_err,
+                                    unsigned int* amounts_err_index);
+
+int shim_bitcoinconsensus_verify_output_proofs(const unsigned char *txTo        , unsigned int txToLen,
+                                    const unsigned char *spentOutputs, unsigned int spentOutputsLen,
+                                    unsigned char *outputProofs, unsigned int outputProofsLen,
+                                    bitcoinconsensus_error* err);
+
+int shim_bitcoinconsensus_check_transaction(const unsigned char *txTo, unsigned int txToLen,
+                                    bitcoinconsensus_error* err, unsigned int* tx_check_err);
+
+int shim_bitcoinconsensus_verify_pegin(const unsigned char *txTo, unsigned int txToLen, unsigned int nIn,
+                                    const unsigned char *parentGenesisHash, const unsigned char *parentPowLimit,
+                                    const unsigned char *peggedAsset,
+                                    const unsigned char *fedpegProgram, unsigned int fedpegProgramLen,
+                                    const unsigned char *fedpegScript, unsigned int fedpegScriptLen,
+                                    unsigned int peginMinDepth, unsigned int confirmations,
+                                    bitcoinconsensus_error* err, unsigned int* pegin_err);
+
+int shim_bitcoinconsensus_verify_issuance(const unsigned char *txTo        , unsigned int txToLen,
+                                    const unsigned char *spentOutput, unsigned int spentOutputLen,
+                                    unsigned int nIn, unsigned char *entropy, unsigned char *asset,
+                                    unsigned char *token, bitcoinconsensus_error* err, unsigned int* issuance_err);
+
+int shim_bitcoinconsensus_verify_block_header(const unsigned char *header, unsigned int headerLen,
+                                    const unsigned char *signblockscript, unsigned int signblockscriptLen,
+                                    unsigned int maxBlockSignatureSize, bitcoinconsensus_error* err,
+                                    unsigned int* header_err, unsigned int* script_err);
+
+int shim_bitcoinconsensus_check_block(const unsigned char *block, unsigned int blockLen,
+                                    const unsigned char *spentOutputs, unsigned int spentOutputsLen,
+                                    bitcoinconsensus_error* err, unsigned int* block_err,
+                                    unsigned int* block_err_index, unsigned int* tx_check_err);
+
+int shim_bitcoinconsensus_dynafed_params_root(const unsigned char *params, unsigned int paramsLen,
+                                    unsigned char *root, unsigned char *extraRoot, bitcoinconsensus_error* err);
+
+int shim_bitcoinconsensus_verify_dynafed_params(const unsigned char *header, unsigned int headerLen,
+                                    const unsigned char *expectedCurrent, unsigned int expectedCurrentLen,
+                                    const unsigned char *fullCurrent, unsigned int fullCurrentLen,
+                                    int enforcePak, bitcoinconsensus_error* err, unsigned int* dynafed_err);
+
+int shim_bitcoinconsensus_check_standard(const unsigned char *hash_genesis_block,
+                                    const unsigned char *txTo, unsigned int txToLen,
+                                    const unsigned char *spentOutputs, unsigned int spentOutputsLen,
+                                    const unsigned char *policyAsset, const unsigned char *parentGenesisHash,
+                                    unsigned int dustRelayFee, unsigned int maxDatacarrierBytes, unsigned int policyFlags,
+                                    bitcoinconsensus_error* err, unsigned int* standard_err,
+                                    unsigned int* standard_err_index, unsigned int* script_err);
+
+int shim_bitcoinconsensus_transaction_weight(const unsigned char *txTo, unsigned int txToLen,
+                                    int64_t* weight, bitcoinconsensus_error* err);
+
+int shim_bitcoinconsensus_transaction_sigop_cost(const unsigned char *txTo, unsigned int txToLen,
+                                    const unsigned char *spentOutputs, unsigned int spentOutputsLen,
+                                    unsigned int flags, int64_t* sigopCost, bitcoinconsensus_error* err);
+
+/// Only for the tests of the shim: runs guard around a function that returns 1 for kind 0, throws a
+/// std::ios_base::failure for kind 1 and throws kind, which is not a std::exception, otherwise.
+int shim_test_guard(int kind, bitcoinconsensus_error* err);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif
+
+#endif // ELEMENTS_CONSENSUS_SYS_SHIM_H
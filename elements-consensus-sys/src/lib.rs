@@ -1,8 +1,80 @@
-#[allow(dead_code, non_camel_case_types, non_upper_case_globals)]
+#[allow(dead_code, non_camel_case_types, non_snake_case, non_upper_case_globals, unused_imports)]
 mod bindings {
     include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+    include!(concat!(env!("OUT_DIR"), "/script_error.rs"));
 }
 
+pub use crate::bindings::bitcoinconsensus_AMOUNTS_ERR_BALANCE;
+pub use crate::bindings::bitcoinconsensus_AMOUNTS_ERR_FEE;
+pub use crate::bindings::bitcoinconsensus_AMOUNTS_ERR_INPUT;
+pub use crate::bindings::bitcoinconsensus_AMOUNTS_ERR_ISSUANCE;
+pub use crate::bindings::bitcoinconsensus_AMOUNTS_ERR_OK;
+pub use crate::bindings::bitcoinconsensus_AMOUNTS_ERR_OUTPUT;
+pub use crate::bindings::bitcoinconsensus_AMOUNTS_ERR_RANGEPROOF;
+pub use crate::bindings::bitcoinconsensus_AMOUNTS_ERR_SURJECTIONPROOF;
+pub use crate::bindings::bitcoinconsensus_AMOUNTS_ERR_UNKNOWN;
+pub use crate::bindings::bitcoinconsensus_BLOCK_ERR_CB_AMOUNT;
+pub use crate::bindings::bitcoinconsensus_BLOCK_ERR_CB_MISSING;
+pub use crate::bindings::bitcoinconsensus_BLOCK_ERR_CB_MULTIPLE;
+pub use crate::bindings::bitcoinconsensus_BLOCK_ERR_CB_WITNESS;
+pub use crate::bindings::bitcoinconsensus_BLOCK_ERR_DUPLICATE;
+pub use crate::bindings::bitcoinconsensus_BLOCK_ERR_LENGTH;
+pub use crate::bindings::bitcoinconsensus_BLOCK_ERR_MERKLE_ROOT;
+pub use crate::bindings::bitcoinconsensus_BLOCK_ERR_OK;
+pub use crate::bindings::bitcoinconsensus_BLOCK_ERR_REWARD_OUT_OF_RANGE;
+pub use crate::bindings::bitcoinconsensus_BLOCK_ERR_SIGOPS;
+pub use crate::bindings::bitcoinconsensus_BLOCK_ERR_TX_CHECK;
+pub use crate::bindings::bitcoinconsensus_BLOCK_ERR_UNEXPECTED_WITNESS;
+pub use crate::bindings::bitcoinconsensus_BLOCK_ERR_WEIGHT;
+pub use crate::bindings::bitcoinconsensus_BLOCK_ERR_WITNESS_MERKLE_MATCH;
+pub use crate::bindings::bitcoinconsensus_BLOCK_ERR_WITNESS_NONCE_SIZE;
+pub use crate::bindings::bitcoinconsensus_DYNAFED_ERR_CURRENT_MISMATCH;
+pub use crate::bindings::bitcoinconsensus_DYNAFED_ERR_CURRENT_NULL;
+pub use crate::bindings::bitcoinconsensus_DYNAFED_ERR_EXTENSION_BITS;
+pub use crate::bindings::bitcoinconsensus_DYNAFED_ERR_EXTENSION_SPACE;
+pub use crate::bindings::bitcoinconsensus_DYNAFED_ERR_FEDPEGSCRIPT;
+pub use crate::bindings::bitcoinconsensus_DYNAFED_ERR_FEDPEGSCRIPT_OP_DEPTH;
+pub use crate::bindings::bitcoinconsensus_DYNAFED_ERR_FEDPEG_PROGRAM;
+pub use crate::bindings::bitcoinconsensus_DYNAFED_ERR_OK;
+pub use crate::bindings::bitcoinconsensus_DYNAFED_ERR_SIGNBLOCKSCRIPT;
+pub use crate::bindings::bitcoinconsensus_HEADER_ERR_CHALLENGE;
+pub use crate::bindings::bitcoinconsensus_HEADER_ERR_OK;
+pub use crate::bindings::bitcoinconsensus_HEADER_ERR_SIGNATURE;
+pub use crate::bindings::bitcoinconsensus_HEADER_ERR_SIGNATURE_SIZE;
+pub use crate::bindings::bitcoinconsensus_ISSUANCE_ERR_AMOUNT;
+pub use crate::bindings::bitcoinconsensus_ISSUANCE_ERR_AMOUNT_RANGEPROOF;
+pub use crate::bindings::bitcoinconsensus_ISSUANCE_ERR_INFLATION_KEYS;
+pub use crate::bindings::bitcoinconsensus_ISSUANCE_ERR_INFLATION_KEYS_RANGEPROOF;
+pub use crate::bindings::bitcoinconsensus_ISSUANCE_ERR_OK;
+pub use crate::bindings::bitcoinconsensus_ISSUANCE_ERR_REISSUANCE_INFLATION_KEYS;
+pub use crate::bindings::bitcoinconsensus_ISSUANCE_ERR_REISSUANCE_TOKEN;
+pub use crate::bindings::bitcoinconsensus_ISSUANCE_ERR_UNKNOWN;
+pub use crate::bindings::bitcoinconsensus_ISSUANCE_ERR_WITNESS;
+pub use crate::bindings::bitcoinconsensus_OUTPUT_PROOFS_INVALID_RANGEPROOF;
+pub use crate::bindings::bitcoinconsensus_OUTPUT_PROOFS_INVALID_SURJECTIONPROOF;
+pub use crate::bindings::bitcoinconsensus_OUTPUT_PROOFS_VALID;
+pub use crate::bindings::bitcoinconsensus_PEGIN_ERR_AMOUNT;
+pub use crate::bindings::bitcoinconsensus_PEGIN_ERR_ASSET;
+pub use crate::bindings::bitcoinconsensus_PEGIN_ERR_CLAIM_SCRIPT;
+pub use crate::bindings::bitcoinconsensus_PEGIN_ERR_CLAIM_SCRIPT_SIZE;
+pub use crate::bindings::bitcoinconsensus_PEGIN_ERR_DEPTH;
+pub use crate::bindings::bitcoinconsensus_PEGIN_ERR_GENESIS;
+pub use crate::bindings::bitcoinconsensus_PEGIN_ERR_MERKLE_BLOCK;
+pub use crate::bindings::bitcoinconsensus_PEGIN_ERR_OK;
+pub use crate::bindings::bitcoinconsensus_PEGIN_ERR_POW;
+pub use crate::bindings::bitcoinconsensus_PEGIN_ERR_TX;
+pub use crate::bindings::bitcoinconsensus_PEGIN_ERR_TXID;
+pub use crate::bindings::bitcoinconsensus_PEGIN_ERR_UNKNOWN;
+pub use crate::bindings::bitcoinconsensus_PEGIN_ERR_VALUE;
+pub use crate::bindings::bitcoinconsensus_PEGIN_ERR_WITNESS;
+pub use crate::bindings::bitcoinconsensus_POLICY_ANYONECANSPEND_AREMINE;
+pub use crate::bindings::bitcoinconsensus_POLICY_DATACARRIER;
+pub use crate::bindings::bitcoinconsensus_POLICY_DYNAFED_ACTIVE;
+pub use crate::bindings::bitcoinconsensus_POLICY_ENFORCE_PAK;
+pub use crate::bindings::bitcoinconsensus_POLICY_MULTI_DATA_PERMITTED;
+pub use crate::bindings::bitcoinconsensus_POLICY_NONE;
+pub use crate::bindings::bitcoinconsensus_POLICY_PERMIT_BARE_MULTISIG;
+pub use crate::bindings::bitcoinconsensus_POLICY_TAPROOT_ACTIVE;
 pub use crate::bindings::bitcoinconsensus_SCRIPT_FLAGS_VERIFY_ALL;
 pub use crate::bindings::bitcoinconsensus_SCRIPT_FLAGS_VERIFY_CHECKLOCKTIMEVERIFY;
 pub use crate::bindings::bitcoinconsensus_SCRIPT_FLAGS_VERIFY_CHECKSEQUENCEVERIFY;
@@ -10,12 +82,220 @@ pub use crate::bindings::bitcoinconsensus_SCRIPT_FLAGS_VERIFY_DERSIG;
 pub use crate::bindings::bitcoinconsensus_SCRIPT_FLAGS_VERIFY_NONE;
 pub use crate::bindings::bitcoinconsensus_SCRIPT_FLAGS_VERIFY_NULLDUMMY;
 pub use crate::bindings::bitcoinconsensus_SCRIPT_FLAGS_VERIFY_P2SH;
+pub use crate::bindings::bitcoinconsensus_SCRIPT_FLAGS_VERIFY_SIGHASH_RANGEPROOF;
+pub use crate::bindings::bitcoinconsensus_SCRIPT_FLAGS_VERIFY_TAPROOT;
 pub use crate::bindings::bitcoinconsensus_SCRIPT_FLAGS_VERIFY_WITNESS;
+pub use crate::bindings::bitcoinconsensus_SIGVERSION_BASE;
+pub use crate::bindings::bitcoinconsensus_SIGVERSION_WITNESS_V0;
+pub use crate::bindings::bitcoinconsensus_STANDARD_ERR_BARE_MULTISIG;
+pub use crate::bindings::bitcoinconsensus_STANDARD_ERR_DUST;
+pub use crate::bindings::bitcoinconsensus_STANDARD_ERR_INPUTS;
+pub use crate::bindings::bitcoinconsensus_STANDARD_ERR_MANDATORY_SCRIPT;
+pub use crate::bindings::bitcoinconsensus_STANDARD_ERR_MULTI_OP_RETURN;
+pub use crate::bindings::bitcoinconsensus_STANDARD_ERR_NON_MANDATORY_SCRIPT;
+pub use crate::bindings::bitcoinconsensus_STANDARD_ERR_OK;
+pub use crate::bindings::bitcoinconsensus_STANDARD_ERR_SCRIPTPUBKEY;
+pub use crate::bindings::bitcoinconsensus_STANDARD_ERR_SCRIPTSIG_NOT_PUSHONLY;
+pub use crate::bindings::bitcoinconsensus_STANDARD_ERR_SCRIPTSIG_SIZE;
+pub use crate::bindings::bitcoinconsensus_STANDARD_ERR_SIGOPS;
+pub use crate::bindings::bitcoinconsensus_STANDARD_ERR_TX_SIZE;
+pub use crate::bindings::bitcoinconsensus_STANDARD_ERR_TX_SIZE_SMALL;
+pub use crate::bindings::bitcoinconsensus_STANDARD_ERR_UNKNOWN;
+pub use crate::bindings::bitcoinconsensus_STANDARD_ERR_VERSION;
+pub use crate::bindings::bitcoinconsensus_STANDARD_ERR_WITNESS;
+pub use crate::bindings::bitcoinconsensus_TX_CHECK_ERR_CB_FEE;
+pub use crate::bindings::bitcoinconsensus_TX_CHECK_ERR_CB_LENGTH;
+pub use crate::bindings::bitcoinconsensus_TX_CHECK_ERR_INPUTS_DUPLICATE;
+pub use crate::bindings::bitcoinconsensus_TX_CHECK_ERR_OK;
+pub use crate::bindings::bitcoinconsensus_TX_CHECK_ERR_OVERSIZE;
+pub use crate::bindings::bitcoinconsensus_TX_CHECK_ERR_PREVOUT_NULL;
+pub use crate::bindings::bitcoinconsensus_TX_CHECK_ERR_TXOUTTOTAL_TOOLARGE;
+pub use crate::bindings::bitcoinconsensus_TX_CHECK_ERR_UNKNOWN;
+pub use crate::bindings::bitcoinconsensus_TX_CHECK_ERR_VIN_EMPTY;
+pub use crate::bindings::bitcoinconsensus_TX_CHECK_ERR_VOUT_AMOUNT_INVALID;
+pub use crate::bindings::bitcoinconsensus_TX_CHECK_ERR_VOUT_EMPTY;
+pub use crate::bindings::bitcoinconsensus_TX_CHECK_ERR_VOUT_NEGATIVE;
+pub use crate::bindings::bitcoinconsensus_TX_CHECK_ERR_VOUT_TOOLARGE;
+pub use crate::bindings::shim_bitcoinconsensus_check_block as bitcoinconsensus_check_block;
+pub use crate::bindings::shim_bitcoinconsensus_check_standard as bitcoinconsensus_check_standard;
+pub use crate::bindings::shim_bitcoinconsensus_check_transaction as bitcoinconsensus_check_transaction;
+pub use crate::bindings::shim_bitcoinconsensus_dynafed_params_root as bitcoinconsensus_dynafed_params_root;
+pub use crate::bindings::bitcoinconsensus_error;
 pub use crate::bindings::bitcoinconsensus_error_t_bitcoinconsensus_ERR_AMOUNT_REQUIRED;
+pub use crate::bindings::bitcoinconsensus_error_t_bitcoinconsensus_ERR_EXCEPTION;
 pub use crate::bindings::bitcoinconsensus_error_t_bitcoinconsensus_ERR_INVALID_FLAGS;
+pub use crate::bindings::bitcoinconsensus_error_t_bitcoinconsensus_ERR_INVALID_SIGHASH;
 pub use crate::bindings::bitcoinconsensus_error_t_bitcoinconsensus_ERR_OK;
+pub use crate::bindings::bitcoinconsensus_error_t_bitcoinconsensus_ERR_OUTPUT_PROOFS_MISMATCH;
+pub use crate::bindings::bitcoinconsensus_error_t_bitcoinconsensus_ERR_SPENT_OUTPUTS_MISMATCH;
+pub use crate::bindings::bitcoinconsensus_error_t_bitcoinconsensus_ERR_SPENT_OUTPUTS_REQUIRED;
+pub use crate::bindings::bitcoinconsensus_error_t_bitcoinconsensus_ERR_TX_COINBASE;
 pub use crate::bindings::bitcoinconsensus_error_t_bitcoinconsensus_ERR_TX_DESERIALIZE;
 pub use crate::bindings::bitcoinconsensus_error_t_bitcoinconsensus_ERR_TX_INDEX;
+pub use crate::bindings::bitcoinconsensus_error_t_bitcoinconsensus_ERR_TX_NOT_PEGIN;
+pub use crate::bindings::bitcoinconsensus_error_t_bitcoinconsensus_ERR_TX_NO_ISSUANCE;
 pub use crate::bindings::bitcoinconsensus_error_t_bitcoinconsensus_ERR_TX_SIZE_MISMATCH;
-pub use crate::bindings::bitcoinconsensus_verify_script;
-pub use crate::bindings::bitcoinconsensus_verify_script_with_amount;
+pub use crate::bindings::shim_bitcoinconsensus_signature_hash as bitcoinconsensus_signature_hash;
+pub use crate::bindings::shim_bitcoinconsensus_signature_hash_taproot as bitcoinconsensus_signature_hash_taproot;
+pub use crate::bindings::bitcoinconsensus_tracer;
+pub use crate::bindings::bitcoinconsensus_transaction;
+pub use crate::bindings::shim_bitcoinconsensus_transaction_create as bitcoinconsensus_transaction_create;
+pub use crate::bindings::shim_bitcoinconsensus_transaction_create_with_spent_outputs as bitcoinconsensus_transaction_create_with_spent_outputs;
+pub use crate::bindings::shim_bitcoinconsensus_transaction_destroy as bitcoinconsensus_transaction_destroy;
+pub use crate::bindings::shim_bitcoinconsensus_transaction_sigop_cost as bitcoinconsensus_transaction_sigop_cost;
+pub use crate::bindings::shim_bitcoinconsensus_transaction_trace_input as bitcoinconsensus_transaction_trace_input;
+pub use crate::bindings::shim_bitcoinconsensus_transaction_verify_input as bitcoinconsensus_transaction_verify_input;
+pub use crate::bindings::shim_bitcoinconsensus_transaction_weight as bitcoinconsensus_transaction_weight;
+pub use crate::bindings::shim_bitcoinconsensus_verify_amounts as bitcoinconsensus_verify_amounts;
+pub use crate::bindings::shim_bitcoinconsensus_verify_block_header as bitcoinconsensus_verify_block_header;
+pub use crate::bindings::shim_bitcoinconsensus_verify_dynafed_params as bitcoinconsensus_verify_dynafed_params;
+pub use crate::bindings::shim_bitcoinconsensus_verify_issuance as bitcoinconsensus_verify_issuance;
+pub use crate::bindings::shim_bitcoinconsensus_verify_output_proofs as bitcoinconsensus_verify_output_proofs;
+pub use crate::bindings::shim_bitcoinconsensus_verify_pegin as bitcoinconsensus_verify_pegin;
+pub use crate::bindings::shim_bitcoinconsensus_verify_script as bitcoinconsensus_verify_script;
+pub use crate::bindings::shim_bitcoinconsensus_verify_script_bitcoin as bitcoinconsensus_verify_script_bitcoin;
+pub use crate::bindings::shim_bitcoinconsensus_verify_script_with_amount as bitcoinconsensus_verify_script_with_amount;
+pub use crate::bindings::shim_bitcoinconsensus_verify_script_with_amount_and_script_error as bitcoinconsensus_verify_script_with_amount_and_script_error;
+pub use crate::bindings::shim_bitcoinconsensus_verify_script_with_spent_outputs as bitcoinconsensus_verify_script_with_spent_outputs;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_BAD_OPCODE;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_CHECKMULTISIGVERIFY;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_CHECKSIGVERIFY;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_CLEANSTACK;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_DISABLED_OPCODE;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_DISCOURAGE_OP_SUCCESS;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_DISCOURAGE_UPGRADABLE_NOPS;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_DISCOURAGE_UPGRADABLE_PUBKEYTYPE;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_DISCOURAGE_UPGRADABLE_TAPROOT_VERSION;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_DISCOURAGE_UPGRADABLE_WITNESS_PROGRAM;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_EQUALVERIFY;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_EVAL_FALSE;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_INVALID_ALTSTACK_OPERATION;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_INVALID_STACK_OPERATION;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_MINIMALDATA;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_MINIMALIF;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_NEGATIVE_LOCKTIME;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_NUMEQUALVERIFY;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_OK;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_OP_CODESEPARATOR;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_OP_COUNT;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_OP_RETURN;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_PEDERSEN_TALLY;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_PUBKEYTYPE;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_PUBKEY_COUNT;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_PUSH_SIZE;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_RANGEPROOF;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_SCHNORR_SIG;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_SCHNORR_SIG_HASHTYPE;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_SCHNORR_SIG_SIZE;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_SCRIPT_SIZE;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_SIG_COUNT;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_SIG_DER;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_SIG_FINDANDDELETE;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_SIG_HASHTYPE;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_SIG_HIGH_S;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_SIG_NULLDUMMY;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_SIG_NULLFAIL;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_SIG_PUSHONLY;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_STACK_SIZE;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_TAPROOT_WRONG_CONTROL_SIZE;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_TAPSCRIPT_CHECKMULTISIG;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_TAPSCRIPT_MINIMALIF;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_TAPSCRIPT_VALIDATION_WEIGHT;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_UNBALANCED_CONDITIONAL;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_UNKNOWN_ERROR;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_UNSATISFIED_LOCKTIME;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_VERIFY;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_WITNESS_MALLEATED;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_WITNESS_MALLEATED_P2SH;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_WITNESS_PROGRAM_MISMATCH;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_WITNESS_PROGRAM_WITNESS_EMPTY;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_WITNESS_PROGRAM_WRONG_LENGTH;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_WITNESS_PUBKEYTYPE;
+pub use crate::bindings::ScriptError_t_SCRIPT_ERR_WITNESS_UNEXPECTED;
+
+#[cfg(test)]
+mod tests {
+    extern crate link_cplusplus;
+    extern crate secp256k1_sys;
+    extern crate secp256k1_zkp_sys;
+
+    use super::*;
+
+    /// A transaction that announces one input and ends right after the announcement.
+    const TRUNCATED_TX: [u8; 6] = [0x02, 0x00, 0x00, 0x00, 0x00, 0x01];
+
+    #[test]
+    fn malformed_transaction_is_reported_by_raw_api() {
+        let genesis = [0u8; 32];
+        let script = [0x51u8];
+
+        let mut err = bitcoinconsensus_error_t_bitcoinconsensus_ERR_OK;
+        let ret = unsafe {
+            bitcoinconsensus_verify_script(
+                genesis.as_ptr(),
+                script.as_ptr(),
+                script.len() as u32,
+                TRUNCATED_TX.as_ptr(),
+                TRUNCATED_TX.len() as u32,
+                0,
+                0,
+                &mut err,
+            )
+        };
+        assert_eq!(ret, 0);
+        assert_eq!(err, bitcoinconsensus_error_t_bitcoinconsensus_ERR_TX_DESERIALIZE);
+
+        let mut err = bitcoinconsensus_error_t_bitcoinconsensus_ERR_OK;
+        let tx = unsafe {
+            bitcoinconsensus_transaction_create(
+                genesis.as_ptr(),
+                TRUNCATED_TX.as_ptr(),
+                TRUNCATED_TX.len() as u32,
+                &mut err,
+            )
+        };
+        assert!(tx.is_null());
+        assert_eq!(err, bitcoinconsensus_error_t_bitcoinconsensus_ERR_TX_DESERIALIZE);
+
+        let mut err = bitcoinconsensus_error_t_bitcoinconsensus_ERR_OK;
+        let mut amounts_err = 0;
+        let mut amounts_err_index = 0;
+        let ret = unsafe {
+            bitcoinconsensus_verify_amounts(
+                TRUNCATED_TX.as_ptr(),
+                TRUNCATED_TX.len() as u32,
+                std::ptr::null(),
+                0,
+                &mut err,
+                &mut amounts_err,
+                &mut amounts_err_index,
+            )
+        };
+        assert_eq!(ret, 0);
+        assert_eq!(err, bitcoinconsensus_error_t_bitcoinconsensus_ERR_TX_DESERIALIZE);
+    }
+
+    #[test]
+    fn shim_returns_result_without_exception() {
+        let mut err = bitcoinconsensus_error_t_bitcoinconsensus_ERR_OK;
+        let ret = unsafe { crate::bindings::shim_test_guard(0, &mut err) };
+        assert_eq!(ret, 1);
+        assert_eq!(err, bitcoinconsensus_error_t_bitcoinconsensus_ERR_OK);
+    }
+
+    #[test]
+    fn shim_reports_ios_failure_as_deserialize_error() {
+        let mut err = bitcoinconsensus_error_t_bitcoinconsensus_ERR_OK;
+        let ret = unsafe { crate::bindings::shim_test_guard(1, &mut err) };
+        assert_eq!(ret, 0);
+        assert_eq!(err, bitcoinconsensus_error_t_bitcoinconsensus_ERR_TX_DESERIALIZE);
+    }
+
+    #[test]
+    fn shim_reports_any_other_exception_as_exception_error() {
+        let mut err = bitcoinconsensus_error_t_bitcoinconsensus_ERR_OK;
+        let ret = unsafe { crate::bindings::shim_test_guard(2, &mut err) };
+        assert_eq!(ret, 0);
+        assert_eq!(err, bitcoinconsensus_error_t_bitcoinconsensus_ERR_EXCEPTION);
+    }
+}
@@ -2,16 +2,18 @@
 set -e
 
 
-if [ -z "$1" ] | [ -z "$2" ]; then
+if [ -z "$1" ] | [ -z "$2" ] | [ -z "$3" ]; then
   echo "\$1 parameter must be the elements-consensus-sys depend directory"
   echo "\$2 parameter must be the rust-secp256k1-sys version code (M_m_p format)"
-  echo "\$3 parameter (optional) can be the revision to check out"
+  echo "\$3 parameter must be the rust-secp256k1-zkp-sys version code (M_m_p format)"
+  echo "\$4 parameter (optional) can be the revision to check out"
   exit 1
 fi
 
 PARENT_DIR=$1
 VERSIONCODE=$2
-REV=$3
+ZKP_VERSIONCODE=$3
+REV=$4
 DIR=elements
 ORIGDIR=$(pwd)
 
@@ -44,6 +46,27 @@ git apply "../../patches/remove-share.diff"
 git apply "../../patches/remove-contrib.diff"
 git apply "../../patches/remove-univalue.diff"
 
+# extend the consensus library interface
+git apply "../../patches/reject-invalid-flags.diff"
+git apply "../../patches/expose-script-error.diff"
+git apply "../../patches/prepared-transaction.diff"
+git apply "../../patches/taproot-spent-outputs.diff"
+git apply "../../patches/signature-hash.diff"
+git apply "../../patches/script-trace.diff"
+git apply "../../patches/verify-amounts.diff"
+git apply "../../patches/verify-output-proofs.diff"
+git apply "../../patches/check-transaction.diff"
+git apply "../../patches/verify-pegin.diff"
+git apply "../../patches/verify-issuance.diff"
+git apply "../../patches/bitcoin-mode.diff"
+git apply "../../patches/exception-error.diff"
+git apply "../../patches/verify-block-header.diff"
+git apply "../../patches/verify-block.diff"
+git apply "../../patches/dynafed-params.diff"
+git apply "../../patches/standard-policy.diff"
+git apply "../../patches/transaction-weight.diff"
+git apply "../../patches/sighash-rangeproof.diff"
+
 rm -rf "src/qt"
 rm -rf "src/test"
 rm -rf "test"
@@ -78,6 +101,22 @@ find "$DIR" -not -path '*/\.*' -type f -print0 | xargs -0 sed -i "/^#include/! s
 # special rule for a method that is not prefixed in libsecp
 find "$DIR" -not -path '*/\.*' -type f -print0 | xargs -0 sed -i "/^#include/! s/ecdsa_signature_parse_der_lax/rustsecp256k1zkp_v${VERSIONCODE}_ecdsa_signature_parse_der_lax/g"
 
+# The modules for confidential transactions are only part of libsecp256k1-zkp, so we link them against rust-secp256k1-zkp
+find "$DIR" -not -path '*/\.*' -type f -print0 | xargs -0 sed -i "/^#include/! s/rustsecp256k1_v${VERSIONCODE}_\(generator\|pedersen_\|rangeproof_\|surjectionproof\)/rustsecp256k1zkp_v${ZKP_VERSIONCODE}_\1/g"
+
+# Their functions take contexts of libsecp256k1-zkp, which are declared by a copy of the base headers with its prefix
+INCLUDE_DIR="$DIR/src/secp256k1/include"
+sed "s/rustsecp256k1_v${VERSIONCODE}_/rustsecp256k1zkp_v${ZKP_VERSIONCODE}_/g; s/SECP256K1_H/SECP256K1_ZKP_H/" "$INCLUDE_DIR/secp256k1.h" > "$INCLUDE_DIR/secp256k1_zkp.h"
+sed "s/rustsecp256k1_v${VERSIONCODE}_/rustsecp256k1zkp_v${ZKP_VERSIONCODE}_/g; s/SECP256K1_PREALLOCATED_H/SECP256K1_ZKP_PREALLOCATED_H/; s/#include \"secp256k1.h\"/#include \"secp256k1_zkp.h\"/" "$INCLUDE_DIR/secp256k1_preallocated.h" > "$INCLUDE_DIR/secp256k1_zkp_preallocated.h"
+for header in secp256k1_generator.h secp256k1_rangeproof.h secp256k1_surjectionproof.h; do
+    sed -i "s/rustsecp256k1_v${VERSIONCODE}_/rustsecp256k1zkp_v${ZKP_VERSIONCODE}_/g; s/#include \"secp256k1.h\"/#include \"secp256k1_zkp.h\"/" "$INCLUDE_DIR/$header"
+done
+
+# The validation of confidential transactions and peg-ins creates its contexts with libsecp256k1-zkp
+for file in confidential_validation.h confidential_validation.cpp consensus/pegin_check.cpp script/sigcache.h script/sigcache.cpp; do
+    sed -i "/^#include/! s/rustsecp256k1_v${VERSIONCODE}_/rustsecp256k1zkp_v${ZKP_VERSIONCODE}_/g" "$DIR/src/$file"
+done
+
 # Undo makefile changes
 (cd "$DIR"; git restore "src/secp256k1/Makefile.am")
 
@@ -0,0 +1,276 @@
+use crate::{ffi, Error};
+use elements::{encode::serialize, Transaction, TxOut};
+use std::{error::Error as StdError, fmt};
+
+/// The value commitments of a transaction do not balance, or the proofs of its outputs are invalid.
+///
+/// Each variant corresponds to one of the checks done by `VerifyAmounts` in Elements, in the order they are done.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AmountsViolation {
+    /// An explicit fee output is zero or out of range, or the fees overflow.
+    InvalidFee,
+    /// The asset or value of the output spent by input `input` is invalid.
+    InvalidSpentOutput { input: usize },
+    /// The issuance of input `input` is invalid, including its rangeproofs.
+    InvalidIssuance { input: usize },
+    /// The asset, value or nonce of output `output` is invalid, or it is spendable with a value of zero.
+    InvalidOutput { output: usize },
+    /// The inputs and issuances do not sum up to the outputs and fees.
+    Unbalanced,
+    /// The rangeproof of output `output` is missing or invalid.
+    InvalidRangeproof { output: usize },
+    /// The surjection proof of output `output` is missing or invalid.
+    InvalidSurjectionProof { output: usize },
+    /// The consensus library reported a violation that this crate does not know, or one that it
+    /// does not know itself.
+    Unrecognized(u32),
+}
+
+impl fmt::Display for AmountsViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AmountsViolation::InvalidFee => write!(f, "fee is out of range"),
+            AmountsViolation::InvalidSpentOutput { input } => {
+                write!(f, "output spent by input {} is invalid", input)
+            }
+            AmountsViolation::InvalidIssuance { input } => {
+                write!(f, "issuance of input {} is invalid", input)
+            }
+            AmountsViolation::InvalidOutput { output } => write!(f, "output {} is invalid", output),
+            AmountsViolation::Unbalanced => write!(f, "inputs and outputs do not balance"),
+            AmountsViolation::InvalidRangeproof { output } => {
+                write!(f, "rangeproof of output {} is invalid", output)
+            }
+            AmountsViolation::InvalidSurjectionProof { output } => {
+                write!(f, "surjection proof of output {} is invalid", output)
+            }
+            AmountsViolation::Unrecognized(code) => {
+                write!(f, "unrecognized amounts violation {}", code)
+            }
+        }
+    }
+}
+
+impl StdError for AmountsViolation {}
+
+/// Verifies that the amounts of `transaction` balance, as done by Elements before accepting it.
+///
+/// `spent_outputs` are the outputs spent by all inputs of `transaction`, in the order of the inputs.
+/// The value commitments of the spent outputs and issuances have to sum up to the ones of the outputs and the explicit fees.
+/// The rangeproofs and surjection proofs of all outputs are verified as well.
+///
+/// Like [`verify`](crate::verify), this function returns two layers of errors.
+/// The outer one reports a mismatch between `spent_outputs` and the inputs of `transaction`, and coinbase transactions, whose amounts depend on the fees of their block.
+pub fn verify_amounts(
+    transaction: &Transaction,
+    spent_outputs: &[TxOut],
+) -> Result<Result<(), AmountsViolation>, Error> {
+    if transaction.is_coinbase() {
+        return Err(Error::Coinbase);
+    }
+    if transaction.input.len() != spent_outputs.len() {
+        return Err(Error::SpentOutputsMismatch);
+    }
+
+    let transaction = serialize(transaction);
+    let spent_outputs = serialize(&spent_outputs.to_vec());
+
+    let mut amounts_err = 0;
+    let mut index = 0;
+
+    let verified = ffi::call(|err| unsafe {
+        elements_consensus_sys::bitcoinconsensus_verify_amounts(
+            transaction.as_ptr(),
+            transaction.len() as u32,
+            spent_outputs.as_ptr(),
+            spent_outputs.len() as u32,
+            err,
+            &mut amounts_err,
+            &mut index,
+        )
+    })?;
+
+    if verified {
+        Ok(Ok(()))
+    } else {
+        Ok(Err(AmountsViolation::from_raw(amounts_err, index as usize)))
+    }
+}
+
+impl AmountsViolation {
+    fn from_raw(code: u32, index: usize) -> Self {
+        match code {
+            elements_consensus_sys::bitcoinconsensus_AMOUNTS_ERR_FEE => {
+                AmountsViolation::InvalidFee
+            }
+            elements_consensus_sys::bitcoinconsensus_AMOUNTS_ERR_INPUT => {
+                AmountsViolation::InvalidSpentOutput { input: index }
+            }
+            elements_consensus_sys::bitcoinconsensus_AMOUNTS_ERR_ISSUANCE => {
+                AmountsViolation::InvalidIssuance { input: index }
+            }
+            elements_consensus_sys::bitcoinconsensus_AMOUNTS_ERR_OUTPUT => {
+                AmountsViolation::InvalidOutput { output: index }
+            }
+            elements_consensus_sys::bitcoinconsensus_AMOUNTS_ERR_BALANCE => {
+                AmountsViolation::Unbalanced
+            }
+            elements_consensus_sys::bitcoinconsensus_AMOUNTS_ERR_RANGEPROOF => {
+                AmountsViolation::InvalidRangeproof { output: index }
+            }
+            elements_consensus_sys::bitcoinconsensus_AMOUNTS_ERR_SURJECTIONPROOF => {
+                AmountsViolation::InvalidSurjectionProof { output: index }
+            }
+            code => AmountsViolation::Unrecognized(code),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use elements::issuance::AssetId;
+    use elements::secp256k1_zkp::{
+        Generator, PedersenCommitment, RangeProof, Secp256k1, SecretKey, Tweak,
+    };
+    use elements::{confidential, AssetIssuance, OutPoint, Script, TxIn, TxOutWitness};
+
+    fn asset() -> AssetId {
+        AssetId::from_slice(&[1; 32]).unwrap()
+    }
+
+    fn explicit_output(value: u64) -> TxOut {
+        TxOut {
+            asset: confidential::Asset::Explicit(asset()),
+            value: confidential::Value::Explicit(value),
+            script_pubkey: Script::from(vec![0x51]),
+            ..Default::default()
+        }
+    }
+
+    fn rangeproof(value: u64) -> RangeProof {
+        let secp = Secp256k1::new();
+        let generator = Generator::new_unblinded(&secp, asset().into_inner().0.into());
+        let blinding_factor = Tweak::from_inner([2; 32]).unwrap();
+        let commitment = PedersenCommitment::new(&secp, value, blinding_factor, generator);
+
+        RangeProof::new(
+            &secp,
+            0,
+            commitment,
+            value,
+            blinding_factor,
+            &[],
+            &[],
+            SecretKey::from_slice(&[3; 32]).unwrap(),
+            0,
+            52,
+            generator,
+        )
+        .unwrap()
+    }
+
+    fn spend(outputs: Vec<TxOut>) -> Transaction {
+        Transaction {
+            version: 2,
+            lock_time: 0,
+            input: vec![TxIn {
+                previous_output: OutPoint {
+                    vout: 0,
+                    ..Default::default()
+                },
+                is_pegin: false,
+                has_issuance: false,
+                script_sig: Script::new(),
+                sequence: 0xffff_ffff,
+                asset_issuance: AssetIssuance::default(),
+                witness: Default::default(),
+            }],
+            output: outputs,
+        }
+    }
+
+    #[test]
+    fn explicit_amounts_balance() {
+        let transaction = spend(vec![
+            explicit_output(99_000),
+            TxOut::new_fee(1_000, asset()),
+        ]);
+
+        verify_amounts(&transaction, &[explicit_output(100_000)])
+            .unwrap()
+            .unwrap();
+    }
+
+    #[test]
+    fn reports_failed_check() {
+        let spent_outputs = [explicit_output(100_000)];
+
+        let unbalanced = spend(vec![
+            explicit_output(100_000),
+            TxOut::new_fee(1_000, asset()),
+        ]);
+        let zero_value = spend(vec![
+            explicit_output(99_000),
+            TxOut::new_fee(1_000, asset()),
+            explicit_output(0),
+        ]);
+        let zero_fee = spend(vec![explicit_output(100_000), TxOut::new_fee(0, asset())]);
+        let mut rangeproof_on_explicit_value = spend(vec![
+            explicit_output(99_000),
+            TxOut::new_fee(1_000, asset()),
+        ]);
+        rangeproof_on_explicit_value.output[1].witness = TxOutWitness {
+            rangeproof: Some(rangeproof(1_000)),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            verify_amounts(&unbalanced, &spent_outputs).unwrap(),
+            Err(AmountsViolation::Unbalanced)
+        );
+        assert_eq!(
+            verify_amounts(&zero_value, &spent_outputs).unwrap(),
+            Err(AmountsViolation::InvalidOutput { output: 2 })
+        );
+        assert_eq!(
+            verify_amounts(&zero_fee, &spent_outputs).unwrap(),
+            Err(AmountsViolation::InvalidFee)
+        );
+        assert_eq!(
+            verify_amounts(&rangeproof_on_explicit_value, &spent_outputs).unwrap(),
+            Err(AmountsViolation::InvalidRangeproof { output: 1 })
+        );
+        assert_eq!(
+            verify_amounts(&unbalanced, &[TxOut::default()]).unwrap(),
+            Err(AmountsViolation::InvalidSpentOutput { input: 0 })
+        );
+    }
+
+    #[test]
+    fn coinbase_amounts_cannot_be_verified() {
+        let mut coinbase = spend(vec![explicit_output(100_000)]);
+        coinbase.input[0].previous_output = OutPoint::default();
+
+        assert_eq!(verify_amounts(&coinbase, &[]).unwrap_err(), Error::Coinbase);
+    }
+
+    #[test]
+    fn spent_outputs_must_match_inputs() {
+        let transaction = spend(vec![explicit_output(100_000)]);
+
+        assert_eq!(
+            verify_amounts(&transaction, &[]).unwrap_err(),
+            Error::SpentOutputsMismatch
+        );
+    }
+
+    #[test]
+    fn unknown_reason_is_unrecognized() {
+        let code = elements_consensus_sys::bitcoinconsensus_AMOUNTS_ERR_UNKNOWN;
+        assert_eq!(
+            AmountsViolation::from_raw(code, 0),
+            AmountsViolation::Unrecognized(code)
+        );
+    }
+}
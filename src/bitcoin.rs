@@ -0,0 +1,375 @@
+//! Verification of Bitcoin transactions, e.g. the Bitcoin side of a cross-chain swap.
+//!
+//! The consensus library verifies these with the Elements interpreter switched to Bitcoin mode:
+//! transactions are serialized and their signatures are hashed like in Bitcoin, and the opcodes that Elements adds or re-enables are rejected.
+//! The mode is only switched for the calling thread, so Elements transactions can be verified on other threads at the same time.
+//!
+//! Taproot is implemented for Elements only and cannot be verified here.
+
+use crate::{ffi, ConsensusViolation, Error, ScriptError, VerificationFlags};
+use elements::bitcoin::{consensus::serialize, Script, Transaction};
+use std::convert::TryFrom;
+
+/// Verifies that the `amount` locked by `script` is unlocked by input `index` in the Bitcoin `transaction` according to the consensus rules.
+///
+/// `amount` is the value of the spent output in satoshi, which must fit into an `i64`.
+/// All rules of [`VerificationFlags::ALL`] except [`VerificationFlags::TAPROOT`] are enforced.
+///
+/// Returns the same two layers of errors as [`verify`](crate::verify).
+pub fn verify(
+    script: Script,
+    amount: u64,
+    index: usize,
+    transaction: &Transaction,
+) -> Result<Result<(), ConsensusViolation>, Error> {
+    verify_with_flags(
+        script,
+        amount,
+        index,
+        transaction,
+        VerificationFlags::ALL - VerificationFlags::TAPROOT,
+    )
+}
+
+/// Verifies that the `amount` locked by `script` is unlocked by input `index` in the Bitcoin `transaction`, enforcing only the rules in `flags`.
+///
+/// Like [`verify_with_flags`](crate::verify_with_flags), the outer layer of errors reports unsupported combinations of `flags`.
+/// This includes every combination with [`VerificationFlags::TAPROOT`] or [`VerificationFlags::SIGHASH_RANGEPROOF`].
+pub fn verify_with_flags(
+    script: Script,
+    amount: u64,
+    index: usize,
+    transaction: &Transaction,
+    flags: VerificationFlags,
+) -> Result<Result<(), ConsensusViolation>, Error> {
+    if transaction.input.len() <= index {
+        return Err(Error::IndexOutOfBounds);
+    }
+    let amount = i64::try_from(amount).map_err(|_| Error::AmountOutOfRange)?;
+
+    let script = script.as_bytes();
+    let transaction = serialize(transaction);
+
+    let mut script_err = 0;
+
+    let verified = ffi::call(|err| unsafe {
+        elements_consensus_sys::bitcoinconsensus_verify_script_bitcoin(
+            script.as_ptr(),
+            script.len() as u32,
+            amount,
+            transaction.as_ptr(),
+            transaction.len() as u32,
+            index as u32,
+            flags.bits(),
+            err,
+            &mut script_err,
+        )
+    })?;
+
+    if verified {
+        Ok(Ok(()))
+    } else {
+        Ok(Err(ConsensusViolation {
+            script_error: ScriptError::from_raw(script_err),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TX_HEX;
+    use elements::bitcoin::blockdata::opcodes;
+    use elements::bitcoin::consensus::deserialize;
+    use elements::bitcoin::hashes::hex::FromHex;
+    use elements::bitcoin::hashes::Hash;
+    use elements::bitcoin::secp256k1::{Message, PublicKey, Secp256k1, SecretKey};
+    use elements::bitcoin::util::bip143::SigHashCache;
+    use elements::bitcoin::{OutPoint, PubkeyHash, SigHashType, TxIn, TxOut, Txid, WPubkeyHash};
+    use elements::confidential;
+    use elements::opcodes::all::{OP_CAT, OP_ENDIF, OP_IF, OP_PUSHBYTES_0, OP_PUSHNUM_1};
+    use elements::script::Builder;
+    use hex_literal::hex;
+    use serde_json::Value;
+    use std::thread;
+
+    /// The first transaction between two people, Satoshi Nakamoto paying Hal Finney in block 170, which spends a P2PK output of block 9.
+    const P2PK_SPEND: &[u8] = &hex!("0100000001c997a5e56e104102fa209c6a852dd90660a20b2d9c352423edce25857fcd3704000000004847304402204e45e16932b8af514961a1d3a1a25fdf3f4f7732e9d624c6c61548ab5fb8cd410220181522ec8eca07de4860a4acdd12909d831cc56cbbac4622082221a8768d1d0901ffffffff0200ca9a3b00000000434104ae1a62fe09c5f51b13905f07f06b99a2f7159b2225f374cd378d71302fa28414e7aab37397f554a7df5f142c21c1b7303b8a0626f1baded5c72a704f7e6cd84cac00286bee0000000043410411db93e1dcdb8a016b49840f8c53bc1eb68a382e97b1482ecad7b148a6909a5cb2e0eaddfb84ccf9744464f82e160bfa9b8b64f9d4c03f999b8643f656b412a3ac00000000");
+    const P2PK_SCRIPT: &str = "410411db93e1dcdb8a016b49840f8c53bc1eb68a382e97b1482ecad7b148a6909a5cb2e0eaddfb84ccf9744464f82e160bfa9b8b64f9d4c03f999b8643f656b412a3ac";
+
+    const P2WPKH_AMOUNT: u64 = 1_900_000;
+
+    /// Signs a transaction that spends a P2WPKH output of [`P2WPKH_AMOUNT`], returning it with the spent script.
+    fn p2wpkh_spend() -> (Transaction, Script) {
+        let secp = Secp256k1::new();
+        let secret_key = SecretKey::from_slice(&[0x2a; 32]).unwrap();
+        let public_key = PublicKey::from_secret_key(&secp, &secret_key);
+        let script = Script::new_v0_wpkh(&WPubkeyHash::hash(&public_key.serialize()));
+
+        let mut transaction = Transaction {
+            version: 2,
+            lock_time: 0,
+            input: vec![TxIn {
+                previous_output: OutPoint::new(Txid::hash(b"funding"), 0),
+                script_sig: Script::new(),
+                sequence: 0xffff_ffff,
+                witness: vec![],
+            }],
+            output: vec![TxOut {
+                value: P2WPKH_AMOUNT - 1_000,
+                script_pubkey: script.clone(),
+            }],
+        };
+        let script_code = Script::new_p2pkh(&PubkeyHash::hash(&public_key.serialize()));
+        let sighash = SigHashCache::new(&transaction).signature_hash(
+            0,
+            &script_code,
+            P2WPKH_AMOUNT,
+            SigHashType::All,
+        );
+        let signature = secp.sign(&Message::from_slice(&sighash[..]).unwrap(), &secret_key);
+        let mut signature = signature.serialize_der().to_vec();
+        signature.push(SigHashType::All.as_u32() as u8);
+        transaction.input[0].witness = vec![signature, public_key.serialize().to_vec()];
+
+        (transaction, script)
+    }
+
+    /// Parses a script in the notation of Bitcoin Core's test vectors.
+    ///
+    /// Numbers are pushed as script numbers, `0x` words are inserted as raw bytes, and opcodes may be written without their `OP_` prefix.
+    fn parse_script(asm: &str) -> Script {
+        let mut script = vec![];
+        for word in asm.split_whitespace() {
+            if let Ok(number) = word.parse::<i64>() {
+                script.extend(Builder::new().push_int(number).into_script().into_bytes());
+            } else if word.starts_with("0x") {
+                script.extend(Vec::<u8>::from_hex(word.trim_start_matches("0x")).unwrap());
+            } else {
+                let name = match word.trim_start_matches("OP_") {
+                    "CHECKLOCKTIMEVERIFY" | "NOP2" => "CLTV",
+                    "CHECKSEQUENCEVERIFY" | "NOP3" => "CSV",
+                    name => name,
+                };
+                let opcode = (0..=255u8)
+                    .map(opcodes::All::from)
+                    .find(|opcode| format!("{:?}", opcode) == format!("OP_{}", name))
+                    .unwrap_or_else(|| panic!("unknown opcode {}", word));
+                script.push(opcode.into_u8());
+            }
+        }
+
+        Script::from(script)
+    }
+
+    fn parse_flags(flags: &str) -> VerificationFlags {
+        flags
+            .split(',')
+            .map(|flag| match flag {
+                "NONE" => VerificationFlags::NONE,
+                "P2SH" => VerificationFlags::P2SH,
+                "DERSIG" => VerificationFlags::DERSIG,
+                "NULLDUMMY" => VerificationFlags::NULLDUMMY,
+                "CHECKLOCKTIMEVERIFY" => VerificationFlags::CHECKLOCKTIMEVERIFY,
+                "CHECKSEQUENCEVERIFY" => VerificationFlags::CHECKSEQUENCEVERIFY,
+                "WITNESS" => VerificationFlags::WITNESS,
+                flag => panic!("unsupported flag {}", flag),
+            })
+            .fold(VerificationFlags::NONE, |flags, flag| flags | flag)
+    }
+
+    /// Verifies every input of the transactions in Bitcoin Core's `tx_valid.json` or `tx_invalid.json` format with the flags of its vector.
+    ///
+    /// Returns the description preceding each vector together with whether all of its inputs verified.
+    fn verify_vectors(json: &str) -> Vec<(String, bool)> {
+        let entries: Vec<Value> = serde_json::from_str(json).unwrap();
+
+        let mut description = String::new();
+        let mut results = vec![];
+        for entry in entries {
+            let entry = entry.as_array().unwrap();
+            if entry.len() == 1 {
+                description = entry[0].as_str().unwrap().to_owned();
+                continue;
+            }
+
+            let prevouts = entry[0].as_array().unwrap();
+            let transaction: Transaction =
+                deserialize(&Vec::<u8>::from_hex(entry[1].as_str().unwrap()).unwrap()).unwrap();
+            let flags = parse_flags(entry[2].as_str().unwrap());
+
+            let verified = transaction.input.iter().enumerate().all(|(index, input)| {
+                let prevout = prevouts
+                    .iter()
+                    .map(|prevout| prevout.as_array().unwrap())
+                    .find(|prevout| {
+                        Txid::from_hex(prevout[0].as_str().unwrap()).unwrap()
+                            == input.previous_output.txid
+                            && prevout[1].as_u64().unwrap() == u64::from(input.previous_output.vout)
+                    })
+                    .unwrap();
+                let script = parse_script(prevout[2].as_str().unwrap());
+                let amount = prevout.get(3).map_or(0, |amount| amount.as_u64().unwrap());
+
+                verify_with_flags(script, amount, index, &transaction, flags)
+                    .unwrap()
+                    .is_ok()
+            });
+
+            results.push((description.clone(), verified));
+        }
+
+        results
+    }
+
+    #[test]
+    fn p2pk_spend_verifies() {
+        let transaction = deserialize(P2PK_SPEND).unwrap();
+        let script = Script::from_hex(P2PK_SCRIPT).unwrap();
+
+        let result = verify(script, 5_000_000_000, 0, &transaction);
+
+        result.unwrap().unwrap();
+    }
+
+    #[test]
+    fn p2wpkh_spend_verifies() {
+        let (transaction, script) = p2wpkh_spend();
+
+        let result = verify(script, P2WPKH_AMOUNT, 0, &transaction);
+
+        result.unwrap().unwrap();
+    }
+
+    #[test]
+    fn segwit_signature_commits_to_amount() {
+        let (transaction, script) = p2wpkh_spend();
+
+        let result = verify(script, P2WPKH_AMOUNT + 1, 0, &transaction);
+
+        let violation = result.unwrap().unwrap_err();
+        assert_eq!(violation.script_error(), ScriptError::EvalFalse);
+    }
+
+    #[test]
+    fn opcodes_re_enabled_by_elements_are_disabled() {
+        let transaction = deserialize(P2PK_SPEND).unwrap();
+        // OP_CAT is disabled in Bitcoin even if it is not executed.
+        let script = Builder::new()
+            .push_opcode(OP_PUSHBYTES_0)
+            .push_opcode(OP_IF)
+            .push_opcode(OP_CAT)
+            .push_opcode(OP_ENDIF)
+            .push_opcode(OP_PUSHNUM_1)
+            .into_script();
+        let script = Script::from(script.into_bytes());
+
+        let result = verify(script, 5_000_000_000, 0, &transaction);
+
+        let violation = result.unwrap().unwrap_err();
+        assert_eq!(violation.script_error(), ScriptError::DisabledOpcode);
+    }
+
+    #[test]
+    fn bitcoin_core_valid_transactions_verify() {
+        let results = verify_vectors(include_str!("../test_data/bitcoin/tx_valid.json"));
+
+        assert!(!results.is_empty());
+        for (description, verified) in results {
+            assert!(verified, "{}", description);
+        }
+    }
+
+    #[test]
+    fn bitcoin_core_invalid_transactions_do_not_verify() {
+        let results = verify_vectors(include_str!("../test_data/bitcoin/tx_invalid.json"));
+
+        assert!(!results.is_empty());
+        for (description, verified) in results {
+            assert!(!verified, "{}", description);
+        }
+    }
+
+    #[test]
+    fn amount_beyond_signed_range_is_rejected() {
+        let (transaction, script) = p2wpkh_spend();
+
+        let result = verify(script, 1 << 63, 0, &transaction);
+
+        assert_eq!(result.unwrap_err(), Error::AmountOutOfRange);
+    }
+
+    #[test]
+    fn taproot_is_invalid_flags() {
+        let transaction = deserialize(P2PK_SPEND).unwrap();
+        let script = Script::from_hex(P2PK_SCRIPT).unwrap();
+
+        let result = verify_with_flags(
+            script,
+            5_000_000_000,
+            0,
+            &transaction,
+            VerificationFlags::ALL,
+        );
+
+        assert_eq!(result.unwrap_err(), Error::InvalidFlags);
+    }
+
+    #[test]
+    fn sighash_rangeproof_is_invalid_flags() {
+        let transaction = deserialize(P2PK_SPEND).unwrap();
+        let script = Script::from_hex(P2PK_SCRIPT).unwrap();
+
+        let result = verify_with_flags(
+            script,
+            5_000_000_000,
+            0,
+            &transaction,
+            VerificationFlags::P2SH | VerificationFlags::SIGHASH_RANGEPROOF,
+        );
+
+        assert_eq!(result.unwrap_err(), Error::InvalidFlags);
+    }
+
+    #[test]
+    fn bitcoin_mode_does_not_leak_into_concurrent_elements_verification() {
+        let elements_address = "H1YukBju4An78pumXgTcu31DyKwmYLqgi1"
+            .parse::<elements::Address>()
+            .unwrap();
+        let elements_transaction: elements::Transaction =
+            elements::encode::deserialize(TX_HEX).unwrap();
+        let coins = confidential::Value::from_commitment(&hex!(
+            "08b0faffc84b19acd0001e5c615d058d7bec15cce82253ac416300f52bbb4a2bf9"
+        ))
+        .unwrap();
+        let (bitcoin_transaction, bitcoin_script) = p2wpkh_spend();
+
+        let elements_thread = thread::spawn(move || {
+            for _ in 0..200 {
+                crate::verify(
+                    elements_address.script_pubkey(),
+                    &coins,
+                    0,
+                    &elements_transaction,
+                )
+                .unwrap()
+                .unwrap();
+            }
+        });
+        let bitcoin_thread = thread::spawn(move || {
+            for _ in 0..200 {
+                verify(
+                    bitcoin_script.clone(),
+                    P2WPKH_AMOUNT,
+                    0,
+                    &bitcoin_transaction,
+                )
+                .unwrap()
+                .unwrap();
+            }
+        });
+
+        elements_thread.join().unwrap();
+        bitcoin_thread.join().unwrap();
+    }
+}
@@ -0,0 +1,939 @@
+use crate::transaction::pegin_spent_output;
+use crate::{
+    ffi, verify_amounts, verify_transaction, AmountsViolation, ChainParams,
+    CheckTransactionViolation, Error, ScriptError, TransactionViolation,
+};
+use elements::{encode::serialize, Block, BlockHeader, OutPoint, Transaction, TxOut};
+use rayon::prelude::*;
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    error::Error as StdError,
+    fmt,
+};
+
+/// The reason why a block header is not signed by the federation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlockHeaderViolation {
+    /// The header has no dynamic federation parameters and its challenge is not the `signblockscript` of the chain.
+    Challenge,
+    /// The block signature is larger than the limit of the chain, or of the current dynamic federation parameters.
+    SignatureSize,
+    /// The block signature does not satisfy the `signblockscript`.
+    Signature(ScriptError),
+    /// The consensus library reported a violation that this crate does not know.
+    Unrecognized(u32),
+}
+
+impl BlockHeaderViolation {
+    fn from_raw(code: u32, script_err: u32) -> Self {
+        match code {
+            elements_consensus_sys::bitcoinconsensus_HEADER_ERR_CHALLENGE => {
+                BlockHeaderViolation::Challenge
+            }
+            elements_consensus_sys::bitcoinconsensus_HEADER_ERR_SIGNATURE_SIZE => {
+                BlockHeaderViolation::SignatureSize
+            }
+            elements_consensus_sys::bitcoinconsensus_HEADER_ERR_SIGNATURE => {
+                BlockHeaderViolation::Signature(ScriptError::from_raw(script_err))
+            }
+            code => BlockHeaderViolation::Unrecognized(code),
+        }
+    }
+}
+
+impl fmt::Display for BlockHeaderViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BlockHeaderViolation::Challenge => {
+                write!(f, "challenge is not the signblockscript of the chain")
+            }
+            BlockHeaderViolation::SignatureSize => write!(f, "block signature is too large"),
+            BlockHeaderViolation::Signature(script_error) => {
+                write!(f, "block signature is invalid: {}", script_error)
+            }
+            BlockHeaderViolation::Unrecognized(code) => {
+                write!(f, "unrecognized block header violation {}", code)
+            }
+        }
+    }
+}
+
+impl StdError for BlockHeaderViolation {}
+
+/// Verifies that `header` is signed by the federation of the chain described by `params`, like `CheckProof` in Elements.
+///
+/// Headers without dynamic federation parameters must commit to [`ChainParams::signblockscript`] as their challenge, and their solution must satisfy it within [`ChainParams::max_block_signature_size`].
+/// Once a chain has moved to dynamic federations, the `signblockscript` and witness limit are taken from the current parameters in the header instead.
+/// Whether those are the parameters of the current epoch depends on the chain's history and is not checked here.
+///
+/// Like [`verify`](crate::verify), this function returns two layers of errors.
+pub fn verify_block_header(
+    header: &BlockHeader,
+    params: &ChainParams,
+) -> Result<Result<(), BlockHeaderViolation>, Error> {
+    let header = serialize(header);
+
+    let mut header_err = 0;
+    let mut script_err = 0;
+
+    let verified = ffi::call(|err| unsafe {
+        elements_consensus_sys::bitcoinconsensus_verify_block_header(
+            header.as_ptr(),
+            header.len() as u32,
+            params.signblockscript.as_ptr(),
+            params.signblockscript.len() as u32,
+            params.max_block_signature_size,
+            err,
+            &mut header_err,
+            &mut script_err,
+        )
+    })?;
+
+    if verified {
+        Ok(Ok(()))
+    } else {
+        Ok(Err(BlockHeaderViolation::from_raw(header_err, script_err)))
+    }
+}
+
+/// Provides the unspent outputs that the transactions of a block can spend, like the UTXO set of a node at the previous block.
+pub trait UtxoView {
+    /// Returns the unspent output at `outpoint`, or `None` if it does not exist or is already spent.
+    fn unspent_output(&self, outpoint: &OutPoint) -> Option<TxOut>;
+}
+
+impl<S: std::hash::BuildHasher> UtxoView for HashMap<OutPoint, TxOut, S> {
+    fn unspent_output(&self, outpoint: &OutPoint) -> Option<TxOut> {
+        self.get(outpoint).cloned()
+    }
+}
+
+impl UtxoView for BTreeMap<OutPoint, TxOut> {
+    fn unspent_output(&self, outpoint: &OutPoint) -> Option<TxOut> {
+        self.get(outpoint).cloned()
+    }
+}
+
+/// The reason why a block is invalid.
+///
+/// Unless noted otherwise, `tx` is the index of the offending transaction in the block.
+#[derive(Debug)]
+pub enum BlockViolation {
+    /// The merkle root in the header does not commit to the transactions of the block.
+    MerkleRoot,
+    /// The block repeats a sequence of transactions, which does not change its merkle root.
+    DuplicateTransactions,
+    /// The block has no transactions, or is too large without its witnesses.
+    Length,
+    /// The first transaction of the block is not a coinbase.
+    CoinbaseMissing,
+    /// Transaction `tx` is a coinbase, but is not the first transaction.
+    CoinbaseMultiple { tx: usize },
+    /// Transaction `tx` fails the context-free checks of [`check_transaction`](crate::check_transaction).
+    Transaction {
+        tx: usize,
+        violation: CheckTransactionViolation,
+    },
+    /// The signature operations of the transactions up to `tx` exceed the limit of the block.
+    SigOps { tx: usize },
+    /// The input witness of the coinbase holds data that the block does not commit to, like rangeproofs or a pegin witness.
+    CoinbaseWitness,
+    /// The coinbase commits to the witnesses of the block, but its input witness is not a single 32 byte reserved value.
+    WitnessNonceSize,
+    /// The witness commitment in the coinbase does not match the witnesses of the block.
+    WitnessMerkleMatch,
+    /// A transaction has a witness, but the coinbase does not commit to the witnesses of the block.
+    UnexpectedWitness,
+    /// The block exceeds the maximum block weight.
+    Weight,
+    /// The fees of the block add up to more than the maximum amount of money in an asset.
+    RewardOutOfRange,
+    /// The coinbase pays more than the fees of the block in an asset, or has confidential or invalid outputs.
+    CoinbaseAmount,
+    /// Input `input` of transaction `tx` spends an output that is neither in the UTXO view nor created earlier in the block, or that is already spent.
+    MissingInput { tx: usize, input: usize },
+    /// Input `input` of transaction `tx` is a pegin, but its pegin witness cannot be parsed.
+    InvalidPeginWitness { tx: usize, input: usize },
+    /// The amounts of transaction `tx` do not balance, see [`verify_amounts`](crate::verify_amounts).
+    Amounts {
+        tx: usize,
+        violation: AmountsViolation,
+    },
+    /// Inputs of transaction `tx` do not unlock the outputs they spend, see [`verify_transaction`](crate::verify_transaction).
+    Scripts {
+        tx: usize,
+        violation: TransactionViolation,
+    },
+    /// The consensus library reported a violation that this crate does not know.
+    Unrecognized(u32),
+}
+
+impl BlockViolation {
+    /// The reject reason reported by Elements, e.g. `bad-txnmrklroot`.
+    pub fn reject_reason(&self) -> &'static str {
+        match self {
+            BlockViolation::MerkleRoot => "bad-txnmrklroot",
+            BlockViolation::DuplicateTransactions => "bad-txns-duplicate",
+            BlockViolation::Length => "bad-blk-length",
+            BlockViolation::CoinbaseMissing => "bad-cb-missing",
+            BlockViolation::CoinbaseMultiple { .. } => "bad-cb-multiple",
+            BlockViolation::Transaction { violation, .. } => violation.reject_reason(),
+            BlockViolation::SigOps { .. } => "bad-blk-sigops",
+            BlockViolation::CoinbaseWitness => "bad-cb-witness",
+            BlockViolation::WitnessNonceSize => "bad-witness-nonce-size",
+            BlockViolation::WitnessMerkleMatch => "bad-witness-merkle-match",
+            BlockViolation::UnexpectedWitness => "unexpected-witness",
+            BlockViolation::Weight => "bad-blk-weight",
+            BlockViolation::RewardOutOfRange => "bad-blockreward-outofrange",
+            BlockViolation::CoinbaseAmount => "bad-cb-amount",
+            BlockViolation::MissingInput { .. } => "bad-txns-inputs-missingorspent",
+            BlockViolation::InvalidPeginWitness { .. } => "bad-pegin-witness",
+            BlockViolation::Amounts {
+                violation: AmountsViolation::InvalidFee,
+                ..
+            } => "bad-txns-fee-outofrange",
+            BlockViolation::Amounts { .. } => "bad-txns-in-ne-out",
+            BlockViolation::Scripts { .. } => "mandatory-script-verify-flag-failed",
+            BlockViolation::Unrecognized(_) => "unrecognized",
+        }
+    }
+
+    fn from_raw(code: u32, index: usize, tx_check_err: u32) -> Self {
+        match code {
+            elements_consensus_sys::bitcoinconsensus_BLOCK_ERR_MERKLE_ROOT => {
+                BlockViolation::MerkleRoot
+            }
+            elements_consensus_sys::bitcoinconsensus_BLOCK_ERR_DUPLICATE => {
+                BlockViolation::DuplicateTransactions
+            }
+            elements_consensus_sys::bitcoinconsensus_BLOCK_ERR_LENGTH => BlockViolation::Length,
+            elements_consensus_sys::bitcoinconsensus_BLOCK_ERR_CB_MISSING => {
+                BlockViolation::CoinbaseMissing
+            }
+            elements_consensus_sys::bitcoinconsensus_BLOCK_ERR_CB_MULTIPLE => {
+                BlockViolation::CoinbaseMultiple { tx: index }
+            }
+            elements_consensus_sys::bitcoinconsensus_BLOCK_ERR_TX_CHECK => {
+                BlockViolation::Transaction {
+                    tx: index,
+                    violation: CheckTransactionViolation::from_raw(tx_check_err),
+                }
+            }
+            elements_consensus_sys::bitcoinconsensus_BLOCK_ERR_SIGOPS => {
+                BlockViolation::SigOps { tx: index }
+            }
+            elements_consensus_sys::bitcoinconsensus_BLOCK_ERR_CB_WITNESS => {
+                BlockViolation::CoinbaseWitness
+            }
+            elements_consensus_sys::bitcoinconsensus_BLOCK_ERR_WITNESS_NONCE_SIZE => {
+                BlockViolation::WitnessNonceSize
+            }
+            elements_consensus_sys::bitcoinconsensus_BLOCK_ERR_WITNESS_MERKLE_MATCH => {
+                BlockViolation::WitnessMerkleMatch
+            }
+            elements_consensus_sys::bitcoinconsensus_BLOCK_ERR_UNEXPECTED_WITNESS => {
+                BlockViolation::UnexpectedWitness
+            }
+            elements_consensus_sys::bitcoinconsensus_BLOCK_ERR_WEIGHT => BlockViolation::Weight,
+            elements_consensus_sys::bitcoinconsensus_BLOCK_ERR_REWARD_OUT_OF_RANGE => {
+                BlockViolation::RewardOutOfRange
+            }
+            elements_consensus_sys::bitcoinconsensus_BLOCK_ERR_CB_AMOUNT => {
+                BlockViolation::CoinbaseAmount
+            }
+            code => BlockViolation::Unrecognized(code),
+        }
+    }
+}
+
+impl fmt::Display for BlockViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BlockViolation::CoinbaseMultiple { tx } | BlockViolation::SigOps { tx } => {
+                write!(f, "{} in transaction {}", self.reject_reason(), tx)
+            }
+            BlockViolation::Transaction { tx, violation } => {
+                write!(f, "{} in transaction {}", violation, tx)
+            }
+            BlockViolation::MissingInput { tx, input }
+            | BlockViolation::InvalidPeginWitness { tx, input } => write!(
+                f,
+                "{} in input {} of transaction {}",
+                self.reject_reason(),
+                input,
+                tx
+            ),
+            BlockViolation::Amounts { tx, violation } => write!(
+                f,
+                "{} in transaction {}: {}",
+                self.reject_reason(),
+                tx,
+                violation
+            ),
+            BlockViolation::Scripts { tx, violation } => write!(
+                f,
+                "{} in transaction {}: {}",
+                self.reject_reason(),
+                tx,
+                violation
+            ),
+            BlockViolation::Unrecognized(code) => {
+                write!(f, "unrecognized block violation {}", code)
+            }
+            _ => write!(f, "{}", self.reject_reason()),
+        }
+    }
+}
+
+impl StdError for BlockViolation {}
+
+/// Verifies that `block` is valid on top of the UTXO set described by `utxo_view`, like `CheckBlock` and `ConnectBlock` in Elements.
+///
+/// This checks the merkle root of the transactions, the witness commitment in the coinbase, the weight and signature operations of the block, and that the coinbase pays at most the fees of the block in each asset.
+/// Elements chains have no block subsidy, so none is added to the fees.
+/// Every other transaction is checked by [`check_transaction`](crate::check_transaction), [`verify_amounts`](crate::verify_amounts) and [`verify_transaction`](crate::verify_transaction), the latter two spread over the global [`rayon`] thread pool.
+///
+/// Transactions may spend outputs created by earlier transactions of the block, except those of the coinbase, which are not mature yet.
+/// Whether the outputs in `utxo_view` are mature is up to the caller.
+/// Pegins are not verified against the parent chain, see [`verify_pegin`](crate::verify_pegin).
+/// Neither is the header, see [`verify_block_header`], nor anything else that depends on the chain, like the height in the coinbase or the lock times.
+///
+/// If the block has several violations, the one that Elements reports first is returned.
+/// Like [`verify`](crate::verify), this function returns two layers of errors.
+pub fn verify_block<U>(
+    block: &Block,
+    utxo_view: &U,
+    chain: &ChainParams,
+) -> Result<Result<(), BlockViolation>, Error>
+where
+    U: UtxoView + ?Sized,
+{
+    let spent_outputs = match spent_outputs(block, utxo_view) {
+        Ok(spent_outputs) => spent_outputs,
+        // The checks that don't need the spent outputs come first in Elements.
+        Err(violation) => return Ok(check_block(block, None)?.and(Err(violation))),
+    };
+
+    let all_spent_outputs = spent_outputs
+        .iter()
+        .flat_map(|(_, outputs)| outputs.iter().cloned())
+        .collect::<Vec<_>>();
+    if let Err(violation) = check_block(block, Some(&all_spent_outputs))? {
+        return Ok(Err(violation));
+    }
+
+    let failure = spent_outputs
+        .par_iter()
+        .map(|(index, outputs)| {
+            verify_block_transaction(*index, &block.txdata[*index], outputs, chain)
+        })
+        .find_first(|result| !matches!(result, Ok(Ok(()))));
+
+    failure.unwrap_or(Ok(Ok(())))
+}
+
+/// Looks up the outputs spent by all transactions of `block` but the coinbase, paired with the index of their transaction.
+fn spent_outputs<U>(
+    block: &Block,
+    utxo_view: &U,
+) -> Result<Vec<(usize, Vec<TxOut>)>, BlockViolation>
+where
+    U: UtxoView + ?Sized,
+{
+    let mut created = HashMap::new();
+    let mut spent = HashSet::new();
+    let mut spent_outputs = Vec::with_capacity(block.txdata.len());
+
+    for (tx, transaction) in block.txdata.iter().enumerate() {
+        if !transaction.is_coinbase() {
+            let outputs = transaction
+                .input
+                .iter()
+                .enumerate()
+                .map(|(input, txin)| {
+                    if txin.is_pegin() {
+                        return pegin_spent_output(txin)
+                            .ok_or(BlockViolation::InvalidPeginWitness { tx, input });
+                    }
+
+                    let outpoint = txin.previous_output;
+                    let output = match created.remove(&outpoint) {
+                        Some(output) => Some(output),
+                        None if spent.contains(&outpoint) => None,
+                        None => utxo_view.unspent_output(&outpoint),
+                    };
+                    spent.insert(outpoint);
+
+                    output.ok_or(BlockViolation::MissingInput { tx, input })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            spent_outputs.push((tx, outputs));
+            add_outputs(&mut created, transaction);
+        }
+    }
+
+    Ok(spent_outputs)
+}
+
+/// Makes the spendable outputs of `transaction` available to later transactions of the block.
+fn add_outputs(created: &mut HashMap<OutPoint, TxOut>, transaction: &Transaction) {
+    let txid = transaction.txid();
+
+    for (vout, output) in transaction.output.iter().enumerate() {
+        if !output.script_pubkey.is_provably_unspendable() {
+            created.insert(OutPoint::new(txid, vout as u32), output.clone());
+        }
+    }
+}
+
+/// Runs the checks of `block` done by the consensus library, which include the ones that need `spent_outputs` if they are given.
+fn check_block(
+    block: &Block,
+    spent_outputs: Option<&Vec<TxOut>>,
+) -> Result<Result<(), BlockViolation>, Error> {
+    let block = serialize(block);
+    let spent_outputs = spent_outputs.map(serialize);
+    let (spent_outputs_ptr, spent_outputs_len) = match &spent_outputs {
+        Some(spent_outputs) => (spent_outputs.as_ptr(), spent_outputs.len() as u32),
+        None => (std::ptr::null(), 0),
+    };
+
+    let mut block_err = 0;
+    let mut index = 0;
+    let mut tx_check_err = 0;
+
+    let checked = ffi::call(|err| unsafe {
+        elements_consensus_sys::bitcoinconsensus_check_block(
+            block.as_ptr(),
+            block.len() as u32,
+            spent_outputs_ptr,
+            spent_outputs_len,
+            err,
+            &mut block_err,
+            &mut index,
+            &mut tx_check_err,
+        )
+    })?;
+
+    if checked {
+        Ok(Ok(()))
+    } else {
+        Ok(Err(BlockViolation::from_raw(
+            block_err,
+            index as usize,
+            tx_check_err,
+        )))
+    }
+}
+
+/// Verifies the amounts and scripts of transaction `tx` of a block, which spends `spent_outputs`.
+fn verify_block_transaction(
+    tx: usize,
+    transaction: &Transaction,
+    spent_outputs: &[TxOut],
+    chain: &ChainParams,
+) -> Result<Result<(), BlockViolation>, Error> {
+    if let Err(violation) = verify_amounts(transaction, spent_outputs)? {
+        return Ok(Err(BlockViolation::Amounts { tx, violation }));
+    }
+    if let Err(violation) = verify_transaction(transaction, spent_outputs, chain)? {
+        return Ok(Err(BlockViolation::Scripts { tx, violation }));
+    }
+
+    Ok(Ok(()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InputError;
+    use elements::bitcoin::util::hash::bitcoin_merkle_root;
+    use elements::encode::serialize;
+    use elements::hashes::{sha256d, Hash};
+    use elements::opcodes::all::{OP_CHECKMULTISIG, OP_CHECKSIG, OP_PUSHBYTES_0, OP_PUSHNUM_1};
+    use elements::script::Builder;
+    use elements::secp256k1_zkp::{Message, PublicKey, Secp256k1, SecretKey};
+    use elements::{confidential, dynafed, issuance::AssetId};
+    use elements::{fast_merkle_root, TxMerkleNode, Txid};
+    use elements::{AssetIssuance, BlockExtData, BlockHash, Script, TxIn, TxInWitness};
+
+    fn federation_key() -> SecretKey {
+        SecretKey::from_slice(&[7; 32]).unwrap()
+    }
+
+    /// `<federation key> OP_CHECKSIG`
+    fn signblockscript() -> Script {
+        let key = PublicKey::from_secret_key(&Secp256k1::new(), &federation_key());
+
+        Builder::new()
+            .push_slice(&key.serialize())
+            .push_opcode(OP_CHECKSIG)
+            .into_script()
+    }
+
+    fn params() -> ChainParams {
+        ChainParams {
+            signblockscript: signblockscript().into_bytes(),
+            ..ChainParams::elements_regtest()
+        }
+    }
+
+    fn header(ext: BlockExtData) -> BlockHeader {
+        BlockHeader {
+            version: 0x2000_0000,
+            prev_blockhash: BlockHash::from_inner([1; 32]),
+            merkle_root: TxMerkleNode::from_inner([2; 32]),
+            time: 1_600_000_000,
+            height: 42,
+            ext,
+        }
+    }
+
+    /// Signs the hash of `header` with `key`, which commits to everything but the signature.
+    fn signature(header: &BlockHeader, key: &SecretKey) -> Vec<u8> {
+        Secp256k1::new()
+            .sign(&Message::from_slice(&header.block_hash()[..]).unwrap(), key)
+            .serialize_der()
+            .to_vec()
+    }
+
+    /// A header without dynamic federation parameters whose solution is signed with `key`.
+    fn legacy_header(challenge: Script, key: &SecretKey) -> BlockHeader {
+        let mut header = header(BlockExtData::Proof {
+            challenge,
+            solution: Script::new(),
+        });
+        let signature = signature(&header, key);
+        if let BlockExtData::Proof { solution, .. } = &mut header.ext {
+            *solution = Builder::new().push_slice(&signature).into_script();
+        }
+
+        header
+    }
+
+    /// A header with dynamic federation parameters whose `signblockscript` is the P2WSH of [`signblockscript`], signed with `key`.
+    fn dynafed_header(signblock_witness_limit: u32, key: &SecretKey) -> BlockHeader {
+        let mut header = header(BlockExtData::Dynafed {
+            current: dynafed::Params::Compact {
+                signblockscript: signblockscript().to_v0_p2wsh(),
+                signblock_witness_limit,
+                elided_root: Default::default(),
+            },
+            proposed: dynafed::Params::Null,
+            signblock_witness: vec![],
+        });
+        let mut signature = signature(&header, key);
+        signature.push(0x01); // SIGHASH_ALL
+        if let BlockExtData::Dynafed {
+            signblock_witness, ..
+        } = &mut header.ext
+        {
+            *signblock_witness = vec![signature, signblockscript().into_bytes()];
+        }
+
+        header
+    }
+
+    #[test]
+    fn header_signed_by_federation_verifies() {
+        let header = legacy_header(signblockscript(), &federation_key());
+
+        verify_block_header(&header, &params()).unwrap().unwrap();
+    }
+
+    #[test]
+    fn header_signed_by_other_key_does_not_verify() {
+        let other_key = SecretKey::from_slice(&[8; 32]).unwrap();
+        let header = legacy_header(signblockscript(), &other_key);
+
+        let violation = verify_block_header(&header, &params())
+            .unwrap()
+            .unwrap_err();
+
+        assert_eq!(
+            violation,
+            BlockHeaderViolation::Signature(ScriptError::EvalFalse)
+        );
+    }
+
+    #[test]
+    fn header_with_other_challenge_does_not_verify() {
+        let header = legacy_header(Script::new(), &federation_key());
+
+        let violation = verify_block_header(&header, &params())
+            .unwrap()
+            .unwrap_err();
+
+        assert_eq!(violation, BlockHeaderViolation::Challenge);
+    }
+
+    #[test]
+    fn header_signature_above_limit_does_not_verify() {
+        let header = legacy_header(signblockscript(), &federation_key());
+        let params = ChainParams {
+            max_block_signature_size: 8,
+            ..params()
+        };
+
+        let violation = verify_block_header(&header, &params).unwrap().unwrap_err();
+
+        assert_eq!(violation, BlockHeaderViolation::SignatureSize);
+    }
+
+    #[test]
+    fn dynafed_header_verifies_against_current_parameters() {
+        let header = dynafed_header(150, &federation_key());
+        // The chain's own signblockscript no longer applies.
+        let params = ChainParams::elements_regtest();
+
+        verify_block_header(&header, &params).unwrap().unwrap();
+    }
+
+    #[test]
+    fn dynafed_witness_above_current_limit_does_not_verify() {
+        let header = dynafed_header(8, &federation_key());
+
+        let violation = verify_block_header(&header, &params())
+            .unwrap()
+            .unwrap_err();
+
+        assert_eq!(violation, BlockHeaderViolation::SignatureSize);
+    }
+
+    fn op_true() -> Script {
+        Builder::new().push_opcode(OP_PUSHNUM_1).into_script()
+    }
+
+    fn output(script_pubkey: Script, value: u64) -> TxOut {
+        TxOut {
+            asset: confidential::Asset::Explicit(AssetId::from_slice(&[3; 32]).unwrap()),
+            value: confidential::Value::Explicit(value),
+            script_pubkey,
+            ..Default::default()
+        }
+    }
+
+    fn input(previous_output: OutPoint, script_sig: Script) -> TxIn {
+        TxIn {
+            previous_output,
+            is_pegin: false,
+            has_issuance: false,
+            script_sig,
+            sequence: 0xffff_ffff,
+            asset_issuance: AssetIssuance::default(),
+            witness: TxInWitness::default(),
+        }
+    }
+
+    fn transaction(input: Vec<TxIn>, output: Vec<TxOut>) -> Transaction {
+        Transaction {
+            version: 2,
+            lock_time: 0,
+            input,
+            output,
+        }
+    }
+
+    fn coinbase(value: u64) -> Transaction {
+        let height = Builder::new().push_int(42).push_int(0).into_script();
+
+        transaction(
+            vec![input(OutPoint::default(), height)],
+            vec![output(op_true(), value)],
+        )
+    }
+
+    /// Spends `previous_output` worth `value` to a new `OP_TRUE` output, paying `fee`.
+    fn spend(previous_output: OutPoint, value: u64, fee: u64) -> Transaction {
+        transaction(
+            vec![input(previous_output, Script::new())],
+            vec![output(op_true(), value - fee), output(Script::new(), fee)],
+        )
+    }
+
+    fn utxo() -> OutPoint {
+        OutPoint::new(Txid::from_inner([9; 32]), 0)
+    }
+
+    fn utxo_view(script_pubkey: Script) -> HashMap<OutPoint, TxOut> {
+        vec![(utxo(), output(script_pubkey, 100_000))]
+            .into_iter()
+            .collect()
+    }
+
+    fn block(txdata: Vec<Transaction>) -> Block {
+        let txids = txdata
+            .iter()
+            .map(|tx| sha256d::Hash::from_inner(tx.txid().into_inner()));
+        let merkle_root = TxMerkleNode::from_inner(bitcoin_merkle_root(txids).into_inner());
+
+        Block {
+            header: BlockHeader {
+                merkle_root,
+                ..header(BlockExtData::Proof {
+                    challenge: signblockscript(),
+                    solution: Script::new(),
+                })
+            },
+            txdata,
+        }
+    }
+
+    fn witness_commitment_prefix() -> Vec<u8> {
+        vec![0x6a, 0x24, 0xaa, 0x21, 0xa9, 0xed]
+    }
+
+    /// Builds a block whose coinbase commits to the witnesses of `txdata`, like Elements does.
+    fn committed_block(mut txdata: Vec<Transaction>) -> Block {
+        let nonce = vec![0; 32];
+        txdata[0].input[0].witness.script_witness = vec![nonce.clone()];
+        txdata[0].output.push(output(Script::new(), 0));
+
+        fn hash(data: Vec<u8>) -> [u8; 32] {
+            sha256d::Hash::hash(&data).into_inner()
+        }
+        fn root(leaves: &[[u8; 32]]) -> [u8; 32] {
+            fast_merkle_root(leaves).into_inner()
+        }
+        fn input_root(input: &TxIn) -> [u8; 32] {
+            // The coinbase input commits to an empty witness in place of the nonce.
+            let witness = if input.previous_output == OutPoint::default() {
+                TxInWitness::default()
+            } else {
+                input.witness.clone()
+            };
+            root(&[
+                hash(serialize(&witness.amount_rangeproof)),
+                hash(serialize(&witness.inflation_keys_rangeproof)),
+                hash(serialize(&witness.script_witness)),
+                hash(serialize(&witness.pegin_witness)),
+            ])
+        }
+        fn output_root(output: &TxOut) -> [u8; 32] {
+            root(&[
+                hash(serialize(&output.witness.surjection_proof)),
+                hash(serialize(&output.witness.rangeproof)),
+            ])
+        }
+
+        let leaves: Vec<_> = txdata
+            .iter()
+            .map(|tx| {
+                let inputs: Vec<_> = tx.input.iter().map(input_root).collect();
+                let outputs: Vec<_> = tx.output.iter().map(output_root).collect();
+                root(&[root(&inputs), root(&outputs)])
+            })
+            .collect();
+        let mut data = root(&leaves).to_vec();
+        data.extend_from_slice(&nonce);
+
+        let mut commitment = witness_commitment_prefix();
+        commitment.extend_from_slice(&hash(data));
+        txdata[0].output.last_mut().unwrap().script_pubkey = Script::from(commitment);
+        block(txdata)
+    }
+
+    /// A block that spends the UTXO in one transaction and its new output in the next.
+    fn chained_spends(coinbase_value: u64) -> Block {
+        let first = spend(utxo(), 100_000, 1_000);
+        let second = spend(OutPoint::new(first.txid(), 0), 99_000, 500);
+
+        block(vec![coinbase(coinbase_value), first, second])
+    }
+
+    fn block_violation(block: &Block, utxo_view: &HashMap<OutPoint, TxOut>) -> BlockViolation {
+        verify_block(block, utxo_view, &params())
+            .unwrap()
+            .unwrap_err()
+    }
+
+    #[test]
+    fn block_spending_utxo_and_earlier_output_verifies() {
+        let block = chained_spends(1_500);
+
+        verify_block(&block, &utxo_view(op_true()), &params())
+            .unwrap()
+            .unwrap();
+    }
+
+    #[test]
+    fn merkle_root_is_checked_before_inputs() {
+        let mut block = block(vec![coinbase(1_000), spend(utxo(), 100_000, 1_000)]);
+        block.header.merkle_root = TxMerkleNode::from_inner([2; 32]);
+
+        let violation = block_violation(&block, &HashMap::new());
+
+        assert!(matches!(violation, BlockViolation::MerkleRoot));
+        assert_eq!(violation.reject_reason(), "bad-txnmrklroot");
+    }
+
+    #[test]
+    fn spend_of_unknown_output_is_missing_input() {
+        let block = block(vec![coinbase(1_000), spend(utxo(), 100_000, 1_000)]);
+
+        let violation = block_violation(&block, &HashMap::new());
+
+        assert!(matches!(
+            violation,
+            BlockViolation::MissingInput { tx: 1, input: 0 }
+        ));
+        assert_eq!(violation.reject_reason(), "bad-txns-inputs-missingorspent");
+    }
+
+    #[test]
+    fn second_spend_in_block_is_missing_input() {
+        let block = block(vec![
+            coinbase(2_000),
+            spend(utxo(), 100_000, 1_000),
+            spend(utxo(), 100_000, 1_000),
+        ]);
+
+        let violation = block_violation(&block, &utxo_view(op_true()));
+
+        assert!(matches!(
+            violation,
+            BlockViolation::MissingInput { tx: 2, input: 0 }
+        ));
+    }
+
+    #[test]
+    fn coinbase_paying_more_than_fees_is_rejected() {
+        let block = chained_spends(1_501);
+
+        let violation = block_violation(&block, &utxo_view(op_true()));
+
+        assert!(matches!(violation, BlockViolation::CoinbaseAmount));
+        assert_eq!(violation.reject_reason(), "bad-cb-amount");
+    }
+
+    #[test]
+    fn unbalanced_transaction_is_amounts_violation() {
+        let block = block(vec![coinbase(1_000), spend(utxo(), 100_001, 1_000)]);
+
+        let violation = block_violation(&block, &utxo_view(op_true()));
+
+        assert!(matches!(
+            violation,
+            BlockViolation::Amounts {
+                tx: 1,
+                violation: AmountsViolation::Unbalanced
+            }
+        ));
+        assert_eq!(violation.reject_reason(), "bad-txns-in-ne-out");
+    }
+
+    #[test]
+    fn failing_script_is_scripts_violation() {
+        let block = chained_spends(1_500);
+        let op_false = Builder::new().push_opcode(OP_PUSHBYTES_0).into_script();
+
+        let violation = block_violation(&block, &utxo_view(op_false));
+
+        match violation {
+            BlockViolation::Scripts { tx: 1, violation } => match violation.input(0) {
+                Some(InputError::ConsensusViolation(violation)) => {
+                    assert_eq!(violation.script_error(), ScriptError::EvalFalse)
+                }
+                error => panic!("unexpected input error: {:?}", error),
+            },
+            violation => panic!("unexpected violation: {}", violation),
+        }
+    }
+
+    #[test]
+    fn witness_without_commitment_is_unexpected() {
+        let mut spend = spend(utxo(), 100_000, 1_000);
+        spend.input[0].witness.script_witness = vec![vec![1]];
+        let block = block(vec![coinbase(1_000), spend]);
+
+        let violation = block_violation(&block, &utxo_view(op_true()));
+
+        assert!(matches!(violation, BlockViolation::UnexpectedWitness));
+    }
+
+    #[test]
+    fn wrong_witness_commitment_is_rejected() {
+        let mut coinbase = coinbase(1_000);
+        let mut commitment = witness_commitment_prefix();
+        commitment.extend_from_slice(&[0; 32]);
+        coinbase.output.push(output(Script::from(commitment), 0));
+        coinbase.input[0].witness.script_witness = vec![vec![0; 32]];
+        let block = block(vec![coinbase, spend(utxo(), 100_000, 1_000)]);
+
+        let violation = block_violation(&block, &utxo_view(op_true()));
+
+        assert!(matches!(violation, BlockViolation::WitnessMerkleMatch));
+    }
+
+    #[test]
+    fn witness_nonce_of_wrong_size_is_rejected() {
+        let mut block = committed_block(vec![coinbase(1_000), spend(utxo(), 100_000, 1_000)]);
+        block.txdata[0].input[0].witness.script_witness = vec![vec![0; 31]];
+
+        let violation = block_violation(&block, &utxo_view(op_true()));
+
+        assert!(matches!(violation, BlockViolation::WitnessNonceSize));
+        assert_eq!(violation.reject_reason(), "bad-witness-nonce-size");
+    }
+
+    #[test]
+    fn pegin_witness_in_coinbase_is_rejected() {
+        let mut coinbase = coinbase(1_000);
+        coinbase.input[0].witness.pegin_witness = vec![vec![1]];
+        let block = block(vec![coinbase, spend(utxo(), 100_000, 1_000)]);
+
+        let violation = block_violation(&block, &utxo_view(op_true()));
+
+        assert!(matches!(violation, BlockViolation::CoinbaseWitness));
+        assert_eq!(violation.reject_reason(), "bad-cb-witness");
+    }
+
+    #[test]
+    fn empty_block_is_length_violation() {
+        let block = block(vec![]);
+
+        let violation = block_violation(&block, &HashMap::new());
+
+        assert!(matches!(violation, BlockViolation::Length));
+        assert_eq!(violation.reject_reason(), "bad-blk-length");
+    }
+
+    #[test]
+    fn witness_above_weight_limit_is_rejected() {
+        let mut spend = spend(utxo(), 100_000, 1_000);
+        spend.input[0].witness.script_witness = vec![vec![0; 4_000_000]];
+        let block = committed_block(vec![coinbase(1_000), spend]);
+
+        let violation = block_violation(&block, &utxo_view(op_true()));
+
+        assert!(matches!(violation, BlockViolation::Weight));
+        assert_eq!(violation.reject_reason(), "bad-blk-weight");
+    }
+
+    #[test]
+    fn legacy_sigops_above_limit_are_rejected() {
+        // OP_CHECKMULTISIG counts as 20 legacy sigops, the block may have 20,000.
+        let mut coinbase = coinbase(1_000);
+        coinbase.output[0].script_pubkey = Script::from(vec![OP_CHECKMULTISIG.into_u8(); 1_001]);
+        let block = block(vec![coinbase, spend(utxo(), 100_000, 1_000)]);
+
+        let violation = block_violation(&block, &utxo_view(op_true()));
+
+        assert!(matches!(violation, BlockViolation::SigOps { tx: 0 }));
+        assert_eq!(violation.reject_reason(), "bad-blk-sigops");
+    }
+
+    #[test]
+    fn fees_above_max_money_are_reward_out_of_range() {
+        let max_money = 21_000_000 * 100_000_000;
+        let first = spend(utxo(), max_money, max_money);
+        let second = spend(OutPoint::new(first.txid(), 0), max_money, max_money);
+        let block = block(vec![coinbase(0), first, second]);
+
+        let violation = block_violation(&block, &utxo_view(op_true()));
+
+        assert!(matches!(violation, BlockViolation::RewardOutOfRange));
+        assert_eq!(violation.reject_reason(), "bad-blockreward-outofrange");
+    }
+}
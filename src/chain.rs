@@ -0,0 +1,417 @@
+use crate::{PeginParams, VerificationFlags};
+use elements::bitcoin::{self, util::uint::Uint256};
+use elements::hashes::hex::{FromHex, ToHex};
+use elements::hashes::{sha256, Hash, HashEngine};
+use elements::issuance::{AssetId, ContractHash};
+use elements::opcodes::all::{OP_PUSHNUM_1, OP_RETURN};
+use elements::script::Builder;
+use elements::{confidential, AssetIssuance, BlockExtData, BlockHash, BlockHeader, OutPoint};
+use elements::{Script, Transaction, TxIn, TxMerkleNode, TxOut, Txid};
+
+const LIQUID_SIGNBLOCKSCRIPT: &str = "5b21026a2a106ec32c8a1e8052e5d02a7b0a150423dbd9b116fc48d46630ff6e6a05b92102791646a8b49c2740352b4495c118d876347bf47d0551c01c4332fdc2df526f1a2102888bda53a424466b0451627df22090143bbf7c060e9eacb1e38426f6b07f2ae12102aee8967150dee220f613de3b239320355a498808084a93eaf39a34dcd62024852102d46e9259d0a0bb2bcbc461a3e68f34adca27b8d08fbe985853992b4b104e27412102e9944e35e5750ab621e098145b8e6cf373c273b7c04747d1aa020be0af40ccd62102f9a9d4b10a6d6c56d8c955c547330c589bb45e774551d46d415e51cd9ad5116321033b421566c124dfde4db9defe4084b7aa4e7f36744758d92806b8f72c2e943309210353dcc6b4cf6ad28aceb7f7b2db92a4bf07ac42d357adf756f3eca790664314b621037f55980af0455e4fb55aad9b85a55068bb6dc4740ea87276dc693f4598db45fa210384001daa88dabd23db878dbb1ce5b4c2a5fa72c3113e3514bf602325d0c37b8e21039056d089f2fe72dbc0a14780b4635b0dc8a1b40b7a59106325dd1bc45cc70493210397ab8ea7b0bf85bc7fc56bb27bf85e75502e94e76a6781c409f3f2ec3d1122192103b00e3b5b77884bf3cae204c4b4eac003601da75f96982ffcb3dcb29c5ee419b92103c1f3c0874cfe34b8131af34699589aacec4093399739ae352e8a46f80a6f68375fae";
+const LIQUID_FEDPEGSCRIPT: &str = "745c87635b21020e0338c96a8870479f2396c373cc7696ba124e8635d41b0ea581112b678172612102675333a4e4b8fb51d9d4e22fa5a8eaced3fdac8a8cbf9be8c030f75712e6af992102896807d54bc55c24981f24a453c60ad3e8993d693732288068a23df3d9f50d4821029e51a5ef5db3137051de8323b001749932f2ff0d34c82e96a2c2461de96ae56c2102a4e1a9638d46923272c266631d94d36bdb03a64ee0e14c7518e49d2f29bc40102102f8a00b269f8c5e59c67d36db3cdc11b11b21f64b4bffb2815e9100d9aa8daf072103079e252e85abffd3c401a69b087e590a9b86f33f574f08129ccbd3521ecf516b2103111cf405b627e22135b3b3733a4a34aa5723fb0f58379a16d32861bf576b0ec2210318f331b3e5d38156da6633b31929c5b220349859cc9ca3d33fb4e68aa08401742103230dae6b4ac93480aeab26d000841298e3b8f6157028e47b0897c1e025165de121035abff4281ff00660f99ab27bb53e6b33689c2cd8dcd364bc3c90ca5aea0d71a62103bd45cddfacf2083b14310ae4a84e25de61e451637346325222747b157446614c2103cc297026b06c71cbfa52089149157b5ff23de027ac5ab781800a578192d175462103d3bde5d63bdb3a6379b461be64dad45eabff42f758543a9645afd42f6d4248282103ed1e8d5109c9ed66f7941bc53cc71137baa76d50d274bda8d5e8ffbd6e61fe9a5f6702c00fb275522103aab896d53a8e7d6433137bbba940f9c521e085dd07e60994579b64a6d992cf79210291b7d0b1b692f8f524516ed950872e5da10fb1b808b5a526dedc6fed1cf29807210386aa9372fbab374593466bc5451dc59954e90787f08060964d95c87ef34ca5bb5368ae";
+const LIQUID_TESTNET_SIGNBLOCKSCRIPT: &str =
+    "51210217e403ddb181872c32a0cd468c710040b2f53d8cac69f18dad07985ee37e9a7151ae";
+/// The PAK list that Liquid started with, as pairs of an offline and an online public key.
+const LIQUID_PAK_LIST: [&str; 29] = [
+    "0362f0cf4898e44a20472664daed460156976bab5cc8bb8431b206bbafddd230c90399dadeeedc2cefe9042ffa596c553cad1967cda04de6aa0f9fbd96b6044292e7",
+    "033fad80bd2b818d1ca8a8d4a25dafcf5e740be07db6788be1f2f15266e3c6805d0253ff3f140ef8f594d54996eab810a82550c79204279920d95681afe699d00da5",
+    "03f2d35e88741f930a3938bfa7075377ec2da4f1d7699a779e2cbf7a389195dc67026132199a025299b5e0f4ab3f44294c81c5302f6d45ddda6316c18ae515793cf6",
+    "036286d30d20ddcd3e867851936802dd8a2d84846c7e52aece0fc303c6deec9e0402c7581da9d9ac0001e1c560c348b5df07d42de166d74eccd4c3bda467fe84f898",
+    "0327b1884b3d743f4859db7c2df07e6e346d61d77fbc46c1da6db113fbbd43d7c50383c832ec502cf0990b199a4e46a45a63bfa6c6eb3f4b231472f144e684d6e9f8",
+    "03075f118532928c7ef27a77644a12a87fbada3cd94cf67b2d2ae5cb169ddaefa402882c4fed938b20f3472af337cd7674a99f0aab0ae1803e27e978c52c417ce5e1",
+    "02b988448e337c15cd6ac82b4737e3e2b5e92947da2f7fa96a81db7f9be3fabeb202f660c7675a1ed4893df838a5c4c07a287997cbd7dc5d884044b338ed606231bc",
+    "0245b763999e3152418b9cd08b5f54c410a072d5e486826823791848e1bb8790610259740ea12e953db0c5fd135c1a9564ce81a318729668811cf54f884c2f980eb8",
+    "032f8814144351d5d05ca40c87cbbda67bb5f8b1920a38cf3bd008c1d266bb4682039eb3a0b89656b338c3f4a9fc7bba582dd21935f59471c18e6b43c57e063053d9",
+    "03d8b2ed1813370955cfb8dec24b7c5cb34b13fa4545d9e6d47d8c05af56a2c7d2026392f13fefce606c60adadfe9e729e0af84f5f8cb6a35b76be244351635b38f7",
+    "03e2a56e47f41eb83af34fb65c4dfb77ac442b01b5134fd92219bd3f4a999c7de5034e93391cea816e5141dace7e5477bbed90c9daa0670b68b7acc8a44af556bbc1",
+    "03156b39a4bce80e68c1582aa78f81f0252ccbb039766b5395ee9a0224f41c236d0399a5d1d42f5b6cb587560394e1581eb0c76916db317c0d644a1b9f509a06c4e6",
+    "029ce033e1dc81164deb04b4c55966b823a025ef47bb1f767017696b68ab9ae20103e612d646e71b07e5ce0eaa3a0178e4606dd9a6e8f0d5ace9171fb1e808a3865b",
+    "02a8300f0cff92b23e402459e83c52ec5824de82ee4004cf9d254e788304027ef60389cbda672fa9efea51706863f1d7ae5e5015b2e519003ef0178c99f71be6e8be",
+    "03fcba7ecf41bc7e1be4ee122d9d22e3333671eb0a3a87b5cdf099d59874e1940f02b0fb4fe4670c68329441e47acaaa954ff00e3fd547b9ff4e0fe547df2e775ec5",
+    "0335f807a1bdc0906adda1a4166f9cdc2aa974a78b15fc29d79a8d7ca529a9600802228dfd7ff95506dd67b1118803eb8ab49352b2e24cd5f38da043847e722009ba",
+    "03fcc2963daaf8249bfd220e52c693626254b9295ac4f947ae2e0cddb3046724c102dac03530ac9712a71eafb87766644b61cf4be85d0fdc6a859875b41e7a1dc8e6",
+    "039bfd22bf5c41ce14d3fbd50ef226d2066e826b2efba455150d23d958d52bfddf03211678d22c45402c993d96ea4a6d861d3e1da33798aebd5424fe5725a7ce8f4b",
+    "02d67fcb027c5d8fe354fb36235192cb4fffabffdcc6ce74be255fe869f62d867503d61d857b2a8cb060fd4b9a98a862f250df5825068665a3c8d93f2ac8a7085888",
+    "02cfe983eb588975958e9ce832937ba7f24592882cf5c0fc0f07896097fd66a8e70344744d01c091eacea5730ed1205b0a83378418644ea7938ed664649e88dcbb29",
+    "029ec6dd0c310513b3720800025a7ad9013d60a7fb041f6e9b9d3963485ba286570277247f28eb9481dd21d664093a2bc19a496c7ffebeca0026a1726a5041e671ba",
+    "03f9dea372c4a667dcfe234ff8e0410c22341149ff7d8780c46954ff74998fbe440340c4e534906c06b73874cef00a880ab602641c7883de94296f0f601e6517ae7e",
+    "03cf8520f2db93e1ba75fa9043ac7e3476719b2a33a12d7e725688a2de68852c880343b7551ba662fa7071ac93e7e25517967bb8a9420af64d35d41c6d88056ad4ba",
+    "03f79461a5559f360c407069b92a8075958bf1f70918872d9dd702db145bccbd420395058fc702f126176ae13e0ebed05107288900a5a35b121f62923e58798b7b2f",
+    "02d7f049d9e87c861fc9decfbe167cb13ccc87cce99113f69e3a5dca8bb71b6aed03e82197b2e9cc0ee11a59808cfdb52e824445f8fa99e44dc9c30d1e49950ff9d6",
+    "0281bfeffcc6841d1355dce039f5d64f72714a4c3adc4d351eaf3c28acbcee15f00270a16ee1cdfc78755a783efbdb66fe822605cc5f53af707e5038615e22b288e2",
+    "025651f14b6347a000e15473eaf631fd78c9307e07db85e177e31fcde0b3f2a57403d5303909fe1c6665cbc96a538b17274068c8e79757705f68db3df2b561a4c110",
+    "03627a4855be1edc657927f30a4a869ad830041c1f0e74ab4670588af9532b8de803444cb85aef9fbba10b3e2662d533858db771010b57b7aedb1ecaa1c5a34918f1",
+    "0286951fdc1e81652cdd10a10971966792e5c2a2bbe524f32a561f585b2b3d2057034294862542484e49c6fb835919212352527298c689ff7be57e445bf0fe3536de",
+];
+/// The PAK entry that custom chains default to, with the same junk key in both halves.
+const DEFAULT_PAK_ENTRY: &str = "02fcba7ecf41bc7e1be4ee122d9d22e3333671eb0a3a87b5cdf099d59874e1940f02fcba7ecf41bc7e1be4ee122d9d22e3333671eb0a3a87b5cdf099d59874e1940f";
+
+const BITCOIN_GENESIS_HASH: &str =
+    "000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f";
+const BITCOIN_REGTEST_GENESIS_HASH: &str =
+    "0f9188f13cb7b2c71f2a335e3a4fc328bf5beb436012afca590b1a11466e2206";
+
+/// The default of `-peginconfirmationdepth`.
+const DEFAULT_PEGIN_CONFIRMATION_DEPTH: u32 = 8;
+/// The default of `-con_max_block_sig_size` for custom chains.
+const DEFAULT_MAX_BLOCK_SIGNATURE_SIZE: u32 = 74;
+/// The block signature limit of Liquid, which has room for 12 signatures and 16 public keys.
+const LIQUID_MAX_BLOCK_SIGNATURE_SIZE: u32 = 12 * 74 + 16 * 33;
+/// The default of `-dynamic_epoch_length` for custom chains.
+const DEFAULT_DYNAMIC_EPOCH_LENGTH: u32 = 10;
+/// The length of a dynamic federation epoch on Liquid, two weeks of one minute blocks.
+const LIQUID_DYNAMIC_EPOCH_LENGTH: u32 = 20160;
+
+/// The parameters of an Elements chain that the consensus rules depend on.
+///
+/// Elements derives most of these from a handful of arguments (see [`CustomChainArgs`]), but they can also be set directly, e.g. for a chain that has already moved to dynamic federations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainParams {
+    /// The hash of the genesis block, which the Taproot signature hash commits to.
+    pub genesis_hash: BlockHash,
+    /// The asset that peg-ins are claimed in and that fees are paid in by default, e.g. L-BTC.
+    pub policy_asset: AssetId,
+    /// The hash of the genesis block of the parent chain.
+    pub parent_genesis_hash: bitcoin::BlockHash,
+    /// The highest target that a parent block may have.
+    pub parent_pow_limit: Uint256,
+    /// The script of the federation that peg-ins are paid to.
+    pub fedpegscript: Vec<u8>,
+    /// The number of confirmations that a parent block needs before its outputs can be claimed.
+    pub pegin_min_depth: u32,
+    /// The script that the federation signs blocks with, until the chain moves to dynamic federations.
+    pub signblockscript: Vec<u8>,
+    /// The maximum size of the signature of a block that is signed with [`ChainParams::signblockscript`].
+    pub max_block_signature_size: u32,
+    /// The number of blocks in a dynamic federation epoch, in which proposed parameters are voted on.
+    pub dynamic_epoch_length: u32,
+    /// The extension space of the full parameters that take effect when the chain moves to dynamic federations, which holds the PAK list.
+    pub first_extension_space: Vec<Vec<u8>>,
+    /// Whether proposed extension spaces must be lists of PAK entries.
+    pub enforce_pak: bool,
+    /// Whether dynamic federations are active, which allows signatures with [`VerificationFlags::SIGHASH_RANGEPROOF`](crate::VerificationFlags::SIGHASH_RANGEPROOF).
+    pub dynafed_active: bool,
+}
+
+/// The arguments that a custom Elements chain is started with, e.g. `-chain=elementsregtest -fedpegscript=51`.
+///
+/// [`CustomChainArgs::new`] sets the defaults of `elementsd` for all of them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomChainArgs {
+    /// The name of the chain, i.e. `-chain`.
+    pub name: String,
+    /// `-fedpegscript`
+    pub fedpegscript: Vec<u8>,
+    /// `-signblockscript`
+    pub signblockscript: Vec<u8>,
+    /// `-parentgenesisblockhash`
+    pub parent_genesis_hash: bitcoin::BlockHash,
+    /// `-con_parentpowlimit`
+    pub parent_pow_limit: Uint256,
+    /// `-peginconfirmationdepth`
+    pub pegin_min_depth: u32,
+    /// `-initialfreecoins`, in satoshi.
+    pub initial_free_coins: u64,
+    /// `-initialreissuancetokens`, in satoshi.
+    pub initial_reissuance_tokens: u64,
+    /// `-con_max_block_sig_size`
+    pub max_block_signature_size: u32,
+    /// `-dynamic_epoch_length`
+    pub dynamic_epoch_length: u32,
+    /// `-pak`, one entry per argument.
+    pub pak: Vec<Vec<u8>>,
+    /// `-enforce_pak`
+    pub enforce_pak: bool,
+    /// Whether dynamic federations are active, which they are from the genesis block unless `-con_dyna_deploy_start` is set.
+    pub dynafed_active: bool,
+}
+
+impl CustomChainArgs {
+    /// The arguments of the custom chain `name` if none are given, which make it a chain pegged to Bitcoin regtest.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            fedpegscript: vec![OP_PUSHNUM_1.into_u8()],
+            signblockscript: vec![OP_PUSHNUM_1.into_u8()],
+            parent_genesis_hash: bitcoin::BlockHash::from_hex(BITCOIN_REGTEST_GENESIS_HASH)
+                .expect("valid hash"),
+            parent_pow_limit: pow_limit(0x7f, 0),
+            pegin_min_depth: DEFAULT_PEGIN_CONFIRMATION_DEPTH,
+            initial_free_coins: 0,
+            initial_reissuance_tokens: 0,
+            max_block_signature_size: DEFAULT_MAX_BLOCK_SIGNATURE_SIZE,
+            dynamic_epoch_length: DEFAULT_DYNAMIC_EPOCH_LENGTH,
+            pak: vec![Vec::from_hex(DEFAULT_PAK_ENTRY).expect("valid hex")],
+            enforce_pak: false,
+            dynafed_active: true,
+        }
+    }
+}
+
+impl ChainParams {
+    /// The parameters of Liquid, pegged to Bitcoin mainnet.
+    pub fn liquid() -> Self {
+        Self::custom(&CustomChainArgs {
+            fedpegscript: Vec::from_hex(LIQUID_FEDPEGSCRIPT).expect("valid hex"),
+            signblockscript: Vec::from_hex(LIQUID_SIGNBLOCKSCRIPT).expect("valid hex"),
+            parent_genesis_hash: bitcoin::BlockHash::from_hex(BITCOIN_GENESIS_HASH)
+                .expect("valid hash"),
+            parent_pow_limit: pow_limit(0xff, 8),
+            pegin_min_depth: 100,
+            max_block_signature_size: LIQUID_MAX_BLOCK_SIGNATURE_SIZE,
+            dynamic_epoch_length: LIQUID_DYNAMIC_EPOCH_LENGTH,
+            pak: LIQUID_PAK_LIST
+                .iter()
+                .map(|entry| Vec::from_hex(entry).expect("valid hex"))
+                .collect(),
+            enforce_pak: true,
+            dynafed_active: true,
+            ..CustomChainArgs::new("liquidv1")
+        })
+    }
+
+    /// The parameters of the Liquid testnet, i.e. `-chain=liquidtestnet` with the arguments that Blockstream publishes for it.
+    ///
+    /// The Liquid testnet has no parent chain (`-con_has_parent_chain=0`), so peg-ins into it cannot be verified.
+    pub fn liquid_testnet() -> Self {
+        Self::custom(&CustomChainArgs {
+            signblockscript: Vec::from_hex(LIQUID_TESTNET_SIGNBLOCKSCRIPT).expect("valid hex"),
+            parent_genesis_hash: bitcoin::BlockHash::default(),
+            initial_free_coins: 2_100_000_000_000_000,
+            max_block_signature_size: 150,
+            ..CustomChainArgs::new("liquidtestnet")
+        })
+    }
+
+    /// The parameters of `-chain=elementsregtest` without further arguments.
+    pub fn elements_regtest() -> Self {
+        Self::custom(&CustomChainArgs::new("elementsregtest"))
+    }
+
+    /// Derives the parameters of a custom chain like `elementsd` does, including the hash of its genesis block.
+    pub fn custom(args: &CustomChainArgs) -> Self {
+        let commit = commit_to_arguments(args);
+        let entropy = AssetId::generate_asset_entropy(
+            OutPoint::new(commit, 0),
+            ContractHash::from_inner(args.parent_genesis_hash.into_inner()),
+        );
+
+        Self {
+            genesis_hash: genesis_block_header(args, commit).block_hash(),
+            policy_asset: AssetId::from_entropy(entropy),
+            parent_genesis_hash: args.parent_genesis_hash,
+            parent_pow_limit: args.parent_pow_limit,
+            fedpegscript: args.fedpegscript.clone(),
+            pegin_min_depth: args.pegin_min_depth,
+            signblockscript: args.signblockscript.clone(),
+            max_block_signature_size: args.max_block_signature_size,
+            dynamic_epoch_length: args.dynamic_epoch_length,
+            first_extension_space: args.pak.clone(),
+            enforce_pak: args.enforce_pak,
+            dynafed_active: args.dynafed_active,
+        }
+    }
+
+    /// The script verification flags that Elements enforces on this chain.
+    ///
+    /// These are [`VerificationFlags::ALL`], plus [`VerificationFlags::SIGHASH_RANGEPROOF`] if dynamic federations are active.
+    pub fn verification_flags(&self) -> VerificationFlags {
+        if self.dynafed_active {
+            VerificationFlags::ALL | VerificationFlags::SIGHASH_RANGEPROOF
+        } else {
+            VerificationFlags::ALL
+        }
+    }
+
+    /// The parameters that [`verify_pegin`](crate::verify_pegin) checks peg-ins into this chain with.
+    ///
+    /// Peg-ins pay to the P2SH-wrapped P2WSH of [`ChainParams::fedpegscript`], as they did before dynamic federations.
+    /// Once a chain has moved to dynamic federations, the federation of the current epoch has to be set in the returned parameters.
+    pub fn pegin_params(&self) -> PeginParams {
+        PeginParams {
+            parent_genesis_hash: self.parent_genesis_hash,
+            parent_pow_limit: self.parent_pow_limit,
+            pegged_asset: self.policy_asset,
+            fedpeg_program: bitcoin::Script::from(self.fedpegscript.clone())
+                .to_v0_p2wsh()
+                .to_p2sh(),
+            fedpegscript: self.fedpegscript.clone(),
+            min_depth: self.pegin_min_depth,
+        }
+    }
+}
+
+/// Builds the proof of work limit whose `zero_bytes` most significant bytes are zero, followed by `first_byte` and `0xff` for all remaining ones.
+fn pow_limit(first_byte: u8, zero_bytes: usize) -> Uint256 {
+    let mut bytes = [0xff; 32];
+    bytes[..zero_bytes].iter_mut().for_each(|byte| *byte = 0);
+    bytes[zero_bytes] = first_byte;
+
+    Uint256::from_be_bytes(bytes)
+}
+
+/// The commitment to the chain's name and scripts, from which Elements derives its genesis block and pegged asset.
+fn commit_to_arguments(args: &CustomChainArgs) -> Txid {
+    let mut engine = sha256::Hash::engine();
+    engine.input(args.name.as_bytes());
+    engine.input(args.fedpegscript.to_hex().as_bytes());
+    engine.input(args.signblockscript.to_hex().as_bytes());
+
+    Txid::from_inner(sha256::Hash::from_engine(engine).into_inner())
+}
+
+/// Builds the header of the genesis block of an Elements chain with signed blocks, like `CreateGenesisBlock` and `AppendInitialIssuance` do.
+fn genesis_block_header(args: &CustomChainArgs, commit: Txid) -> BlockHeader {
+    let mut transactions = vec![Transaction {
+        version: 1,
+        lock_time: 0,
+        input: vec![TxIn {
+            previous_output: OutPoint::default(),
+            is_pegin: false,
+            has_issuance: false,
+            script_sig: Builder::new().push_slice(&commit[..]).into_script(),
+            sequence: 0xffff_ffff,
+            asset_issuance: AssetIssuance::default(),
+            witness: Default::default(),
+        }],
+        output: vec![TxOut {
+            asset: confidential::Asset::Explicit(AssetId::default()),
+            value: confidential::Value::Explicit(0),
+            script_pubkey: Builder::new().push_opcode(OP_RETURN).into_script(),
+            ..Default::default()
+        }],
+    }];
+
+    if args.initial_free_coins != 0 || args.initial_reissuance_tokens != 0 {
+        let prevout = OutPoint::new(commit, 0);
+        let contract_hash = ContractHash::from_inner(args.parent_genesis_hash.into_inner());
+        let entropy = AssetId::generate_asset_entropy(prevout, contract_hash);
+        let anyone_can_spend = Builder::new().push_opcode(OP_PUSHNUM_1).into_script();
+
+        let mut issuance = Transaction {
+            version: 1,
+            lock_time: 0,
+            input: vec![TxIn {
+                previous_output: prevout,
+                is_pegin: false,
+                has_issuance: true,
+                script_sig: Script::new(),
+                sequence: 0xffff_ffff,
+                asset_issuance: AssetIssuance {
+                    asset_entropy: contract_hash.into_inner(),
+                    amount: confidential::Value::Explicit(args.initial_free_coins),
+                    inflation_keys: confidential::Value::Explicit(args.initial_reissuance_tokens),
+                    ..Default::default()
+                },
+                witness: Default::default(),
+            }],
+            output: Vec::new(),
+        };
+        if args.initial_free_coins != 0 {
+            issuance.output.push(TxOut {
+                asset: confidential::Asset::Explicit(AssetId::from_entropy(entropy)),
+                value: confidential::Value::Explicit(args.initial_free_coins),
+                script_pubkey: anyone_can_spend.clone(),
+                ..Default::default()
+            });
+        }
+        if args.initial_reissuance_tokens != 0 {
+            issuance.output.push(TxOut {
+                asset: confidential::Asset::Explicit(AssetId::reissuance_token_from_entropy(
+                    entropy, false,
+                )),
+                value: confidential::Value::Explicit(args.initial_reissuance_tokens),
+                script_pubkey: anyone_can_spend,
+                ..Default::default()
+            });
+        }
+
+        transactions.push(issuance);
+    }
+
+    let merkle_root = bitcoin::util::hash::bitcoin_merkle_root(
+        transactions
+            .iter()
+            .map(|transaction| transaction.txid().as_hash()),
+    );
+
+    BlockHeader {
+        version: 1,
+        prev_blockhash: BlockHash::default(),
+        merkle_root: TxMerkleNode::from_hash(merkle_root),
+        time: 1_296_688_602,
+        height: 0,
+        ext: BlockExtData::Proof {
+            challenge: Script::from(args.signblockscript.clone()),
+            solution: Script::new(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use elements::bitcoin::blockdata::constants::genesis_block;
+    use elements::bitcoin::Network;
+
+    #[test]
+    fn liquid_matches_elementsd() {
+        let chain = ChainParams::liquid();
+
+        assert_eq!(
+            chain.genesis_hash.to_string(),
+            "1466275836220db2944ca059a3a10ef6fd2ea684b0688d2c379296888a206003"
+        );
+        assert_eq!(
+            chain.policy_asset.to_string(),
+            "6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d"
+        );
+        assert_eq!(
+            chain.parent_genesis_hash,
+            genesis_block(Network::Bitcoin).block_hash()
+        );
+        assert!(chain.dynafed_active);
+    }
+
+    #[test]
+    fn liquid_testnet_matches_elementsd() {
+        let chain = ChainParams::liquid_testnet();
+
+        assert_eq!(
+            chain.genesis_hash.to_string(),
+            "a771da8e52ee6ad581ed1e9a99825e5b3b7992225534eaa2ae23244fe26ab1c1"
+        );
+        assert_eq!(
+            chain.policy_asset.to_string(),
+            "144c654344aa716d6f3abcc1ca90e5641e4e2a7f633bc09fe3baf64585819a49"
+        );
+    }
+
+    #[test]
+    fn elements_regtest_is_pegged_to_bitcoin_regtest() {
+        let chain = ChainParams::elements_regtest();
+
+        assert_eq!(
+            chain.parent_genesis_hash,
+            genesis_block(Network::Regtest).block_hash()
+        );
+        // Regtest blocks have the lowest difficulty, with bits 0x207fffff.
+        assert!(
+            chain.parent_pow_limit >= bitcoin::BlockHeader::u256_from_compact_target(0x207f_ffff)
+        );
+        assert_eq!(chain.pegin_min_depth, 8);
+        assert_ne!(chain.genesis_hash, ChainParams::liquid().genesis_hash);
+    }
+
+    #[test]
+    fn pegin_params_pay_to_p2sh_wrapped_fedpegscript() {
+        let chain = ChainParams::liquid();
+
+        let params = chain.pegin_params();
+
+        assert!(params.fedpeg_program.is_p2sh());
+        assert_eq!(params.pegged_asset, chain.policy_asset);
+        assert_eq!(params.min_depth, 100);
+    }
+}
@@ -0,0 +1,261 @@
+use crate::{ffi, Error};
+use elements::{encode::serialize, Transaction};
+use std::{error::Error as StdError, fmt};
+
+/// The reason why a transaction fails the context-free checks done by `CheckTransaction` in Elements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CheckTransactionViolation {
+    /// The transaction has no inputs.
+    VinEmpty,
+    /// The transaction has no outputs.
+    VoutEmpty,
+    /// The transaction without its witness exceeds the maximum block weight.
+    Oversize,
+    /// The value of an output is neither null, explicit nor a valid commitment.
+    VoutAmountInvalid,
+    /// The explicit value of an output is negative.
+    VoutNegative,
+    /// The explicit value of an output exceeds the maximum amount of money.
+    VoutTooLarge,
+    /// The explicit values of all outputs add up to more than the maximum amount of money.
+    TxOutTotalTooLarge,
+    /// Two inputs spend the same output.
+    InputsDuplicate,
+    /// The `script_sig` of the coinbase input is shorter than 2 or longer than 100 bytes.
+    CoinbaseLength,
+    /// The coinbase transaction has a fee output.
+    CoinbaseFee,
+    /// An input of a transaction that is not a coinbase spends the null outpoint.
+    PrevoutNull,
+    /// The consensus library reported a violation that this crate does not know, or one that it
+    /// does not know itself.
+    Unrecognized(u32),
+}
+
+impl CheckTransactionViolation {
+    /// The reject reason reported by Elements, e.g. `bad-txns-vin-empty`.
+    pub fn reject_reason(&self) -> &'static str {
+        match self {
+            CheckTransactionViolation::VinEmpty => "bad-txns-vin-empty",
+            CheckTransactionViolation::VoutEmpty => "bad-txns-vout-empty",
+            CheckTransactionViolation::Oversize => "bad-txns-oversize",
+            CheckTransactionViolation::VoutAmountInvalid => "bad-txns-vout-amount-invalid",
+            CheckTransactionViolation::VoutNegative => "bad-txns-vout-negative",
+            CheckTransactionViolation::VoutTooLarge => "bad-txns-vout-toolarge",
+            CheckTransactionViolation::TxOutTotalTooLarge => "bad-txns-txouttotal-toolarge",
+            CheckTransactionViolation::InputsDuplicate => "bad-txns-inputs-duplicate",
+            CheckTransactionViolation::CoinbaseLength => "bad-cb-length",
+            CheckTransactionViolation::CoinbaseFee => "bad-cb-fee",
+            CheckTransactionViolation::PrevoutNull => "bad-txns-prevout-null",
+            CheckTransactionViolation::Unrecognized(_) => "unrecognized",
+        }
+    }
+
+    pub(crate) fn from_raw(code: u32) -> Self {
+        match code {
+            elements_consensus_sys::bitcoinconsensus_TX_CHECK_ERR_VIN_EMPTY => {
+                CheckTransactionViolation::VinEmpty
+            }
+            elements_consensus_sys::bitcoinconsensus_TX_CHECK_ERR_VOUT_EMPTY => {
+                CheckTransactionViolation::VoutEmpty
+            }
+            elements_consensus_sys::bitcoinconsensus_TX_CHECK_ERR_OVERSIZE => {
+                CheckTransactionViolation::Oversize
+            }
+            elements_consensus_sys::bitcoinconsensus_TX_CHECK_ERR_VOUT_AMOUNT_INVALID => {
+                CheckTransactionViolation::VoutAmountInvalid
+            }
+            elements_consensus_sys::bitcoinconsensus_TX_CHECK_ERR_VOUT_NEGATIVE => {
+                CheckTransactionViolation::VoutNegative
+            }
+            elements_consensus_sys::bitcoinconsensus_TX_CHECK_ERR_VOUT_TOOLARGE => {
+                CheckTransactionViolation::VoutTooLarge
+            }
+            elements_consensus_sys::bitcoinconsensus_TX_CHECK_ERR_TXOUTTOTAL_TOOLARGE => {
+                CheckTransactionViolation::TxOutTotalTooLarge
+            }
+            elements_consensus_sys::bitcoinconsensus_TX_CHECK_ERR_INPUTS_DUPLICATE => {
+                CheckTransactionViolation::InputsDuplicate
+            }
+            elements_consensus_sys::bitcoinconsensus_TX_CHECK_ERR_CB_LENGTH => {
+                CheckTransactionViolation::CoinbaseLength
+            }
+            elements_consensus_sys::bitcoinconsensus_TX_CHECK_ERR_CB_FEE => {
+                CheckTransactionViolation::CoinbaseFee
+            }
+            elements_consensus_sys::bitcoinconsensus_TX_CHECK_ERR_PREVOUT_NULL => {
+                CheckTransactionViolation::PrevoutNull
+            }
+            code => CheckTransactionViolation::Unrecognized(code),
+        }
+    }
+}
+
+impl fmt::Display for CheckTransactionViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.reject_reason())
+    }
+}
+
+impl StdError for CheckTransactionViolation {}
+
+/// Checks that `transaction` is well-formed, as done by Elements for every transaction before looking at its inputs.
+///
+/// These checks don't depend on the spent outputs or the chain, so they don't cover scripts, amounts or proofs.
+///
+/// Like [`verify`](crate::verify), this function returns two layers of errors.
+/// The outer one is only reported if the consensus library fails to process the transaction.
+pub fn check_transaction(
+    transaction: &Transaction,
+) -> Result<Result<(), CheckTransactionViolation>, Error> {
+    let transaction = serialize(transaction);
+
+    let mut tx_check_err = 0;
+
+    let checked = ffi::call(|err| unsafe {
+        elements_consensus_sys::bitcoinconsensus_check_transaction(
+            transaction.as_ptr(),
+            transaction.len() as u32,
+            err,
+            &mut tx_check_err,
+        )
+    })?;
+
+    if checked {
+        Ok(Ok(()))
+    } else {
+        Ok(Err(CheckTransactionViolation::from_raw(tx_check_err)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use elements::hashes::Hash;
+    use elements::issuance::AssetId;
+    use elements::{confidential, AssetIssuance, OutPoint, Script, TxIn, TxOut, Txid};
+
+    const MAX_MONEY: u64 = 21_000_000 * 100_000_000;
+
+    fn input(vout: u32) -> TxIn {
+        TxIn {
+            previous_output: OutPoint {
+                txid: Txid::from_inner([1; 32]),
+                vout,
+            },
+            is_pegin: false,
+            has_issuance: false,
+            script_sig: Script::new(),
+            sequence: 0xffff_ffff,
+            asset_issuance: AssetIssuance::default(),
+            witness: Default::default(),
+        }
+    }
+
+    fn output(value: u64) -> TxOut {
+        TxOut {
+            asset: confidential::Asset::Explicit(AssetId::from_slice(&[1; 32]).unwrap()),
+            value: confidential::Value::Explicit(value),
+            script_pubkey: Script::from(vec![0x51]),
+            ..Default::default()
+        }
+    }
+
+    fn transaction(input: Vec<TxIn>, output: Vec<TxOut>) -> Transaction {
+        Transaction {
+            version: 2,
+            lock_time: 0,
+            input,
+            output,
+        }
+    }
+
+    #[test]
+    fn well_formed_transaction_passes() {
+        check_transaction(&transaction(vec![input(0), input(1)], vec![output(1_000)]))
+            .unwrap()
+            .unwrap();
+    }
+
+    #[test]
+    fn malformed_transactions_are_rejected_with_reason() {
+        let mut coinbase_input = input(0);
+        coinbase_input.previous_output = OutPoint::default();
+        coinbase_input.script_sig = Script::from(vec![0x51]);
+        let mut oversized_output = output(1_000);
+        oversized_output.script_pubkey = Script::from(vec![0x6a; 1_000_000]);
+
+        let cases = vec![
+            (
+                transaction(vec![], vec![output(1_000)]),
+                CheckTransactionViolation::VinEmpty,
+            ),
+            (
+                transaction(vec![input(0)], vec![]),
+                CheckTransactionViolation::VoutEmpty,
+            ),
+            (
+                transaction(vec![input(0)], vec![oversized_output]),
+                CheckTransactionViolation::Oversize,
+            ),
+            (
+                transaction(vec![input(0)], vec![output(u64::MAX)]),
+                CheckTransactionViolation::VoutNegative,
+            ),
+            (
+                transaction(vec![input(0)], vec![output(MAX_MONEY + 1)]),
+                CheckTransactionViolation::VoutTooLarge,
+            ),
+            (
+                transaction(vec![input(0)], vec![output(MAX_MONEY), output(1)]),
+                CheckTransactionViolation::TxOutTotalTooLarge,
+            ),
+            (
+                transaction(vec![input(0), input(0)], vec![output(1_000)]),
+                CheckTransactionViolation::InputsDuplicate,
+            ),
+            (
+                transaction(vec![coinbase_input.clone()], vec![output(1_000)]),
+                CheckTransactionViolation::CoinbaseLength,
+            ),
+            (
+                transaction(
+                    vec![TxIn {
+                        script_sig: Script::from(vec![0x51, 0x51]),
+                        ..coinbase_input.clone()
+                    }],
+                    vec![TxOut::new_fee(
+                        1_000,
+                        AssetId::from_slice(&[1; 32]).unwrap(),
+                    )],
+                ),
+                CheckTransactionViolation::CoinbaseFee,
+            ),
+            (
+                transaction(vec![input(0), coinbase_input], vec![output(1_000)]),
+                CheckTransactionViolation::PrevoutNull,
+            ),
+        ];
+
+        for (transaction, violation) in cases {
+            assert_eq!(check_transaction(&transaction), Ok(Err(violation)));
+        }
+    }
+
+    #[test]
+    fn violation_displays_reject_reason() {
+        assert_eq!(
+            CheckTransactionViolation::InputsDuplicate.to_string(),
+            "bad-txns-inputs-duplicate"
+        );
+    }
+
+    #[test]
+    fn unknown_reason_is_unrecognized() {
+        let code = elements_consensus_sys::bitcoinconsensus_TX_CHECK_ERR_UNKNOWN;
+        assert_eq!(
+            CheckTransactionViolation::from_raw(code),
+            CheckTransactionViolation::Unrecognized(code)
+        );
+    }
+}
@@ -0,0 +1,611 @@
+use crate::{ffi, ChainParams, Error};
+use elements::dynafed::Params;
+use elements::hashes::sha256;
+use elements::{encode::serialize, BlockExtData, BlockHeader, Script};
+use std::{collections::HashMap, error::Error as StdError, fmt};
+
+/// The reason why the dynamic federation parameters of a block header are invalid.
+///
+/// Elements rejects all of them as `invalid-dyna-fed`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DynafedViolation {
+    /// The header has no current parameters.
+    CurrentNull,
+    /// The header version sets bits that are reserved for future hard forks.
+    ExtensionBits,
+    /// The current parameters of the header are not the ones that the previous blocks imply.
+    CurrentMismatch,
+    /// The header proposes a new `signblockscript` that is not a native segwit scriptPubKey.
+    Signblockscript,
+    /// The header proposes a new fedpeg program that is not a native segwit scriptPubKey.
+    FedpegProgram,
+    /// The header proposes a new v0 fedpeg program that is not the P2WSH of the proposed fedpegscript.
+    Fedpegscript,
+    /// The header proposes a new v0 fedpegscript that starts with `OP_DEPTH`, like the first Liquid watchman script.
+    FedpegscriptOpDepth,
+    /// The header proposes a new extension space that is not a list of PAK entries, although the chain enforces them.
+    ExtensionSpace,
+    /// The consensus library reported a violation that this crate does not know.
+    Unrecognized(u32),
+}
+
+impl DynafedViolation {
+    fn from_raw(code: u32) -> Self {
+        match code {
+            elements_consensus_sys::bitcoinconsensus_DYNAFED_ERR_CURRENT_NULL => {
+                DynafedViolation::CurrentNull
+            }
+            elements_consensus_sys::bitcoinconsensus_DYNAFED_ERR_EXTENSION_BITS => {
+                DynafedViolation::ExtensionBits
+            }
+            elements_consensus_sys::bitcoinconsensus_DYNAFED_ERR_CURRENT_MISMATCH => {
+                DynafedViolation::CurrentMismatch
+            }
+            elements_consensus_sys::bitcoinconsensus_DYNAFED_ERR_SIGNBLOCKSCRIPT => {
+                DynafedViolation::Signblockscript
+            }
+            elements_consensus_sys::bitcoinconsensus_DYNAFED_ERR_FEDPEG_PROGRAM => {
+                DynafedViolation::FedpegProgram
+            }
+            elements_consensus_sys::bitcoinconsensus_DYNAFED_ERR_FEDPEGSCRIPT => {
+                DynafedViolation::Fedpegscript
+            }
+            elements_consensus_sys::bitcoinconsensus_DYNAFED_ERR_FEDPEGSCRIPT_OP_DEPTH => {
+                DynafedViolation::FedpegscriptOpDepth
+            }
+            elements_consensus_sys::bitcoinconsensus_DYNAFED_ERR_EXTENSION_SPACE => {
+                DynafedViolation::ExtensionSpace
+            }
+            code => DynafedViolation::Unrecognized(code),
+        }
+    }
+
+    /// The reject reason reported by Elements, which is `invalid-dyna-fed` for all of them.
+    pub fn reject_reason(&self) -> &'static str {
+        match self {
+            DynafedViolation::Unrecognized(_) => "unrecognized",
+            _ => "invalid-dyna-fed",
+        }
+    }
+}
+
+impl fmt::Display for DynafedViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DynafedViolation::CurrentNull => write!(
+                f,
+                "dynamic block headers must have non-empty current signblockscript field"
+            ),
+            DynafedViolation::ExtensionBits => {
+                write!(f, "dynamic block header has unknown HF extension bits set")
+            }
+            DynafedViolation::CurrentMismatch => write!(
+                f,
+                "dynamic block header's current parameters do not match expected"
+            ),
+            DynafedViolation::Signblockscript => write!(
+                f,
+                "proposed signblockscript must be native segwit scriptPubkey"
+            ),
+            DynafedViolation::FedpegProgram => write!(
+                f,
+                "proposed fedpeg program must be native segwit scriptPubkey"
+            ),
+            DynafedViolation::Fedpegscript => write!(
+                f,
+                "proposed v0 segwit fedpegscript must match proposed fedpeg witness program"
+            ),
+            DynafedViolation::FedpegscriptOpDepth => write!(
+                f,
+                "Proposed fedpegscript starts with OP_DEPTH, which is illegal"
+            ),
+            DynafedViolation::ExtensionSpace => {
+                write!(f, "Extension space is not list of valid PAK entries")
+            }
+            DynafedViolation::Unrecognized(code) => {
+                write!(f, "unrecognized dynamic federation violation {}", code)
+            }
+        }
+    }
+}
+
+impl StdError for DynafedViolation {}
+
+/// The dynamic federation parameters that the block after a sequence of headers has to publish and is checked against.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NextBlockParams {
+    /// The current parameters that the block has to publish, in full at the start of an epoch and compact otherwise.
+    pub current: Params,
+    /// The full parameters in effect for the block, which proposals are compared with.
+    pub full: Params,
+    /// Whether the block starts an epoch with parameters that the previous epoch voted for.
+    pub transition: bool,
+}
+
+/// Computes the root that a block header commits to for `params`, as done by the consensus library.
+///
+/// The root of [`Params::Null`] is zero. Votes for proposed parameters are tallied by this root.
+pub fn params_root(params: &Params) -> Result<sha256::Midstate, Error> {
+    roots(params).map(|(root, _)| root)
+}
+
+/// Computes the root of `params` and the root of its fedpeg program, fedpegscript and extension space, which compact parameters carry as their elided root.
+fn roots(params: &Params) -> Result<(sha256::Midstate, sha256::Midstate), Error> {
+    let params = serialize(params);
+
+    let mut root = [0u8; 32];
+    let mut extra_root = [0u8; 32];
+
+    let computed = ffi::call(|err| unsafe {
+        elements_consensus_sys::bitcoinconsensus_dynafed_params_root(
+            params.as_ptr(),
+            params.len() as u32,
+            root.as_mut_ptr(),
+            extra_root.as_mut_ptr(),
+            err,
+        )
+    })?;
+
+    if !computed {
+        return Err(Error::NoResult);
+    }
+
+    Ok((
+        sha256::Midstate::from_inner(root),
+        sha256::Midstate::from_inner(extra_root),
+    ))
+}
+
+/// Returns the current and proposed parameters of `header`, or `None` if it has none like the headers before dynamic federations.
+fn header_params(header: &BlockHeader) -> Option<(&Params, &Params)> {
+    match &header.ext {
+        BlockExtData::Dynafed {
+            current, proposed, ..
+        } if !(current.is_null() && proposed.is_null()) => Some((current, proposed)),
+        _ => None,
+    }
+}
+
+/// Computes the dynamic federation parameters of the block following `headers`, like `ComputeNextBlockCurrentParameters` in Elements.
+///
+/// `headers` are consecutive headers of the chain ending with the previous block.
+/// They have to reach back to the start of the current epoch of [`ChainParams::dynamic_epoch_length`] blocks, or of the previous epoch if the next block starts a new one, which is when the votes of the previous epoch are tallied.
+/// If the epoch started before the chain moved to dynamic federations, its full parameters are derived from the challenge of its first header and the fedpegscript, signature limit and first extension space of `chain`.
+///
+/// Returns `None` if `headers` is empty, does not reach back far enough or is not a chain.
+pub fn next_block_params(
+    headers: &[BlockHeader],
+    chain: &ChainParams,
+) -> Result<Option<NextBlockParams>, Error> {
+    let (first, previous) = match (headers.first(), headers.last()) {
+        (Some(first), Some(previous)) => (first, previous),
+        _ => return Ok(None),
+    };
+    let is_chain = headers.windows(2).all(|pair| {
+        pair[1].height == pair[0].height + 1 && pair[1].prev_blockhash == pair[0].block_hash()
+    });
+    if !is_chain {
+        return Ok(None);
+    }
+
+    let epoch_length = chain.dynamic_epoch_length;
+    let next_height = previous.height + 1;
+    let epoch_age = match next_height.checked_rem(epoch_length) {
+        Some(epoch_age) => epoch_age,
+        None => return Ok(None),
+    };
+    let epoch_start_height = if epoch_age == 0 {
+        next_height - epoch_length
+    } else {
+        next_height - epoch_age
+    };
+    if epoch_start_height < first.height {
+        return Ok(None);
+    }
+    let header_at = |height: u32| &headers[(height - first.height) as usize];
+
+    if epoch_age == 0 {
+        if let Some(winner) = tally_votes(headers, epoch_length)? {
+            return Ok(Some(NextBlockParams {
+                current: winner.clone(),
+                full: winner,
+                transition: true,
+            }));
+        }
+    }
+
+    let epoch_start = header_at(epoch_start_height);
+    let full = match (header_params(epoch_start), &epoch_start.ext) {
+        (Some((current, _)), _) => current.clone(),
+        (None, BlockExtData::Proof { challenge, .. }) => Params::Full {
+            signblockscript: challenge.to_v0_p2wsh(),
+            signblock_witness_limit: chain.max_block_signature_size,
+            fedpeg_program: Script::from(chain.fedpegscript.clone())
+                .to_v0_p2wsh()
+                .to_p2sh(),
+            fedpegscript: chain.fedpegscript.clone(),
+            extension_space: chain.first_extension_space.clone(),
+        },
+        // Dynamic federation parameters that are null in both entries
+        (None, BlockExtData::Dynafed { .. }) => Params::Null,
+    };
+
+    let current = if epoch_age == 0 || header_params(previous).is_none() {
+        full.clone()
+    } else {
+        match &full {
+            Params::Full {
+                signblockscript,
+                signblock_witness_limit,
+                ..
+            } => Params::Compact {
+                signblockscript: signblockscript.clone(),
+                signblock_witness_limit: *signblock_witness_limit,
+                elided_root: roots(&full)?.1,
+            },
+            _ => full.clone(),
+        }
+    };
+
+    Ok(Some(NextBlockParams {
+        current,
+        full,
+        transition: false,
+    }))
+}
+
+/// Tallies the proposals of the last `epoch_length` of `headers`, returning the first that reaches four fifths of them when walking back from the last header.
+fn tally_votes(headers: &[BlockHeader], epoch_length: u32) -> Result<Option<Params>, Error> {
+    let threshold = epoch_length * 4 / 5;
+    let mut tally = HashMap::new();
+
+    for header in headers.iter().rev().take(epoch_length as usize) {
+        let proposal = match header_params(header) {
+            Some((_, proposal)) => proposal,
+            None => continue,
+        };
+        if proposal.is_null() {
+            continue;
+        }
+
+        let votes = tally.entry(params_root(proposal)?).or_insert(0);
+        *votes += 1;
+        if *votes >= threshold {
+            return Ok(Some(proposal.clone()));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Verifies the dynamic federation parameters of `header` against `expected`, the parameters that [`next_block_params`] computes from the previous headers, like `ContextualCheckDynaFedHeader` in Elements.
+///
+/// The header has to publish `expected.current`. Proposed parameters that differ from `expected.full` must use native segwit scripts, a v0 fedpeg program must be the P2WSH of the proposed fedpegscript, and a proposed extension space must be a list of PAK entries if [`ChainParams::enforce_pak`] is set.
+/// The signature of the header is verified by [`verify_block_header`](crate::verify_block_header).
+///
+/// Like [`verify`](crate::verify), this function returns two layers of errors.
+pub fn verify_dynafed_params(
+    header: &BlockHeader,
+    expected: &NextBlockParams,
+    chain: &ChainParams,
+) -> Result<Result<(), DynafedViolation>, Error> {
+    let header = serialize(header);
+    let expected_current = serialize(&expected.current);
+    let full_current = serialize(&expected.full);
+
+    let mut dynafed_err = 0;
+
+    let verified = ffi::call(|err| unsafe {
+        elements_consensus_sys::bitcoinconsensus_verify_dynafed_params(
+            header.as_ptr(),
+            header.len() as u32,
+            expected_current.as_ptr(),
+            expected_current.len() as u32,
+            full_current.as_ptr(),
+            full_current.len() as u32,
+            chain.enforce_pak as i32,
+            err,
+            &mut dynafed_err,
+        )
+    })?;
+
+    if verified {
+        Ok(Ok(()))
+    } else {
+        Ok(Err(DynafedViolation::from_raw(dynafed_err)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use elements::hashes::{hex::FromHex, Hash};
+    use elements::opcodes::all::{OP_CHECKSIG, OP_DEPTH};
+    use elements::script::Builder;
+    use elements::{BlockHash, TxMerkleNode};
+
+    fn chain() -> ChainParams {
+        ChainParams {
+            dynamic_epoch_length: 10,
+            ..ChainParams::elements_regtest()
+        }
+    }
+
+    fn fedpegscript(key: u8) -> Vec<u8> {
+        Builder::new()
+            .push_slice(&[key; 33])
+            .push_opcode(OP_CHECKSIG)
+            .into_script()
+            .into_bytes()
+    }
+
+    fn full_params(key: u8) -> Params {
+        Params::Full {
+            signblockscript: Script::from(vec![key]).to_v0_p2wsh(),
+            signblock_witness_limit: 150,
+            fedpeg_program: Script::from(fedpegscript(key)).to_v0_p2wsh(),
+            fedpegscript: fedpegscript(key),
+            extension_space: chain().first_extension_space,
+        }
+    }
+
+    fn header(prev_blockhash: BlockHash, height: u32, ext: BlockExtData) -> BlockHeader {
+        BlockHeader {
+            version: 0x2000_0000,
+            prev_blockhash,
+            merkle_root: TxMerkleNode::from_inner([2; 32]),
+            time: 1_600_000_000 + height,
+            height,
+            ext,
+        }
+    }
+
+    fn dynafed_header(current: Params, proposed: Params) -> BlockHeader {
+        header(
+            BlockHash::from_inner([1; 32]),
+            42,
+            BlockExtData::Dynafed {
+                current,
+                proposed,
+                signblock_witness: vec![],
+            },
+        )
+    }
+
+    /// Extends `headers` by `count` dynafed headers that publish the parameters that [`next_block_params`] expects and propose `proposed`.
+    fn extend(headers: &mut Vec<BlockHeader>, count: usize, proposed: &Params) {
+        for _ in 0..count {
+            let previous = headers.last().unwrap();
+            let expected = next_block_params(headers, &chain()).unwrap().unwrap();
+            let next = header(
+                previous.block_hash(),
+                previous.height + 1,
+                BlockExtData::Dynafed {
+                    current: expected.current,
+                    proposed: proposed.clone(),
+                    signblock_witness: vec![],
+                },
+            );
+            headers.push(next);
+        }
+    }
+
+    /// Pre-dynafed headers up to height 9, so that the next block starts an epoch.
+    fn legacy_headers() -> Vec<BlockHeader> {
+        let mut headers: Vec<BlockHeader> = Vec::new();
+        for height in 0..10 {
+            let prev_blockhash = headers
+                .last()
+                .map(BlockHeader::block_hash)
+                .unwrap_or_default();
+            headers.push(header(
+                prev_blockhash,
+                height,
+                BlockExtData::Proof {
+                    challenge: Script::from(chain().signblockscript),
+                    solution: Script::new(),
+                },
+            ));
+        }
+
+        headers
+    }
+
+    #[test]
+    fn params_root_matches_rust_elements() {
+        let params = full_params(1);
+
+        assert_eq!(params_root(&params).unwrap(), params.calculate_root());
+        assert_eq!(
+            params_root(&params.clone().into_compact().unwrap()).unwrap(),
+            params.calculate_root()
+        );
+        assert_eq!(
+            params_root(&Params::Null).unwrap(),
+            sha256::Midstate::default()
+        );
+    }
+
+    #[test]
+    fn first_dynafed_params_are_derived_from_legacy_chain() {
+        let headers = legacy_headers();
+
+        let expected = next_block_params(&headers, &chain()).unwrap().unwrap();
+
+        assert!(!expected.transition);
+        assert_eq!(expected.current, expected.full);
+        assert_eq!(
+            expected.full.signblockscript(),
+            Some(&Script::from(chain().signblockscript).to_v0_p2wsh())
+        );
+        assert!(expected.full.fedpeg_program().unwrap().is_p2sh());
+        assert_eq!(
+            expected.full.extension_space(),
+            Some(&chain().first_extension_space)
+        );
+    }
+
+    #[test]
+    fn mid_epoch_current_params_are_compact() {
+        let mut headers = legacy_headers();
+        extend(&mut headers, 3, &Params::Null);
+
+        let expected = next_block_params(&headers, &chain()).unwrap().unwrap();
+
+        assert!(expected.current.is_compact());
+        assert_eq!(
+            params_root(&expected.current).unwrap(),
+            params_root(&expected.full).unwrap()
+        );
+    }
+
+    #[test]
+    fn proposal_with_four_fifths_of_votes_takes_effect() {
+        let mut headers = legacy_headers();
+        extend(&mut headers, 2, &Params::Null);
+        extend(&mut headers, 8, &full_params(1));
+
+        let expected = next_block_params(&headers, &chain()).unwrap().unwrap();
+
+        assert!(expected.transition);
+        assert_eq!(expected.current, full_params(1));
+        assert_eq!(expected.full, full_params(1));
+    }
+
+    #[test]
+    fn proposal_below_four_fifths_of_votes_does_not_take_effect() {
+        let mut headers = legacy_headers();
+        extend(&mut headers, 3, &Params::Null);
+        extend(&mut headers, 7, &full_params(1));
+
+        let expected = next_block_params(&headers, &chain()).unwrap().unwrap();
+
+        assert!(!expected.transition);
+        assert_ne!(expected.full, full_params(1));
+        assert!(expected.current.is_full());
+    }
+
+    #[test]
+    fn headers_not_reaching_epoch_start_give_none() {
+        let mut headers = legacy_headers();
+        extend(&mut headers, 3, &Params::Null);
+
+        assert_eq!(next_block_params(&headers[11..], &chain()).unwrap(), None);
+    }
+
+    #[test]
+    fn header_with_expected_params_verifies() {
+        let mut headers = legacy_headers();
+        extend(&mut headers, 1, &full_params(1));
+        let expected = next_block_params(&headers[..10], &chain())
+            .unwrap()
+            .unwrap();
+
+        verify_dynafed_params(&headers[10], &expected, &chain())
+            .unwrap()
+            .unwrap();
+    }
+
+    #[test]
+    fn header_with_other_current_params_does_not_verify() {
+        let expected = NextBlockParams {
+            current: full_params(1),
+            full: full_params(1),
+            transition: false,
+        };
+        let header = dynafed_header(full_params(2), Params::Null);
+
+        let violation = verify_dynafed_params(&header, &expected, &chain())
+            .unwrap()
+            .unwrap_err();
+
+        assert_eq!(violation, DynafedViolation::CurrentMismatch);
+        assert_eq!(violation.reject_reason(), "invalid-dyna-fed");
+    }
+
+    /// Verifies a header that publishes [`full_params`] and proposes `proposed`.
+    fn verify_proposal(proposed: Params, chain: &ChainParams) -> Result<(), DynafedViolation> {
+        let expected = NextBlockParams {
+            current: full_params(1),
+            full: full_params(1),
+            transition: false,
+        };
+        let header = dynafed_header(full_params(1), proposed);
+
+        verify_dynafed_params(&header, &expected, chain).unwrap()
+    }
+
+    #[test]
+    fn proposal_with_legacy_signblockscript_does_not_verify() {
+        let mut proposed = full_params(2);
+        if let Params::Full {
+            signblockscript, ..
+        } = &mut proposed
+        {
+            *signblockscript = Script::from(vec![2]);
+        }
+
+        assert_eq!(
+            verify_proposal(proposed, &chain()),
+            Err(DynafedViolation::Signblockscript)
+        );
+    }
+
+    #[test]
+    fn proposal_with_other_fedpegscript_does_not_verify() {
+        let mut proposed = full_params(2);
+        if let Params::Full { fedpegscript, .. } = &mut proposed {
+            *fedpegscript = self::fedpegscript(3);
+        }
+
+        assert_eq!(
+            verify_proposal(proposed, &chain()),
+            Err(DynafedViolation::Fedpegscript)
+        );
+    }
+
+    #[test]
+    fn proposal_with_op_depth_fedpegscript_does_not_verify() {
+        let script = Builder::new()
+            .push_opcode(OP_DEPTH)
+            .push_slice(&[2; 33])
+            .push_opcode(OP_CHECKSIG)
+            .into_script();
+        let mut proposed = full_params(2);
+        if let Params::Full {
+            fedpeg_program,
+            fedpegscript,
+            ..
+        } = &mut proposed
+        {
+            *fedpeg_program = script.to_v0_p2wsh();
+            *fedpegscript = script.into_bytes();
+        }
+
+        assert_eq!(
+            verify_proposal(proposed, &chain()),
+            Err(DynafedViolation::FedpegscriptOpDepth)
+        );
+    }
+
+    #[test]
+    fn proposal_with_invalid_pak_list_only_fails_when_enforced() {
+        let mut proposed = full_params(2);
+        if let Params::Full {
+            extension_space, ..
+        } = &mut proposed
+        {
+            *extension_space = vec![Vec::from_hex("deadbeef").unwrap()];
+        }
+        let enforcing = ChainParams {
+            enforce_pak: true,
+            ..chain()
+        };
+
+        assert_eq!(verify_proposal(proposed.clone(), &chain()), Ok(()));
+        assert_eq!(
+            verify_proposal(proposed, &enforcing),
+            Err(DynafedViolation::ExtensionSpace)
+        );
+    }
+}
@@ -0,0 +1,233 @@
+use crate::Error;
+use elements_consensus_sys::bitcoinconsensus_error;
+use std::os::raw::c_int;
+use std::ptr::NonNull;
+
+/// Makes a call into the consensus library, which reports its errors through the `err` out-parameter passed to `call`.
+///
+/// Returns `Ok(true)` if the call succeeded and `Ok(false)` if the arguments violate a rule, whose details the call reports through its other out-parameters.
+/// Errors are converted with [`Error::from_raw`], so a code unknown to this crate is passed on instead of causing a panic.
+pub(crate) fn call<F>(call: F) -> Result<bool, Error>
+where
+    F: FnOnce(&mut bitcoinconsensus_error) -> c_int,
+{
+    let mut err = elements_consensus_sys::bitcoinconsensus_error_t_bitcoinconsensus_ERR_OK;
+
+    let ret = call(&mut err);
+
+    if ret == 1 {
+        return Ok(true);
+    }
+
+    // if ret != 1, err is set
+    match err {
+        elements_consensus_sys::bitcoinconsensus_error_t_bitcoinconsensus_ERR_OK => Ok(false),
+        e => Err(Error::from_raw(e)),
+    }
+}
+
+/// Makes a call into the consensus library that creates an object, returning a null pointer and setting the `err` out-parameter passed to `call` on failure.
+pub(crate) fn create<T, F>(create: F) -> Result<NonNull<T>, Error>
+where
+    F: FnOnce(&mut bitcoinconsensus_error) -> *mut T,
+{
+    let mut err = elements_consensus_sys::bitcoinconsensus_error_t_bitcoinconsensus_ERR_OK;
+
+    let object = create(&mut err);
+
+    NonNull::new(object).ok_or_else(|| Error::from_raw(err))
+}
+
+impl Error {
+    /// Converts the raw `bitcoinconsensus_error` reported by the consensus library.
+    ///
+    /// `bitcoinconsensus_ERR_OK` does not describe an error, so the library failing with it is reported as [`Error::NoResult`].
+    pub(crate) fn from_raw(code: bitcoinconsensus_error) -> Self {
+        match code {
+            elements_consensus_sys::bitcoinconsensus_error_t_bitcoinconsensus_ERR_OK => {
+                Error::NoResult
+            }
+            elements_consensus_sys::bitcoinconsensus_error_t_bitcoinconsensus_ERR_TX_INDEX => {
+                Error::IndexOutOfBounds
+            }
+            elements_consensus_sys::bitcoinconsensus_error_t_bitcoinconsensus_ERR_TX_SIZE_MISMATCH => {
+                Error::TxSizeMismatch
+            }
+            elements_consensus_sys::bitcoinconsensus_error_t_bitcoinconsensus_ERR_TX_DESERIALIZE => {
+                Error::TxDeserialize
+            }
+            elements_consensus_sys::bitcoinconsensus_error_t_bitcoinconsensus_ERR_AMOUNT_REQUIRED => {
+                Error::AmountRequired
+            }
+            elements_consensus_sys::bitcoinconsensus_error_t_bitcoinconsensus_ERR_INVALID_FLAGS => {
+                Error::InvalidFlags
+            }
+            elements_consensus_sys::bitcoinconsensus_error_t_bitcoinconsensus_ERR_SPENT_OUTPUTS_REQUIRED => {
+                Error::SpentOutputsRequired
+            }
+            elements_consensus_sys::bitcoinconsensus_error_t_bitcoinconsensus_ERR_SPENT_OUTPUTS_MISMATCH => {
+                Error::SpentOutputsMismatch
+            }
+            elements_consensus_sys::bitcoinconsensus_error_t_bitcoinconsensus_ERR_INVALID_SIGHASH => {
+                Error::InvalidSigHash
+            }
+            elements_consensus_sys::bitcoinconsensus_error_t_bitcoinconsensus_ERR_TX_COINBASE => {
+                Error::Coinbase
+            }
+            elements_consensus_sys::bitcoinconsensus_error_t_bitcoinconsensus_ERR_OUTPUT_PROOFS_MISMATCH => {
+                Error::OutputProofsMismatch
+            }
+            elements_consensus_sys::bitcoinconsensus_error_t_bitcoinconsensus_ERR_TX_NOT_PEGIN => {
+                Error::NotPegin
+            }
+            elements_consensus_sys::bitcoinconsensus_error_t_bitcoinconsensus_ERR_TX_NO_ISSUANCE => {
+                Error::NoIssuance
+            }
+            elements_consensus_sys::bitcoinconsensus_error_t_bitcoinconsensus_ERR_EXCEPTION => {
+                Error::Exception
+            }
+            code => Error::Unknown(code),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ERRORS: [(bitcoinconsensus_error, Error); 13] = [
+        (
+            elements_consensus_sys::bitcoinconsensus_error_t_bitcoinconsensus_ERR_TX_INDEX,
+            Error::IndexOutOfBounds,
+        ),
+        (
+            elements_consensus_sys::bitcoinconsensus_error_t_bitcoinconsensus_ERR_TX_SIZE_MISMATCH,
+            Error::TxSizeMismatch,
+        ),
+        (
+            elements_consensus_sys::bitcoinconsensus_error_t_bitcoinconsensus_ERR_TX_DESERIALIZE,
+            Error::TxDeserialize,
+        ),
+        (
+            elements_consensus_sys::bitcoinconsensus_error_t_bitcoinconsensus_ERR_AMOUNT_REQUIRED,
+            Error::AmountRequired,
+        ),
+        (
+            elements_consensus_sys::bitcoinconsensus_error_t_bitcoinconsensus_ERR_INVALID_FLAGS,
+            Error::InvalidFlags,
+        ),
+        (
+            elements_consensus_sys::bitcoinconsensus_error_t_bitcoinconsensus_ERR_SPENT_OUTPUTS_REQUIRED,
+            Error::SpentOutputsRequired,
+        ),
+        (
+            elements_consensus_sys::bitcoinconsensus_error_t_bitcoinconsensus_ERR_SPENT_OUTPUTS_MISMATCH,
+            Error::SpentOutputsMismatch,
+        ),
+        (
+            elements_consensus_sys::bitcoinconsensus_error_t_bitcoinconsensus_ERR_INVALID_SIGHASH,
+            Error::InvalidSigHash,
+        ),
+        (
+            elements_consensus_sys::bitcoinconsensus_error_t_bitcoinconsensus_ERR_TX_COINBASE,
+            Error::Coinbase,
+        ),
+        (
+            elements_consensus_sys::bitcoinconsensus_error_t_bitcoinconsensus_ERR_OUTPUT_PROOFS_MISMATCH,
+            Error::OutputProofsMismatch,
+        ),
+        (
+            elements_consensus_sys::bitcoinconsensus_error_t_bitcoinconsensus_ERR_TX_NOT_PEGIN,
+            Error::NotPegin,
+        ),
+        (
+            elements_consensus_sys::bitcoinconsensus_error_t_bitcoinconsensus_ERR_TX_NO_ISSUANCE,
+            Error::NoIssuance,
+        ),
+        (
+            elements_consensus_sys::bitcoinconsensus_error_t_bitcoinconsensus_ERR_EXCEPTION,
+            Error::Exception,
+        ),
+    ];
+
+    /// Stands in for a function of the consensus library that fails with `code`.
+    fn failing_with(
+        code: bitcoinconsensus_error,
+    ) -> impl FnOnce(&mut bitcoinconsensus_error) -> c_int {
+        move |err| {
+            *err = code;
+            0
+        }
+    }
+
+    #[test]
+    fn every_library_error_is_converted() {
+        for &(code, error) in ERRORS.iter() {
+            assert_eq!(call(failing_with(code)), Err(error));
+            assert_eq!(
+                create::<u8, _>(|err| {
+                    *err = code;
+                    std::ptr::null_mut()
+                }),
+                Err(error)
+            );
+        }
+    }
+
+    #[test]
+    fn unknown_library_error_is_passed_on() {
+        assert_eq!(call(failing_with(42)), Err(Error::Unknown(42)));
+    }
+
+    #[test]
+    fn creation_failure_without_error_has_no_result() {
+        assert_eq!(
+            create::<u8, _>(|_| std::ptr::null_mut()),
+            Err(Error::NoResult)
+        );
+    }
+
+    #[test]
+    fn failure_without_error_is_a_violation() {
+        assert_eq!(
+            call(failing_with(
+                elements_consensus_sys::bitcoinconsensus_error_t_bitcoinconsensus_ERR_OK
+            )),
+            Ok(false)
+        );
+    }
+
+    #[test]
+    fn success_ignores_error() {
+        assert_eq!(
+            call(|err| {
+                *err =
+                    elements_consensus_sys::bitcoinconsensus_error_t_bitcoinconsensus_ERR_TX_INDEX;
+                1
+            }),
+            Ok(true)
+        );
+
+        let mut object = 0u8;
+        let pointer = &mut object as *mut u8;
+        assert_eq!(create(|_| pointer).unwrap().as_ptr(), pointer);
+    }
+
+    /// A transaction that announces one input and ends right after the announcement.
+    const TRUNCATED_TX: [u8; 6] = [0x02, 0x00, 0x00, 0x00, 0x00, 0x01];
+
+    #[test]
+    fn malformed_transaction_is_reported_as_error() {
+        assert_eq!(
+            call(|err| unsafe {
+                elements_consensus_sys::bitcoinconsensus_check_transaction(
+                    TRUNCATED_TX.as_ptr(),
+                    TRUNCATED_TX.len() as u32,
+                    err,
+                    &mut 0,
+                )
+            }),
+            Err(Error::TxDeserialize)
+        );
+    }
+}
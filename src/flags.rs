@@ -0,0 +1,213 @@
+use std::{fmt, ops};
+
+/// A set of script verification rules to enforce.
+///
+/// [`VerificationFlags::ALL`] enables every rule that is part of the Elements consensus.
+/// Smaller sets allow checking a transaction against older soft-fork rule sets or leaving out individual rules.
+///
+/// Enabling [`VerificationFlags::WITNESS`] requires [`VerificationFlags::P2SH`] to be enabled as well.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VerificationFlags(u32);
+
+impl VerificationFlags {
+    /// Enforce no additional rules.
+    pub const NONE: Self = Self(elements_consensus_sys::bitcoinconsensus_SCRIPT_FLAGS_VERIFY_NONE);
+    /// Evaluate P2SH subscripts (BIP16).
+    pub const P2SH: Self = Self(elements_consensus_sys::bitcoinconsensus_SCRIPT_FLAGS_VERIFY_P2SH);
+    /// Enforce strict DER signature encoding (BIP66).
+    pub const DERSIG: Self =
+        Self(elements_consensus_sys::bitcoinconsensus_SCRIPT_FLAGS_VERIFY_DERSIG);
+    /// Require the dummy element consumed by `OP_CHECKMULTISIG` to be empty (BIP147).
+    pub const NULLDUMMY: Self =
+        Self(elements_consensus_sys::bitcoinconsensus_SCRIPT_FLAGS_VERIFY_NULLDUMMY);
+    /// Enable `OP_CHECKLOCKTIMEVERIFY` (BIP65).
+    pub const CHECKLOCKTIMEVERIFY: Self =
+        Self(elements_consensus_sys::bitcoinconsensus_SCRIPT_FLAGS_VERIFY_CHECKLOCKTIMEVERIFY);
+    /// Enable `OP_CHECKSEQUENCEVERIFY` (BIP112).
+    pub const CHECKSEQUENCEVERIFY: Self =
+        Self(elements_consensus_sys::bitcoinconsensus_SCRIPT_FLAGS_VERIFY_CHECKSEQUENCEVERIFY);
+    /// Enable segregated witness (BIP141).
+    pub const WITNESS: Self =
+        Self(elements_consensus_sys::bitcoinconsensus_SCRIPT_FLAGS_VERIFY_WITNESS);
+    /// Enable Taproot and tapscript (BIP341 and BIP342).
+    ///
+    /// Verifying the spend of a Taproot output requires all outputs spent by the transaction, because the Taproot signature hash commits to them.
+    pub const TAPROOT: Self =
+        Self(elements_consensus_sys::bitcoinconsensus_SCRIPT_FLAGS_VERIFY_TAPROOT);
+    /// Allow signatures with `SIGHASH_RANGEPROOF` (0x40), which also commit to the rangeproofs and surjection proofs of the outputs.
+    ///
+    /// Elements allows them once dynamic federations are active (see [`ChainParams::dynafed_active`](crate::ChainParams::dynafed_active)), so this flag is not part of [`VerificationFlags::ALL`].
+    pub const SIGHASH_RANGEPROOF: Self =
+        Self(elements_consensus_sys::bitcoinconsensus_SCRIPT_FLAGS_VERIFY_SIGHASH_RANGEPROOF);
+    /// Enforce all rules supported by the consensus library.
+    pub const ALL: Self = Self(elements_consensus_sys::bitcoinconsensus_SCRIPT_FLAGS_VERIFY_ALL);
+
+    /// Returns the raw bits as understood by the consensus library.
+    pub fn bits(self) -> u32 {
+        self.0
+    }
+
+    /// Constructs a set of flags from raw bits, returning `None` if any bit does not correspond to a known flag.
+    pub fn from_bits(bits: u32) -> Option<Self> {
+        if bits & !(Self::ALL | Self::SIGHASH_RANGEPROOF).0 == 0 {
+            Some(Self(bits))
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if all flags in `other` are contained in `self`.
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns `true` if no flags are set.
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Adds the flags in `other` to `self`.
+    pub fn insert(&mut self, other: Self) {
+        self.0 |= other.0;
+    }
+
+    /// Removes the flags in `other` from `self`.
+    pub fn remove(&mut self, other: Self) {
+        self.0 &= !other.0;
+    }
+}
+
+impl fmt::Debug for VerificationFlags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const NAMES: &[(VerificationFlags, &str)] = &[
+            (VerificationFlags::P2SH, "P2SH"),
+            (VerificationFlags::DERSIG, "DERSIG"),
+            (VerificationFlags::NULLDUMMY, "NULLDUMMY"),
+            (
+                VerificationFlags::CHECKLOCKTIMEVERIFY,
+                "CHECKLOCKTIMEVERIFY",
+            ),
+            (
+                VerificationFlags::CHECKSEQUENCEVERIFY,
+                "CHECKSEQUENCEVERIFY",
+            ),
+            (VerificationFlags::WITNESS, "WITNESS"),
+            (VerificationFlags::TAPROOT, "TAPROOT"),
+            (VerificationFlags::SIGHASH_RANGEPROOF, "SIGHASH_RANGEPROOF"),
+        ];
+
+        if self.is_empty() {
+            return write!(f, "NONE");
+        }
+
+        let mut first = true;
+        for (flag, name) in NAMES {
+            if self.contains(*flag) {
+                if !first {
+                    write!(f, " | ")?;
+                }
+                write!(f, "{}", name)?;
+                first = false;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl ops::BitOr for VerificationFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl ops::BitOrAssign for VerificationFlags {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.insert(rhs)
+    }
+}
+
+impl ops::BitAnd for VerificationFlags {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+}
+
+impl ops::Sub for VerificationFlags {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0 & !rhs.0)
+    }
+}
+
+impl ops::SubAssign for VerificationFlags {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.remove(rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_bits_rejects_unknown_bits() {
+        assert_eq!(
+            VerificationFlags::from_bits(VerificationFlags::ALL.bits()),
+            Some(VerificationFlags::ALL)
+        );
+        assert_eq!(
+            VerificationFlags::from_bits(0),
+            Some(VerificationFlags::NONE)
+        );
+        let dynafed = VerificationFlags::ALL | VerificationFlags::SIGHASH_RANGEPROOF;
+        assert_eq!(VerificationFlags::from_bits(dynafed.bits()), Some(dynafed));
+        assert_eq!(VerificationFlags::from_bits(1 << 31), None);
+        assert_eq!(
+            VerificationFlags::from_bits(VerificationFlags::P2SH.bits() | 1 << 31),
+            None
+        );
+    }
+
+    #[test]
+    fn set_operations() {
+        let flags = VerificationFlags::P2SH | VerificationFlags::WITNESS;
+        assert!(flags.contains(VerificationFlags::P2SH));
+        assert!(flags.contains(VerificationFlags::WITNESS));
+        assert!(!flags.contains(VerificationFlags::TAPROOT));
+
+        assert_eq!(flags - VerificationFlags::WITNESS, VerificationFlags::P2SH);
+        assert_eq!(flags - flags, VerificationFlags::NONE);
+        assert_eq!(
+            VerificationFlags::ALL - VerificationFlags::NONE,
+            VerificationFlags::ALL
+        );
+        assert_eq!(flags & VerificationFlags::P2SH, VerificationFlags::P2SH);
+
+        let mut assigned = VerificationFlags::NONE;
+        assigned |= VerificationFlags::P2SH;
+        assigned |= VerificationFlags::WITNESS;
+        assert_eq!(assigned, flags);
+        assigned -= VerificationFlags::P2SH;
+        assert_eq!(assigned, VerificationFlags::WITNESS);
+    }
+
+    #[test]
+    fn debug_lists_flag_names() {
+        assert_eq!(format!("{:?}", VerificationFlags::NONE), "NONE");
+        assert_eq!(format!("{:?}", VerificationFlags::DERSIG), "DERSIG");
+        assert_eq!(
+            format!("{:?}", VerificationFlags::WITNESS | VerificationFlags::P2SH),
+            "P2SH | WITNESS"
+        );
+        assert_eq!(
+            format!("{:?}", VerificationFlags::ALL),
+            "P2SH | DERSIG | NULLDUMMY | CHECKLOCKTIMEVERIFY | CHECKSEQUENCEVERIFY | WITNESS | TAPROOT"
+        );
+    }
+}
@@ -0,0 +1,457 @@
+use crate::{ffi, Error};
+use elements::hashes::sha256;
+use elements::issuance::AssetId;
+use elements::{encode::serialize, Transaction, TxOut};
+use std::{error::Error as StdError, fmt};
+
+/// The identifiers that Elements derives from the asset issuance of an input.
+///
+/// For a new issuance, the entropy is derived from the spent outpoint and the contract hash in `asset_entropy`.
+/// For a reissuance, `asset_entropy` already is the entropy of the initial issuance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Issuance {
+    /// The entropy that the asset and its reissuance token are derived from.
+    pub entropy: sha256::Midstate,
+    /// The issued asset.
+    pub asset: AssetId,
+    /// The reissuance token of the issued asset, which differs depending on whether the issued amount is blinded.
+    pub token: AssetId,
+}
+
+/// The reason why the asset issuance of an input is invalid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IssuanceViolation {
+    /// The transaction has no witness, which issuances of a non-null amount require.
+    MissingWitness,
+    /// The issued amount is zero, out of range, or explicit but has a rangeproof.
+    InvalidAmount,
+    /// The rangeproof of the blinded issued amount is invalid.
+    InvalidAmountRangeproof,
+    /// The amount of issued reissuance tokens is zero, out of range, or explicit but has a rangeproof.
+    InvalidInflationKeys,
+    /// The rangeproof of the blinded amount of issued reissuance tokens is invalid.
+    InvalidInflationKeysRangeproof,
+    /// A reissuance issues reissuance tokens, which only a new issuance may do.
+    ReissuanceInflationKeys,
+    /// The output spent by a reissuance is not the reissuance token, blinded with the `asset_blinding_nonce` of the issuance.
+    MissingReissuanceToken,
+    /// The consensus library reported a violation that this crate does not know, or one that it
+    /// does not know itself.
+    Unrecognized(u32),
+}
+
+impl IssuanceViolation {
+    fn from_raw(code: u32) -> Self {
+        match code {
+            elements_consensus_sys::bitcoinconsensus_ISSUANCE_ERR_WITNESS => {
+                IssuanceViolation::MissingWitness
+            }
+            elements_consensus_sys::bitcoinconsensus_ISSUANCE_ERR_AMOUNT => {
+                IssuanceViolation::InvalidAmount
+            }
+            elements_consensus_sys::bitcoinconsensus_ISSUANCE_ERR_AMOUNT_RANGEPROOF => {
+                IssuanceViolation::InvalidAmountRangeproof
+            }
+            elements_consensus_sys::bitcoinconsensus_ISSUANCE_ERR_INFLATION_KEYS => {
+                IssuanceViolation::InvalidInflationKeys
+            }
+            elements_consensus_sys::bitcoinconsensus_ISSUANCE_ERR_INFLATION_KEYS_RANGEPROOF => {
+                IssuanceViolation::InvalidInflationKeysRangeproof
+            }
+            elements_consensus_sys::bitcoinconsensus_ISSUANCE_ERR_REISSUANCE_INFLATION_KEYS => {
+                IssuanceViolation::ReissuanceInflationKeys
+            }
+            elements_consensus_sys::bitcoinconsensus_ISSUANCE_ERR_REISSUANCE_TOKEN => {
+                IssuanceViolation::MissingReissuanceToken
+            }
+            code => IssuanceViolation::Unrecognized(code),
+        }
+    }
+}
+
+impl fmt::Display for IssuanceViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IssuanceViolation::MissingWitness => write!(f, "issuance requires a witness"),
+            IssuanceViolation::InvalidAmount => write!(f, "issued amount is invalid"),
+            IssuanceViolation::InvalidAmountRangeproof => {
+                write!(f, "rangeproof of issued amount is invalid")
+            }
+            IssuanceViolation::InvalidInflationKeys => {
+                write!(f, "issued amount of reissuance tokens is invalid")
+            }
+            IssuanceViolation::InvalidInflationKeysRangeproof => {
+                write!(f, "rangeproof of issued reissuance tokens is invalid")
+            }
+            IssuanceViolation::ReissuanceInflationKeys => {
+                write!(f, "reissuance cannot issue reissuance tokens")
+            }
+            IssuanceViolation::MissingReissuanceToken => {
+                write!(f, "reissuance does not spend the reissuance token")
+            }
+            IssuanceViolation::Unrecognized(code) => {
+                write!(f, "unrecognized issuance violation {}", code)
+            }
+        }
+    }
+}
+
+impl StdError for IssuanceViolation {}
+
+/// Verifies the asset issuance of input `index` in `transaction`, which spends `spent_output`, as done by Elements before accepting it.
+///
+/// On success, the entropy, asset and reissuance token of the issuance are returned, as computed by Elements.
+/// A reissuance has to spend the reissuance token, so `spent_output` is checked to be the token, blinded with the `asset_blinding_nonce` of the issuance.
+///
+/// This does not check that the issued amounts balance the outputs; that is the job of [`verify_amounts`](crate::verify_amounts).
+pub fn verify_issuance(
+    transaction: &Transaction,
+    index: usize,
+    spent_output: &TxOut,
+) -> Result<Result<Issuance, IssuanceViolation>, Error> {
+    if index >= transaction.input.len() {
+        return Err(Error::IndexOutOfBounds);
+    }
+
+    let transaction = serialize(transaction);
+    let spent_output = serialize(spent_output);
+
+    let mut entropy = [0u8; 32];
+    let mut asset = [0u8; 32];
+    let mut token = [0u8; 32];
+    let mut issuance_err = 0;
+
+    let verified = ffi::call(|err| unsafe {
+        elements_consensus_sys::bitcoinconsensus_verify_issuance(
+            transaction.as_ptr(),
+            transaction.len() as u32,
+            spent_output.as_ptr(),
+            spent_output.len() as u32,
+            index as u32,
+            entropy.as_mut_ptr(),
+            asset.as_mut_ptr(),
+            token.as_mut_ptr(),
+            err,
+            &mut issuance_err,
+        )
+    })?;
+
+    if !verified {
+        return Ok(Err(IssuanceViolation::from_raw(issuance_err)));
+    }
+
+    Ok(Ok(Issuance {
+        entropy: sha256::Midstate::from_inner(entropy),
+        asset: AssetId::from_inner(sha256::Midstate::from_inner(asset)),
+        token: AssetId::from_inner(sha256::Midstate::from_inner(token)),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use elements::confidential::{Asset, Value};
+    use elements::hashes::Hash;
+    use elements::issuance::ContractHash;
+    use elements::secp256k1_zkp::{
+        Generator, PedersenCommitment, RangeProof, Secp256k1, SecretKey, Tweak,
+    };
+    use elements::{AssetIssuance, OutPoint, Script, TxIn, TxInWitness, Txid};
+
+    fn prevout() -> OutPoint {
+        OutPoint {
+            txid: Txid::from_inner([1; 32]),
+            vout: 0,
+        }
+    }
+
+    fn entropy() -> sha256::Midstate {
+        AssetId::generate_asset_entropy(prevout(), ContractHash::from_inner([2; 32]))
+    }
+
+    fn spent_output(asset: Asset) -> TxOut {
+        TxOut {
+            asset,
+            value: Value::Explicit(100_000),
+            script_pubkey: Script::from(vec![0x51]),
+            ..Default::default()
+        }
+    }
+
+    fn blinded_amount(asset: AssetId, value: u64) -> (Value, RangeProof) {
+        let secp = Secp256k1::new();
+        let generator = Generator::new_unblinded(&secp, asset.into_tag());
+        let blinding_factor = Tweak::from_inner([3; 32]).unwrap();
+        let commitment = PedersenCommitment::new(&secp, value, blinding_factor, generator);
+        let rangeproof = RangeProof::new(
+            &secp,
+            0,
+            commitment,
+            value,
+            blinding_factor,
+            &[],
+            &[],
+            SecretKey::from_slice(&[4; 32]).unwrap(),
+            0,
+            52,
+            generator,
+        )
+        .unwrap();
+
+        (Value::Confidential(commitment), rangeproof)
+    }
+
+    fn issuance(asset_issuance: AssetIssuance, witness: TxInWitness) -> Transaction {
+        Transaction {
+            version: 2,
+            lock_time: 0,
+            input: vec![TxIn {
+                previous_output: prevout(),
+                is_pegin: false,
+                has_issuance: true,
+                script_sig: Script::new(),
+                sequence: 0xffff_ffff,
+                asset_issuance,
+                witness: TxInWitness {
+                    script_witness: vec![vec![1]],
+                    ..witness
+                },
+            }],
+            output: vec![],
+        }
+    }
+
+    fn new_issuance(amount: Value, inflation_keys: Value) -> AssetIssuance {
+        AssetIssuance {
+            asset_blinding_nonce: Tweak::from_inner([0; 32]).unwrap(),
+            asset_entropy: [2; 32],
+            amount,
+            inflation_keys,
+        }
+    }
+
+    fn reissuance(amount: Value, inflation_keys: Value) -> AssetIssuance {
+        AssetIssuance {
+            asset_blinding_nonce: Tweak::from_inner([5; 32]).unwrap(),
+            asset_entropy: entropy().into_inner(),
+            amount,
+            inflation_keys,
+        }
+    }
+
+    /// The reissuance token of an explicit issuance, blinded with the `asset_blinding_nonce` of [`reissuance`].
+    fn blinded_token() -> Asset {
+        let token = AssetId::reissuance_token_from_entropy(entropy(), false);
+
+        Asset::Confidential(Generator::new_blinded(
+            &Secp256k1::new(),
+            token.into_tag(),
+            Tweak::from_inner([5; 32]).unwrap(),
+        ))
+    }
+
+    #[test]
+    fn new_issuance_derives_asset_and_token() {
+        let asset = Asset::Explicit(AssetId::from_slice(&[6; 32]).unwrap());
+        let transaction = issuance(
+            new_issuance(Value::Explicit(1_000), Value::Explicit(1)),
+            TxInWitness::default(),
+        );
+
+        assert_eq!(
+            verify_issuance(&transaction, 0, &spent_output(asset))
+                .unwrap()
+                .unwrap(),
+            Issuance {
+                entropy: entropy(),
+                asset: AssetId::from_entropy(entropy()),
+                token: AssetId::reissuance_token_from_entropy(entropy(), false),
+            }
+        );
+    }
+
+    #[test]
+    fn blinded_issuance_has_confidential_token() {
+        let asset = Asset::Explicit(AssetId::from_slice(&[6; 32]).unwrap());
+        let (amount, rangeproof) = blinded_amount(AssetId::from_entropy(entropy()), 1_000);
+        let transaction = issuance(
+            new_issuance(amount, Value::Null),
+            TxInWitness {
+                amount_rangeproof: Some(rangeproof),
+                ..Default::default()
+            },
+        );
+
+        let issuance = verify_issuance(&transaction, 0, &spent_output(asset))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            issuance.token,
+            AssetId::reissuance_token_from_entropy(entropy(), true)
+        );
+    }
+
+    #[test]
+    fn blinded_inflation_keys_are_verified() {
+        let asset = Asset::Explicit(AssetId::from_slice(&[6; 32]).unwrap());
+        let (amount, amount_rangeproof) = blinded_amount(AssetId::from_entropy(entropy()), 1_000);
+        let token = AssetId::reissuance_token_from_entropy(entropy(), true);
+        let (inflation_keys, inflation_keys_rangeproof) = blinded_amount(token, 1);
+        let (_, other_rangeproof) = blinded_amount(token, 2);
+
+        let transaction = issuance(
+            new_issuance(amount, inflation_keys),
+            TxInWitness {
+                amount_rangeproof: Some(amount_rangeproof.clone()),
+                inflation_keys_rangeproof: Some(inflation_keys_rangeproof),
+                ..Default::default()
+            },
+        );
+        assert_eq!(
+            verify_issuance(&transaction, 0, &spent_output(asset))
+                .unwrap()
+                .unwrap()
+                .token,
+            token
+        );
+
+        let transaction = issuance(
+            new_issuance(amount, inflation_keys),
+            TxInWitness {
+                amount_rangeproof: Some(amount_rangeproof),
+                inflation_keys_rangeproof: Some(other_rangeproof),
+                ..Default::default()
+            },
+        );
+        assert_eq!(
+            verify_issuance(&transaction, 0, &spent_output(asset)).unwrap(),
+            Err(IssuanceViolation::InvalidInflationKeysRangeproof)
+        );
+    }
+
+    #[test]
+    fn reissuance_spends_token() {
+        let transaction = issuance(
+            reissuance(Value::Explicit(1_000), Value::Null),
+            TxInWitness::default(),
+        );
+
+        let issuance = verify_issuance(&transaction, 0, &spent_output(blinded_token()))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(issuance.asset, AssetId::from_entropy(entropy()));
+    }
+
+    #[test]
+    fn invalid_issuances_are_rejected() {
+        let asset = spent_output(Asset::Explicit(AssetId::from_slice(&[6; 32]).unwrap()));
+        let token = spent_output(blinded_token());
+        let (other_amount, other_rangeproof) =
+            blinded_amount(AssetId::from_slice(&[6; 32]).unwrap(), 1_000);
+        let mut without_witness = issuance(
+            new_issuance(Value::Explicit(1_000), Value::Null),
+            TxInWitness::default(),
+        );
+        without_witness.input[0].witness = TxInWitness::default();
+
+        let cases = vec![
+            (without_witness, &asset, IssuanceViolation::MissingWitness),
+            (
+                issuance(
+                    new_issuance(Value::Explicit(0), Value::Null),
+                    TxInWitness::default(),
+                ),
+                &asset,
+                IssuanceViolation::InvalidAmount,
+            ),
+            (
+                issuance(
+                    new_issuance(Value::Explicit(1_000), Value::Null),
+                    TxInWitness {
+                        amount_rangeproof: Some(other_rangeproof.clone()),
+                        ..Default::default()
+                    },
+                ),
+                &asset,
+                IssuanceViolation::InvalidAmount,
+            ),
+            (
+                issuance(
+                    new_issuance(other_amount, Value::Null),
+                    TxInWitness {
+                        amount_rangeproof: Some(other_rangeproof),
+                        ..Default::default()
+                    },
+                ),
+                &asset,
+                IssuanceViolation::InvalidAmountRangeproof,
+            ),
+            (
+                issuance(
+                    new_issuance(
+                        Value::Explicit(1_000),
+                        Value::Explicit(22_000_000 * 100_000_000),
+                    ),
+                    TxInWitness::default(),
+                ),
+                &asset,
+                IssuanceViolation::InvalidInflationKeys,
+            ),
+            (
+                issuance(
+                    reissuance(Value::Explicit(1_000), Value::Explicit(1)),
+                    TxInWitness::default(),
+                ),
+                &token,
+                IssuanceViolation::ReissuanceInflationKeys,
+            ),
+            (
+                issuance(
+                    reissuance(Value::Explicit(1_000), Value::Null),
+                    TxInWitness::default(),
+                ),
+                &asset,
+                IssuanceViolation::MissingReissuanceToken,
+            ),
+        ];
+
+        for (transaction, spent_output, violation) in cases {
+            assert_eq!(
+                verify_issuance(&transaction, 0, spent_output).unwrap(),
+                Err(violation)
+            );
+        }
+    }
+
+    #[test]
+    fn only_inputs_with_issuance_can_be_verified() {
+        let asset = spent_output(Asset::Explicit(AssetId::from_slice(&[6; 32]).unwrap()));
+        let mut transaction = issuance(
+            new_issuance(Value::Explicit(1_000), Value::Null),
+            TxInWitness::default(),
+        );
+
+        assert_eq!(
+            verify_issuance(&transaction, 1, &asset).unwrap_err(),
+            Error::IndexOutOfBounds
+        );
+
+        transaction.input[0].has_issuance = false;
+        transaction.input[0].asset_issuance = AssetIssuance::default();
+        assert_eq!(
+            verify_issuance(&transaction, 0, &asset).unwrap_err(),
+            Error::NoIssuance
+        );
+    }
+
+    #[test]
+    fn unknown_reason_is_unrecognized() {
+        let code = elements_consensus_sys::bitcoinconsensus_ISSUANCE_ERR_UNKNOWN;
+        assert_eq!(
+            IssuanceViolation::from_raw(code),
+            IssuanceViolation::Unrecognized(code)
+        );
+    }
+}
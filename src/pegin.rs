@@ -0,0 +1,466 @@
+use crate::{ffi, Error};
+use elements::bitcoin::{self, util::uint::Uint256, BlockHash};
+use elements::hashes::Hash;
+use elements::issuance::AssetId;
+use elements::{encode::serialize, Transaction};
+use std::{error::Error as StdError, fmt};
+
+/// The parent chain and federation that a peg-in is verified against.
+///
+/// These are the parts of the chain parameters of the sidechain, e.g. `-parentgenesisblockhash` and `-fedpegscript`, that Elements checks a peg-in with.
+/// Only parent chains with proof of work, like Bitcoin, are supported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PeginParams {
+    /// The hash of the genesis block of the parent chain.
+    pub parent_genesis_hash: BlockHash,
+    /// The highest target that a parent block may have.
+    pub parent_pow_limit: Uint256,
+    /// The asset that peg-ins are claimed in, e.g. L-BTC.
+    pub pegged_asset: AssetId,
+    /// The `scriptPubKey` that the federation is paid to on the parent chain.
+    ///
+    /// If this is a P2SH script, peg-ins pay to the P2SH-wrapped P2WSH of the tweaked `fedpegscript`, otherwise to its P2WSH.
+    pub fedpeg_program: bitcoin::Script,
+    /// The script of the federation, whose public keys are tweaked with the claim script of each peg-in.
+    pub fedpegscript: Vec<u8>,
+    /// The number of confirmations that a parent block needs before its outputs can be claimed.
+    pub min_depth: u32,
+}
+
+/// The reason why the pegin witness of an input does not prove the claimed output on the parent chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PeginViolation {
+    /// The pegin witness does not have the expected items or their encoding is invalid.
+    Witness,
+    /// The claimed value exceeds the maximum amount of money.
+    Value,
+    /// The claim script is longer than 100 bytes.
+    ClaimScriptSize,
+    /// The merkle block cannot be decoded or does not prove exactly one transaction.
+    MerkleBlock,
+    /// The proof of work of the parent block is invalid or its target exceeds the limit.
+    ProofOfWork,
+    /// The parent transaction cannot be decoded or does not have the claimed output.
+    Transaction,
+    /// The parent transaction or the merkle proof does not match the claimed outpoint.
+    Txid,
+    /// The claimed value does not match the value of the parent output.
+    Amount,
+    /// The parent output does not pay to the `fedpegscript` tweaked with the claim script.
+    ClaimScript,
+    /// The genesis block hash does not match the parent chain.
+    GenesisHash,
+    /// The claimed asset is not the pegged asset.
+    Asset,
+    /// The parent block does not have enough confirmations.
+    ///
+    /// Outputs of a coinbase transaction require at least 100 confirmations, regardless of [`PeginParams::min_depth`].
+    Depth,
+    /// The consensus library reported a violation that this crate does not know, or one that it
+    /// does not know itself.
+    Unrecognized(u32),
+}
+
+impl PeginViolation {
+    fn from_raw(code: u32) -> Self {
+        match code {
+            elements_consensus_sys::bitcoinconsensus_PEGIN_ERR_WITNESS => PeginViolation::Witness,
+            elements_consensus_sys::bitcoinconsensus_PEGIN_ERR_VALUE => PeginViolation::Value,
+            elements_consensus_sys::bitcoinconsensus_PEGIN_ERR_CLAIM_SCRIPT_SIZE => {
+                PeginViolation::ClaimScriptSize
+            }
+            elements_consensus_sys::bitcoinconsensus_PEGIN_ERR_MERKLE_BLOCK => {
+                PeginViolation::MerkleBlock
+            }
+            elements_consensus_sys::bitcoinconsensus_PEGIN_ERR_POW => PeginViolation::ProofOfWork,
+            elements_consensus_sys::bitcoinconsensus_PEGIN_ERR_TX => PeginViolation::Transaction,
+            elements_consensus_sys::bitcoinconsensus_PEGIN_ERR_TXID => PeginViolation::Txid,
+            elements_consensus_sys::bitcoinconsensus_PEGIN_ERR_AMOUNT => PeginViolation::Amount,
+            elements_consensus_sys::bitcoinconsensus_PEGIN_ERR_CLAIM_SCRIPT => {
+                PeginViolation::ClaimScript
+            }
+            elements_consensus_sys::bitcoinconsensus_PEGIN_ERR_GENESIS => {
+                PeginViolation::GenesisHash
+            }
+            elements_consensus_sys::bitcoinconsensus_PEGIN_ERR_ASSET => PeginViolation::Asset,
+            elements_consensus_sys::bitcoinconsensus_PEGIN_ERR_DEPTH => PeginViolation::Depth,
+            code => PeginViolation::Unrecognized(code),
+        }
+    }
+}
+
+impl fmt::Display for PeginViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PeginViolation::Witness => write!(f, "pegin witness is malformed"),
+            PeginViolation::Value => write!(f, "claimed value is out of range"),
+            PeginViolation::ClaimScriptSize => write!(f, "claim script is too large"),
+            PeginViolation::MerkleBlock => write!(f, "merkle block is invalid"),
+            PeginViolation::ProofOfWork => write!(f, "parent block has invalid proof of work"),
+            PeginViolation::Transaction => write!(f, "parent transaction is invalid"),
+            PeginViolation::Txid => write!(f, "parent transaction does not match the outpoint"),
+            PeginViolation::Amount => write!(f, "claimed value does not match the parent output"),
+            PeginViolation::ClaimScript => {
+                write!(f, "parent output does not pay to the tweaked fedpegscript")
+            }
+            PeginViolation::GenesisHash => write!(f, "parent genesis block hash does not match"),
+            PeginViolation::Asset => write!(f, "claimed asset is not the pegged asset"),
+            PeginViolation::Depth => write!(f, "parent block has not enough confirmations"),
+            PeginViolation::Unrecognized(code) => {
+                write!(f, "unrecognized pegin violation {}", code)
+            }
+        }
+    }
+}
+
+impl StdError for PeginViolation {}
+
+/// Verifies that the pegin witness of input `index` in `transaction` proves the claimed output on the parent chain described by `params`.
+///
+/// The pegin witness carries the parent transaction and a merkle proof of its inclusion in a parent block.
+/// This function checks the proof of work of that block, the merkle proof, that the parent output pays to the `fedpegscript` tweaked with the claim script, and that the claimed value and asset match.
+/// `confirmations` is the number of confirmations of the parent block, which Elements would otherwise look up through the parent chain daemon.
+///
+/// The script of the input is not verified; that is the job of [`verify`](crate::verify).
+pub fn verify_pegin(
+    transaction: &Transaction,
+    index: usize,
+    params: &PeginParams,
+    confirmations: u32,
+) -> Result<Result<(), PeginViolation>, Error> {
+    let input = transaction
+        .input
+        .get(index)
+        .ok_or(Error::IndexOutOfBounds)?;
+    if !input.is_pegin {
+        return Err(Error::NotPegin);
+    }
+
+    let transaction = serialize(transaction);
+    let parent_genesis_hash = params.parent_genesis_hash.into_inner();
+    let parent_pow_limit = bitcoin::consensus::serialize(&params.parent_pow_limit);
+    let pegged_asset = params.pegged_asset.into_inner().into_inner();
+
+    let mut pegin_err = 0;
+
+    let verified = ffi::call(|err| unsafe {
+        elements_consensus_sys::bitcoinconsensus_verify_pegin(
+            transaction.as_ptr(),
+            transaction.len() as u32,
+            index as u32,
+            parent_genesis_hash.as_ptr(),
+            parent_pow_limit.as_ptr(),
+            pegged_asset.as_ptr(),
+            params.fedpeg_program.as_bytes().as_ptr(),
+            params.fedpeg_program.len() as u32,
+            params.fedpegscript.as_ptr(),
+            params.fedpegscript.len() as u32,
+            params.min_depth,
+            confirmations,
+            err,
+            &mut pegin_err,
+        )
+    })?;
+
+    if verified {
+        Ok(Ok(()))
+    } else {
+        Ok(Err(PeginViolation::from_raw(pegin_err)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ChainParams;
+    use elements::bitcoin::blockdata::constants::genesis_block;
+    use elements::bitcoin::consensus::Params;
+    use elements::bitcoin::{BlockHeader, MerkleBlock, Network, TxMerkleNode};
+    use elements::hashes::{hmac, sha256, HashEngine};
+    use elements::secp256k1_zkp::{PublicKey, Secp256k1, SecretKey};
+    use elements::{confidential, AssetIssuance, OutPoint, Script, TxIn, TxOut, Txid};
+
+    const CLAIM_SCRIPT: [u8; 22] = [
+        0x00, 0x14, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    ];
+
+    fn federation_key() -> PublicKey {
+        PublicKey::from_secret_key(&Secp256k1::new(), &SecretKey::from_slice(&[2; 32]).unwrap())
+    }
+
+    /// `<federation key> OP_CHECKSIG`
+    fn fedpegscript(key: &PublicKey) -> bitcoin::Script {
+        bitcoin::blockdata::script::Builder::new()
+            .push_slice(&key.serialize())
+            .push_opcode(bitcoin::blockdata::opcodes::all::OP_CHECKSIG)
+            .into_script()
+    }
+
+    fn params() -> PeginParams {
+        let fedpegscript = fedpegscript(&federation_key());
+
+        PeginParams {
+            parent_genesis_hash: genesis_block(Network::Regtest).block_hash(),
+            parent_pow_limit: Params::new(Network::Regtest).pow_limit,
+            pegged_asset: AssetId::from_slice(&[1; 32]).unwrap(),
+            fedpeg_program: fedpegscript.to_v0_p2wsh(),
+            fedpegscript: fedpegscript.into_bytes(),
+            min_depth: 10,
+        }
+    }
+
+    /// The federation key tweaked with `HMAC-SHA256(key, claim script)`, as done by `calculate_contract` in Elements.
+    fn tweaked_fedpegscript() -> bitcoin::Script {
+        let key = federation_key();
+        let mut engine = hmac::HmacEngine::<sha256::Hash>::new(&key.serialize());
+        engine.input(&CLAIM_SCRIPT);
+        let tweak = hmac::Hmac::from_engine(engine).into_inner();
+
+        let mut tweaked = key;
+        tweaked.add_exp_assign(&Secp256k1::new(), &tweak).unwrap();
+
+        fedpegscript(&tweaked)
+    }
+
+    fn parent_transaction(value: u64) -> bitcoin::Transaction {
+        bitcoin::Transaction {
+            version: 2,
+            lock_time: 0,
+            input: vec![bitcoin::TxIn {
+                previous_output: bitcoin::OutPoint {
+                    txid: bitcoin::Txid::from_inner([3; 32]),
+                    vout: 0,
+                },
+                script_sig: bitcoin::Script::new(),
+                sequence: 0xffff_ffff,
+                witness: vec![],
+            }],
+            output: vec![bitcoin::TxOut {
+                value,
+                script_pubkey: tweaked_fedpegscript().to_v0_p2wsh(),
+            }],
+        }
+    }
+
+    /// Mines a regtest block that contains a coinbase and `transaction`, proving only the latter.
+    fn merkle_block(transaction: &bitcoin::Transaction) -> MerkleBlock {
+        let txids = [bitcoin::Txid::from_inner([4; 32]), transaction.txid()];
+        let merkle_root =
+            bitcoin::util::hash::bitcoin_merkle_root(txids.iter().map(|txid| txid.as_hash()));
+        let mut header = BlockHeader {
+            version: 4,
+            prev_blockhash: genesis_block(Network::Regtest).block_hash(),
+            merkle_root: TxMerkleNode::from_hash(merkle_root),
+            time: 1_600_000_000,
+            bits: 0x207f_ffff,
+            nonce: 0,
+        };
+        while header.validate_pow(&header.target()).is_err() {
+            header.nonce += 1;
+        }
+
+        MerkleBlock::from_header_txids_with_predicate(&header, &txids, |txid| {
+            *txid == transaction.txid()
+        })
+    }
+
+    fn pegin(
+        value: u64,
+        parent_transaction: &bitcoin::Transaction,
+        merkle_block: &MerkleBlock,
+    ) -> Transaction {
+        pegin_into(&params(), value, parent_transaction, merkle_block)
+    }
+
+    fn pegin_into(
+        params: &PeginParams,
+        value: u64,
+        parent_transaction: &bitcoin::Transaction,
+        merkle_block: &MerkleBlock,
+    ) -> Transaction {
+        Transaction {
+            version: 2,
+            lock_time: 0,
+            input: vec![TxIn {
+                previous_output: OutPoint {
+                    txid: Txid::from_inner(parent_transaction.txid().into_inner()),
+                    vout: 0,
+                },
+                is_pegin: true,
+                has_issuance: false,
+                script_sig: Script::new(),
+                sequence: 0xffff_ffff,
+                asset_issuance: AssetIssuance::default(),
+                witness: elements::TxInWitness {
+                    pegin_witness: vec![
+                        bitcoin::consensus::serialize(&value),
+                        params.pegged_asset.into_inner().into_inner().to_vec(),
+                        params.parent_genesis_hash.into_inner().to_vec(),
+                        CLAIM_SCRIPT.to_vec(),
+                        bitcoin::consensus::serialize(parent_transaction),
+                        bitcoin::consensus::serialize(merkle_block),
+                    ],
+                    ..Default::default()
+                },
+            }],
+            output: vec![TxOut {
+                asset: confidential::Asset::Explicit(params.pegged_asset),
+                value: confidential::Value::Explicit(value),
+                script_pubkey: Script::from(CLAIM_SCRIPT.to_vec()),
+                ..Default::default()
+            }],
+        }
+    }
+
+    #[test]
+    fn valid_pegin_passes() {
+        let parent_transaction = parent_transaction(100_000);
+        let transaction = pegin(
+            100_000,
+            &parent_transaction,
+            &merkle_block(&parent_transaction),
+        );
+
+        verify_pegin(&transaction, 0, &params(), 10)
+            .unwrap()
+            .unwrap();
+    }
+
+    #[test]
+    fn pegin_to_legacy_fedpeg_program_passes() {
+        let parent_transaction = bitcoin::Transaction {
+            output: vec![bitcoin::TxOut {
+                value: 100_000,
+                script_pubkey: tweaked_fedpegscript().to_v0_p2wsh().to_p2sh(),
+            }],
+            ..parent_transaction(100_000)
+        };
+        let transaction = pegin(
+            100_000,
+            &parent_transaction,
+            &merkle_block(&parent_transaction),
+        );
+        let params = PeginParams {
+            fedpeg_program: bitcoin::Script::from(params().fedpegscript)
+                .to_v0_p2wsh()
+                .to_p2sh(),
+            ..params()
+        };
+
+        verify_pegin(&transaction, 0, &params, 10).unwrap().unwrap();
+    }
+
+    #[test]
+    fn regtest_pegin_passes_with_elements_regtest_params() {
+        let params = ChainParams::elements_regtest().pegin_params();
+        let mut parent_transaction = parent_transaction(100_000);
+        // The default fedpegscript `OP_TRUE` has no keys to tweak with the claim script.
+        parent_transaction.output[0].script_pubkey = params.fedpeg_program.clone();
+        let transaction = pegin_into(
+            &params,
+            100_000,
+            &parent_transaction,
+            &merkle_block(&parent_transaction),
+        );
+
+        verify_pegin(&transaction, 0, &params, params.min_depth)
+            .unwrap()
+            .unwrap();
+    }
+
+    #[test]
+    fn invalid_pegins_are_rejected() {
+        let parent_transaction = parent_transaction(100_000);
+        let merkle_block = merkle_block(&parent_transaction);
+        let transaction = pegin(100_000, &parent_transaction, &merkle_block);
+
+        let mut wrong_amount = pegin(90_000, &parent_transaction, &merkle_block);
+        wrong_amount.output[0].value = confidential::Value::Explicit(90_000);
+        let mut wrong_claim_script = transaction.clone();
+        wrong_claim_script.input[0].witness.pegin_witness[3] = vec![0x51];
+        let mut missing_item = transaction.clone();
+        missing_item.input[0].witness.pegin_witness.pop();
+        let mut unproven_block = merkle_block.clone();
+        unproven_block.header.nonce += 1;
+        while unproven_block
+            .header
+            .validate_pow(&unproven_block.header.target())
+            .is_ok()
+        {
+            unproven_block.header.nonce += 1;
+        }
+
+        let cases = vec![
+            (missing_item, params(), 10, PeginViolation::Witness),
+            (wrong_amount, params(), 10, PeginViolation::Amount),
+            (
+                wrong_claim_script,
+                params(),
+                10,
+                PeginViolation::ClaimScript,
+            ),
+            (
+                pegin(100_000, &parent_transaction, &unproven_block),
+                params(),
+                10,
+                PeginViolation::ProofOfWork,
+            ),
+            (
+                transaction.clone(),
+                PeginParams {
+                    parent_genesis_hash: genesis_block(Network::Testnet).block_hash(),
+                    ..params()
+                },
+                10,
+                PeginViolation::GenesisHash,
+            ),
+            (
+                transaction.clone(),
+                PeginParams {
+                    pegged_asset: AssetId::from_slice(&[2; 32]).unwrap(),
+                    ..params()
+                },
+                10,
+                PeginViolation::Asset,
+            ),
+            (transaction, params(), 9, PeginViolation::Depth),
+        ];
+
+        for (transaction, params, confirmations, violation) in cases {
+            assert_eq!(
+                verify_pegin(&transaction, 0, &params, confirmations).unwrap(),
+                Err(violation)
+            );
+        }
+    }
+
+    #[test]
+    fn only_pegin_inputs_can_be_verified() {
+        let parent_transaction = parent_transaction(100_000);
+        let mut transaction = pegin(
+            100_000,
+            &parent_transaction,
+            &merkle_block(&parent_transaction),
+        );
+
+        assert_eq!(
+            verify_pegin(&transaction, 1, &params(), 10).unwrap_err(),
+            Error::IndexOutOfBounds
+        );
+
+        transaction.input[0].is_pegin = false;
+        assert_eq!(
+            verify_pegin(&transaction, 0, &params(), 10).unwrap_err(),
+            Error::NotPegin
+        );
+    }
+
+    #[test]
+    fn unknown_reason_is_unrecognized() {
+        let code = elements_consensus_sys::bitcoinconsensus_PEGIN_ERR_UNKNOWN;
+        assert_eq!(
+            PeginViolation::from_raw(code),
+            PeginViolation::Unrecognized(code)
+        );
+    }
+}
@@ -0,0 +1,538 @@
+//! Standardness of transactions, i.e. whether `elementsd` would relay them and accept them to its mempool.
+//!
+//! Transactions that pass consensus can still be rejected by nodes because of their relay policy, for example when an output is dust.
+//! [`check_standard`] runs the policy checks of `testmempoolaccept` offline and reports the same reject reasons.
+//! The consensus checks are not repeated, see [`check_transaction`](crate::check_transaction), [`verify_amounts`](crate::verify_amounts) and [`verify_transaction`](crate::verify_transaction).
+
+use crate::{ffi, ChainParams, Error, ScriptError};
+use elements::hashes::Hash;
+use elements::{encode::serialize, Transaction, TxOut};
+use std::{error::Error as StdError, fmt};
+
+/// The relay settings of a node that standardness depends on.
+///
+/// [`PolicySettings::new`] sets the defaults of `elementsd` for all of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PolicySettings {
+    /// `-dustrelayfee`, in satoshi per kvB.
+    pub dust_relay_fee: u32,
+    /// `-permitbaremultisig`
+    pub permit_bare_multisig: bool,
+    /// `-datacarrier`
+    pub data_carrier: bool,
+    /// `-datacarriersize`
+    pub data_carrier_size: u32,
+    /// `-multi_data_permitted`
+    pub multi_data_permitted: bool,
+    /// `-anyonecanspendaremine`, which makes `OP_TRUE` outputs standard.
+    pub anyonecanspend_aremine: bool,
+    /// Whether Taproot is active, without which spends of Taproot outputs are non-standard.
+    pub taproot_active: bool,
+    /// Whether dynamic federations are active, which allows signatures to commit to the rangeproofs of outputs.
+    pub dynafed_active: bool,
+}
+
+impl PolicySettings {
+    /// The default settings of `elementsd` on `chain`.
+    ///
+    /// Multiple data outputs are permitted on chains that enforce PAK, like Liquid.
+    /// Dynamic federations are active if they are on `chain`, see [`ChainParams::dynafed_active`].
+    pub fn new(chain: &ChainParams) -> Self {
+        Self {
+            dust_relay_fee: 3_000,
+            permit_bare_multisig: true,
+            data_carrier: true,
+            data_carrier_size: 83,
+            multi_data_permitted: chain.enforce_pak,
+            anyonecanspend_aremine: false,
+            taproot_active: true,
+            dynafed_active: chain.dynafed_active,
+        }
+    }
+
+    fn flags(&self, chain: &ChainParams) -> u32 {
+        let settings = [
+            (
+                self.permit_bare_multisig,
+                elements_consensus_sys::bitcoinconsensus_POLICY_PERMIT_BARE_MULTISIG,
+            ),
+            (
+                self.data_carrier,
+                elements_consensus_sys::bitcoinconsensus_POLICY_DATACARRIER,
+            ),
+            (
+                self.multi_data_permitted,
+                elements_consensus_sys::bitcoinconsensus_POLICY_MULTI_DATA_PERMITTED,
+            ),
+            (
+                chain.enforce_pak,
+                elements_consensus_sys::bitcoinconsensus_POLICY_ENFORCE_PAK,
+            ),
+            (
+                self.anyonecanspend_aremine,
+                elements_consensus_sys::bitcoinconsensus_POLICY_ANYONECANSPEND_AREMINE,
+            ),
+            (
+                self.taproot_active,
+                elements_consensus_sys::bitcoinconsensus_POLICY_TAPROOT_ACTIVE,
+            ),
+            (
+                self.dynafed_active,
+                elements_consensus_sys::bitcoinconsensus_POLICY_DYNAFED_ACTIVE,
+            ),
+        ];
+
+        settings.iter().filter(|(enabled, _)| *enabled).fold(
+            elements_consensus_sys::bitcoinconsensus_POLICY_NONE,
+            |flags, (_, flag)| flags | flag,
+        )
+    }
+}
+
+/// A transaction is valid, but not standard.
+///
+/// Each variant corresponds to one of the policy checks done by `MemPoolAccept` in Elements, in the order they are done.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StandardViolation {
+    /// The version of the transaction is not 1 or 2.
+    Version,
+    /// The transaction is heavier than 400000 weight units.
+    TxSize,
+    /// The scriptSig of input `input` is larger than 1650 bytes.
+    ScriptSigSize { input: usize },
+    /// The scriptSig of input `input` does not only push data.
+    ScriptSigNotPushOnly { input: usize },
+    /// The script of output `output` is of no standard type.
+    ScriptPubkey { output: usize },
+    /// Output `output` is a bare multisig, which is not permitted.
+    BareMultisig { output: usize },
+    /// The value of output `output` is below the dust threshold of its script.
+    Dust { output: usize },
+    /// The transaction has more than one data carrier output.
+    MultiOpReturn,
+    /// The transaction is smaller than 82 bytes without witness data.
+    TxSizeSmall,
+    /// Input `input` spends an output of no standard type, or its P2SH redeem script has too many signature operations.
+    NonstandardInputs { input: usize },
+    /// The witness of input `input` is over the standard resource limits.
+    NonstandardWitness { input: usize },
+    /// The transaction has more signature operations than a fifth of a block permits.
+    SigOps,
+    /// Input `input` fails the standard script verification flags, but not the mandatory ones.
+    NonMandatoryScript { input: usize, error: ScriptError },
+    /// Input `input` fails the mandatory script verification flags, which makes the transaction invalid.
+    MandatoryScript { input: usize, error: ScriptError },
+    /// The consensus library reported a violation that this crate does not know, or one that it
+    /// does not know itself.
+    Unrecognized(u32),
+}
+
+impl StandardViolation {
+    /// The reject reason reported by `testmempoolaccept`, e.g. `dust` or `non-mandatory-script-verify-flag (Using OP_CODESEPARATOR in non-witness script)`.
+    pub fn reject_reason(&self) -> String {
+        match self {
+            StandardViolation::Version => "version".to_owned(),
+            StandardViolation::TxSize => "tx-size".to_owned(),
+            StandardViolation::ScriptSigSize { .. } => "scriptsig-size".to_owned(),
+            StandardViolation::ScriptSigNotPushOnly { .. } => "scriptsig-not-pushonly".to_owned(),
+            StandardViolation::ScriptPubkey { .. } => "scriptpubkey".to_owned(),
+            StandardViolation::BareMultisig { .. } => "bare-multisig".to_owned(),
+            StandardViolation::Dust { .. } => "dust".to_owned(),
+            StandardViolation::MultiOpReturn => "multi-op-return".to_owned(),
+            StandardViolation::TxSizeSmall => "tx-size-small".to_owned(),
+            StandardViolation::NonstandardInputs { .. } => "bad-txns-nonstandard-inputs".to_owned(),
+            StandardViolation::NonstandardWitness { .. } => "bad-witness-nonstandard".to_owned(),
+            StandardViolation::SigOps => "bad-txns-too-many-sigops".to_owned(),
+            StandardViolation::NonMandatoryScript { error, .. } => {
+                format!("non-mandatory-script-verify-flag ({})", error)
+            }
+            StandardViolation::MandatoryScript { error, .. } => {
+                format!("mandatory-script-verify-flag-failed ({})", error)
+            }
+            StandardViolation::Unrecognized(_) => "unrecognized".to_owned(),
+        }
+    }
+
+    fn from_raw(code: u32, index: usize, script_err: u32) -> Self {
+        match code {
+            elements_consensus_sys::bitcoinconsensus_STANDARD_ERR_VERSION => {
+                StandardViolation::Version
+            }
+            elements_consensus_sys::bitcoinconsensus_STANDARD_ERR_TX_SIZE => {
+                StandardViolation::TxSize
+            }
+            elements_consensus_sys::bitcoinconsensus_STANDARD_ERR_SCRIPTSIG_SIZE => {
+                StandardViolation::ScriptSigSize { input: index }
+            }
+            elements_consensus_sys::bitcoinconsensus_STANDARD_ERR_SCRIPTSIG_NOT_PUSHONLY => {
+                StandardViolation::ScriptSigNotPushOnly { input: index }
+            }
+            elements_consensus_sys::bitcoinconsensus_STANDARD_ERR_SCRIPTPUBKEY => {
+                StandardViolation::ScriptPubkey { output: index }
+            }
+            elements_consensus_sys::bitcoinconsensus_STANDARD_ERR_BARE_MULTISIG => {
+                StandardViolation::BareMultisig { output: index }
+            }
+            elements_consensus_sys::bitcoinconsensus_STANDARD_ERR_DUST => {
+                StandardViolation::Dust { output: index }
+            }
+            elements_consensus_sys::bitcoinconsensus_STANDARD_ERR_MULTI_OP_RETURN => {
+                StandardViolation::MultiOpReturn
+            }
+            elements_consensus_sys::bitcoinconsensus_STANDARD_ERR_TX_SIZE_SMALL => {
+                StandardViolation::TxSizeSmall
+            }
+            elements_consensus_sys::bitcoinconsensus_STANDARD_ERR_INPUTS => {
+                StandardViolation::NonstandardInputs { input: index }
+            }
+            elements_consensus_sys::bitcoinconsensus_STANDARD_ERR_WITNESS => {
+                StandardViolation::NonstandardWitness { input: index }
+            }
+            elements_consensus_sys::bitcoinconsensus_STANDARD_ERR_SIGOPS => {
+                StandardViolation::SigOps
+            }
+            elements_consensus_sys::bitcoinconsensus_STANDARD_ERR_NON_MANDATORY_SCRIPT => {
+                StandardViolation::NonMandatoryScript {
+                    input: index,
+                    error: ScriptError::from_raw(script_err),
+                }
+            }
+            elements_consensus_sys::bitcoinconsensus_STANDARD_ERR_MANDATORY_SCRIPT => {
+                StandardViolation::MandatoryScript {
+                    input: index,
+                    error: ScriptError::from_raw(script_err),
+                }
+            }
+            code => StandardViolation::Unrecognized(code),
+        }
+    }
+}
+
+impl fmt::Display for StandardViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StandardViolation::ScriptSigSize { input }
+            | StandardViolation::ScriptSigNotPushOnly { input }
+            | StandardViolation::NonstandardInputs { input }
+            | StandardViolation::NonstandardWitness { input }
+            | StandardViolation::NonMandatoryScript { input, .. }
+            | StandardViolation::MandatoryScript { input, .. } => {
+                write!(f, "{} in input {}", self.reject_reason(), input)
+            }
+            StandardViolation::ScriptPubkey { output }
+            | StandardViolation::BareMultisig { output }
+            | StandardViolation::Dust { output } => {
+                write!(f, "{} in output {}", self.reject_reason(), output)
+            }
+            StandardViolation::Unrecognized(code) => {
+                write!(f, "unrecognized standard violation {}", code)
+            }
+            _ => write!(f, "{}", self.reject_reason()),
+        }
+    }
+}
+
+impl StdError for StandardViolation {}
+
+/// Checks that `transaction` is standard on `chain` for a node with the given relay `settings`, like `testmempoolaccept` in Elements.
+///
+/// `spent_outputs` must hold the output spent by each input, including the outputs claimed by peg-ins.
+/// Only outputs in the policy asset of `chain` can be dust.
+/// The inputs are verified under the standard script verification flags, which extend the consensus rules of [`VerificationFlags::ALL`](crate::VerificationFlags::ALL).
+/// Coinbase transactions are never standard and cannot be checked.
+///
+/// If the transaction has several violations, the one that Elements reports first is returned.
+/// Like [`verify`](crate::verify), this function returns two layers of errors.
+pub fn check_standard(
+    transaction: &Transaction,
+    spent_outputs: &[TxOut],
+    chain: &ChainParams,
+    settings: &PolicySettings,
+) -> Result<Result<(), StandardViolation>, Error> {
+    if transaction.is_coinbase() {
+        return Err(Error::Coinbase);
+    }
+    if transaction.input.len() != spent_outputs.len() {
+        return Err(Error::SpentOutputsMismatch);
+    }
+
+    let transaction = serialize(transaction);
+    let spent_outputs = serialize(&spent_outputs.to_vec());
+    let policy_asset = chain.policy_asset.into_inner().0;
+    let parent_genesis_hash = chain.parent_genesis_hash.into_inner();
+
+    let mut standard_err = 0;
+    let mut index = 0;
+    let mut script_err = 0;
+
+    let standard = ffi::call(|err| unsafe {
+        elements_consensus_sys::bitcoinconsensus_check_standard(
+            chain.genesis_hash.as_inner().as_ptr(),
+            transaction.as_ptr(),
+            transaction.len() as u32,
+            spent_outputs.as_ptr(),
+            spent_outputs.len() as u32,
+            policy_asset.as_ptr(),
+            parent_genesis_hash.as_ptr(),
+            settings.dust_relay_fee,
+            settings.data_carrier_size,
+            settings.flags(chain),
+            err,
+            &mut standard_err,
+            &mut index,
+            &mut script_err,
+        )
+    })?;
+
+    if standard {
+        Ok(Ok(()))
+    } else {
+        Ok(Err(StandardViolation::from_raw(
+            standard_err,
+            index as usize,
+            script_err,
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use elements::hashes::sha256;
+    use elements::opcodes::all::{OP_PUSHBYTES_0, OP_PUSHNUM_1, OP_RETURN};
+    use elements::script::Builder;
+    use elements::{confidential, AssetIssuance, OutPoint, Script, TxIn, TxInWitness};
+
+    fn chain() -> ChainParams {
+        ChainParams::elements_regtest()
+    }
+
+    fn witness_script() -> Script {
+        Builder::new().push_opcode(OP_PUSHNUM_1).into_script()
+    }
+
+    fn p2wsh_output(value: u64) -> TxOut {
+        let script_hash = sha256::Hash::hash(witness_script().as_bytes());
+
+        TxOut {
+            asset: confidential::Asset::Explicit(chain().policy_asset),
+            value: confidential::Value::Explicit(value),
+            script_pubkey: Builder::new()
+                .push_opcode(OP_PUSHBYTES_0)
+                .push_slice(&script_hash)
+                .into_script(),
+            ..Default::default()
+        }
+    }
+
+    fn output(script_pubkey: Script, value: u64) -> TxOut {
+        TxOut {
+            script_pubkey,
+            ..p2wsh_output(value)
+        }
+    }
+
+    fn spend(outputs: Vec<TxOut>) -> Transaction {
+        Transaction {
+            version: 2,
+            lock_time: 0,
+            input: vec![TxIn {
+                previous_output: OutPoint {
+                    vout: 0,
+                    ..Default::default()
+                },
+                is_pegin: false,
+                has_issuance: false,
+                script_sig: Script::new(),
+                sequence: 0xffff_ffff,
+                asset_issuance: AssetIssuance::default(),
+                witness: TxInWitness {
+                    script_witness: vec![witness_script().into_bytes()],
+                    ..Default::default()
+                },
+            }],
+            output: outputs,
+        }
+    }
+
+    fn data_output() -> TxOut {
+        output(
+            Builder::new()
+                .push_opcode(OP_RETURN)
+                .push_slice(&[1; 20])
+                .into_script(),
+            0,
+        )
+    }
+
+    fn check(
+        transaction: &Transaction,
+        settings: &PolicySettings,
+    ) -> Result<(), StandardViolation> {
+        check_standard(transaction, &[p2wsh_output(100_000)], &chain(), settings).unwrap()
+    }
+
+    #[test]
+    fn p2wsh_spend_is_standard() {
+        let transaction = spend(vec![
+            p2wsh_output(99_000),
+            TxOut::new_fee(1_000, chain().policy_asset),
+        ]);
+
+        check(&transaction, &PolicySettings::new(&chain())).unwrap();
+    }
+
+    #[test]
+    fn reports_failed_check() {
+        let settings = PolicySettings::new(&chain());
+        let fee = TxOut::new_fee(1_000, chain().policy_asset);
+
+        let dust = spend(vec![p2wsh_output(98_999), p2wsh_output(1), fee.clone()]);
+        let anyonecanspend = spend(vec![output(witness_script(), 99_000), fee.clone()]);
+        let multi_data = spend(vec![
+            p2wsh_output(99_000),
+            data_output(),
+            data_output(),
+            fee.clone(),
+        ]);
+        let too_small = spend(vec![TxOut::default()]);
+        let mut version = spend(vec![p2wsh_output(99_000), fee]);
+        version.version = 3;
+
+        assert_eq!(
+            check(&dust, &settings),
+            Err(StandardViolation::Dust { output: 1 })
+        );
+        assert_eq!(
+            check(&anyonecanspend, &settings),
+            Err(StandardViolation::ScriptPubkey { output: 0 })
+        );
+        assert_eq!(
+            check(&multi_data, &settings),
+            Err(StandardViolation::MultiOpReturn)
+        );
+        assert_eq!(
+            check(&too_small, &settings),
+            Err(StandardViolation::TxSizeSmall)
+        );
+        assert_eq!(check(&version, &settings), Err(StandardViolation::Version));
+    }
+
+    #[test]
+    fn settings_relax_checks() {
+        let fee = TxOut::new_fee(1_000, chain().policy_asset);
+        let anyonecanspend = spend(vec![output(witness_script(), 99_000), fee.clone()]);
+        let multi_data = spend(vec![
+            p2wsh_output(99_000),
+            data_output(),
+            data_output(),
+            fee,
+        ]);
+
+        check(
+            &anyonecanspend,
+            &PolicySettings {
+                anyonecanspend_aremine: true,
+                ..PolicySettings::new(&chain())
+            },
+        )
+        .unwrap();
+        check(&multi_data, &PolicySettings::new(&ChainParams::liquid())).unwrap();
+    }
+
+    #[test]
+    fn only_policy_asset_can_be_dust() {
+        let other_asset = TxOut {
+            asset: confidential::Asset::Explicit(
+                elements::issuance::AssetId::from_slice(&[1; 32]).unwrap(),
+            ),
+            ..p2wsh_output(1)
+        };
+        let transaction = spend(vec![
+            p2wsh_output(99_000),
+            other_asset,
+            TxOut::new_fee(1_000, chain().policy_asset),
+        ]);
+
+        check(&transaction, &PolicySettings::new(&chain())).unwrap();
+    }
+
+    #[test]
+    fn script_failures_are_mandatory_or_not() {
+        let settings = PolicySettings::new(&chain());
+        let fee = TxOut::new_fee(1_000, chain().policy_asset);
+
+        let mut unclean_stack = spend(vec![p2wsh_output(99_000), fee.clone()]);
+        unclean_stack.input[0]
+            .witness
+            .script_witness
+            .insert(0, vec![]);
+
+        let redeem_script = Builder::new().push_int(0).into_script();
+        let p2sh = output(redeem_script.to_p2sh(), 100_000);
+        let mut false_redeem_script = spend(vec![p2wsh_output(99_000), fee]);
+        false_redeem_script.input[0].witness = TxInWitness::default();
+        false_redeem_script.input[0].script_sig = Builder::new()
+            .push_slice(redeem_script.as_bytes())
+            .into_script();
+
+        let violation = check(&unclean_stack, &settings).unwrap_err();
+        assert_eq!(
+            violation,
+            StandardViolation::NonMandatoryScript {
+                input: 0,
+                error: ScriptError::CleanStack
+            }
+        );
+        assert_eq!(
+            violation.reject_reason(),
+            "non-mandatory-script-verify-flag (Stack size must be exactly one after execution)"
+        );
+
+        let violation = check_standard(&false_redeem_script, &[p2sh], &chain(), &settings)
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(
+            violation,
+            StandardViolation::MandatoryScript {
+                input: 0,
+                error: ScriptError::EvalFalse
+            }
+        );
+        assert_eq!(
+            violation.reject_reason(),
+            "mandatory-script-verify-flag-failed (Script evaluated without error but finished with a false/empty top stack element)"
+        );
+    }
+
+    #[test]
+    fn coinbase_cannot_be_checked() {
+        let mut coinbase = spend(vec![p2wsh_output(100_000)]);
+        coinbase.input[0].previous_output = OutPoint::default();
+
+        assert_eq!(
+            check_standard(&coinbase, &[], &chain(), &PolicySettings::new(&chain())).unwrap_err(),
+            Error::Coinbase
+        );
+    }
+
+    #[test]
+    fn spent_outputs_must_match_inputs() {
+        let transaction = spend(vec![p2wsh_output(100_000)]);
+
+        assert_eq!(
+            check_standard(&transaction, &[], &chain(), &PolicySettings::new(&chain()))
+                .unwrap_err(),
+            Error::SpentOutputsMismatch
+        );
+    }
+
+    #[test]
+    fn unknown_reason_is_unrecognized() {
+        let code = elements_consensus_sys::bitcoinconsensus_STANDARD_ERR_UNKNOWN;
+        assert_eq!(
+            StandardViolation::from_raw(code, 0, 0),
+            StandardViolation::Unrecognized(code)
+        );
+    }
+}
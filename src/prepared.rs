@@ -0,0 +1,255 @@
+use crate::trace::{self, ExecutionTrace};
+use crate::{ffi, ChainParams, ConsensusViolation, Error, ScriptError, VerificationFlags};
+use elements::hashes::Hash;
+use elements::{confidential, encode::serialize, Script, Transaction, TxOut};
+use std::ptr::NonNull;
+
+/// A transaction that has been handed to the consensus library once, ready to have its inputs verified.
+///
+/// [`verify`](crate::verify) serializes the transaction, lets the consensus library deserialize it and precomputes the signature hashing data on every call.
+/// Preparing a transaction does this work only once, which makes verifying many of its inputs considerably cheaper.
+///
+/// Spends of Taproot outputs can only be verified if the transaction was prepared [`with_spent_outputs`](PreparedTransaction::with_spent_outputs).
+///
+/// A prepared transaction can be shared between threads to verify its inputs in parallel, see [`verify_parallel`](crate::verify_parallel).
+pub struct PreparedTransaction {
+    inner: NonNull<elements_consensus_sys::bitcoinconsensus_transaction>,
+    inputs: usize,
+}
+
+impl PreparedTransaction {
+    /// Prepares `transaction` for verifying its inputs.
+    ///
+    /// The result does not depend on the chain: only Taproot signatures commit to a genesis block, and spends of Taproot outputs are reported as [`Error::SpentOutputsRequired`] unless the transaction is prepared [`with_spent_outputs`](PreparedTransaction::with_spent_outputs) for a [`ChainParams`].
+    pub fn new(transaction: &Transaction) -> Result<Self, Error> {
+        let serialized = serialize(transaction);
+
+        let inner = ffi::create(|err| unsafe {
+            elements_consensus_sys::bitcoinconsensus_transaction_create(
+                // no genesis hash, which only the Taproot signature hash commits to
+                std::ptr::null(),
+                serialized.as_ptr(),
+                serialized.len() as u32,
+                err,
+            )
+        })?;
+
+        Ok(Self {
+            inner,
+            inputs: transaction.input.len(),
+        })
+    }
+
+    /// Prepares `transaction` for verifying its inputs, including spends of Taproot outputs.
+    ///
+    /// `spent_outputs` are the outputs spent by all inputs of `transaction`, in the order of the inputs.
+    /// Taproot signatures commit to the genesis block of the chain they are made for, which is taken from `chain`.
+    pub fn with_spent_outputs(
+        transaction: &Transaction,
+        spent_outputs: &[TxOut],
+        chain: &ChainParams,
+    ) -> Result<Self, Error> {
+        if transaction.input.len() != spent_outputs.len() {
+            return Err(Error::SpentOutputsMismatch);
+        }
+
+        let serialized = serialize(transaction);
+        let spent_outputs = serialize(&spent_outputs.to_vec());
+
+        let inner = ffi::create(|err| unsafe {
+            elements_consensus_sys::bitcoinconsensus_transaction_create_with_spent_outputs(
+                chain.genesis_hash.as_inner().as_ptr(),
+                serialized.as_ptr(),
+                serialized.len() as u32,
+                spent_outputs.as_ptr(),
+                spent_outputs.len() as u32,
+                err,
+            )
+        })?;
+
+        Ok(Self {
+            inner,
+            inputs: transaction.input.len(),
+        })
+    }
+
+    /// Verifies that the `coins` locked by `script` are unlocked by input `index` according to the consensus rules.
+    ///
+    /// Returns the same two layers of errors as [`verify`](crate::verify).
+    pub fn verify(
+        &self,
+        script: &Script,
+        coins: &confidential::Value,
+        index: usize,
+    ) -> Result<Result<(), ConsensusViolation>, Error> {
+        self.verify_with_flags(script, coins, index, VerificationFlags::ALL)
+    }
+
+    /// Verifies that the `coins` locked by `script` are unlocked by input `index`, enforcing only the rules in `flags`.
+    ///
+    /// Returns the same two layers of errors as [`verify_with_flags`](crate::verify_with_flags).
+    pub fn verify_with_flags(
+        &self,
+        script: &Script,
+        coins: &confidential::Value,
+        index: usize,
+        flags: VerificationFlags,
+    ) -> Result<Result<(), ConsensusViolation>, Error> {
+        self.verify_input(script, coins, index, flags, None)
+    }
+
+    /// Verifies input `index` like [`verify_with_flags`](PreparedTransaction::verify_with_flags), recording every step of the interpreter.
+    pub fn trace(
+        &self,
+        script: &Script,
+        coins: &confidential::Value,
+        index: usize,
+        flags: VerificationFlags,
+    ) -> Result<ExecutionTrace, Error> {
+        let mut scripts = Vec::new();
+        let tracer = trace::tracer(&mut scripts);
+
+        let result = self.verify_input(script, coins, index, flags, Some(&tracer))?;
+
+        Ok(ExecutionTrace {
+            scripts,
+            result: result.map_err(|violation| violation.script_error),
+        })
+    }
+
+    fn verify_input(
+        &self,
+        script: &Script,
+        coins: &confidential::Value,
+        index: usize,
+        flags: VerificationFlags,
+        tracer: Option<&elements_consensus_sys::bitcoinconsensus_tracer>,
+    ) -> Result<Result<(), ConsensusViolation>, Error> {
+        if self.inputs <= index {
+            return Err(Error::IndexOutOfBounds);
+        }
+
+        let script = script.as_bytes();
+        let coins = serialize(coins);
+
+        let mut script_err = 0;
+
+        let verified = ffi::call(|err| unsafe {
+            match tracer {
+                Some(tracer) => elements_consensus_sys::bitcoinconsensus_transaction_trace_input(
+                    self.inner.as_ptr(),
+                    script.as_ptr(),
+                    script.len() as u32,
+                    coins.as_ptr(),
+                    coins.len() as u32,
+                    index as u32,
+                    flags.bits(),
+                    tracer,
+                    err,
+                    &mut script_err,
+                ),
+                None => elements_consensus_sys::bitcoinconsensus_transaction_verify_input(
+                    self.inner.as_ptr(),
+                    script.as_ptr(),
+                    script.len() as u32,
+                    coins.as_ptr(),
+                    coins.len() as u32,
+                    index as u32,
+                    flags.bits(),
+                    err,
+                    &mut script_err,
+                ),
+            }
+        })?;
+
+        if verified {
+            Ok(Ok(()))
+        } else {
+            Ok(Err(ConsensusViolation {
+                script_error: ScriptError::from_raw(script_err),
+            }))
+        }
+    }
+}
+
+impl Drop for PreparedTransaction {
+    fn drop(&mut self) {
+        unsafe { elements_consensus_sys::bitcoinconsensus_transaction_destroy(self.inner.as_ptr()) }
+    }
+}
+
+// The consensus library only reads a transaction after preparing it, so it can be verified from any number of threads at once.
+unsafe impl Send for PreparedTransaction {}
+unsafe impl Sync for PreparedTransaction {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TX_HEX;
+    use elements::encode::deserialize;
+    use elements::opcodes::all::OP_RETURN;
+    use elements::script::Builder;
+    use hex_literal::hex;
+
+    #[test]
+    fn prepared_transaction_verifies_inputs_repeatedly() {
+        let address = "H1YukBju4An78pumXgTcu31DyKwmYLqgi1"
+            .parse::<elements::Address>()
+            .unwrap();
+        let coins = confidential::Value::from_commitment(&hex!(
+            "08b0faffc84b19acd0001e5c615d058d7bec15cce82253ac416300f52bbb4a2bf9"
+        ))
+        .unwrap();
+        let transaction = deserialize(TX_HEX).unwrap();
+
+        let prepared = PreparedTransaction::new(&transaction).unwrap();
+
+        for _ in 0..3 {
+            prepared
+                .verify(&address.script_pubkey(), &coins, 0)
+                .unwrap()
+                .unwrap();
+        }
+        let violation = prepared
+            .verify(&address.script_pubkey(), &coins, 1)
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(violation.script_error(), ScriptError::EvalFalse);
+    }
+
+    #[test]
+    fn prepared_transaction_reports_errors_like_verify_with_flags() {
+        let transaction = deserialize(TX_HEX).unwrap();
+        let script = Builder::new().push_opcode(OP_RETURN).into_script();
+
+        let prepared = PreparedTransaction::new(&transaction).unwrap();
+
+        assert_eq!(
+            prepared
+                .verify_with_flags(
+                    &script,
+                    &confidential::Value::Null,
+                    2,
+                    VerificationFlags::ALL
+                )
+                .unwrap_err(),
+            Error::IndexOutOfBounds
+        );
+        assert_eq!(
+            prepared
+                .verify_with_flags(
+                    &script,
+                    &confidential::Value::Null,
+                    0,
+                    VerificationFlags::WITNESS
+                )
+                .unwrap_err(),
+            Error::InvalidFlags
+        );
+        let violation = prepared
+            .verify(&script, &confidential::Value::Null, 0)
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(violation.script_error(), ScriptError::OpReturn);
+    }
+}
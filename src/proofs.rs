@@ -0,0 +1,209 @@
+use crate::{ffi, Error};
+use elements::{encode::serialize, Transaction, TxOut};
+use std::{error::Error as StdError, fmt};
+
+/// A proof in the witness of an output that does not verify.
+///
+/// A rangeproof is invalid if it is missing for a value commitment, does not prove the committed value to be in range, or is present on an explicit value.
+/// The same holds for a surjection proof, which has to prove that the committed asset is one of the assets spent or issued by the transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProofViolation {
+    /// The rangeproof of output `output` is invalid.
+    InvalidRangeproof { output: usize },
+    /// The surjection proof of output `output` is invalid.
+    InvalidSurjectionProof { output: usize },
+}
+
+impl fmt::Display for ProofViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProofViolation::InvalidRangeproof { output } => {
+                write!(f, "rangeproof of output {} is invalid", output)
+            }
+            ProofViolation::InvalidSurjectionProof { output } => {
+                write!(f, "surjection proof of output {} is invalid", output)
+            }
+        }
+    }
+}
+
+impl StdError for ProofViolation {}
+
+/// Verifies the rangeproof and surjection proof of every output of `transaction`, as done by Elements before accepting it.
+///
+/// `spent_outputs` are the outputs spent by all inputs of `transaction`, in the order of the inputs.
+/// Together with the issuances of `transaction`, their assets are the ones the surjection proofs have to map to.
+///
+/// Unlike [`verify_amounts`](crate::verify_amounts), this function neither checks that the amounts balance nor stops at the first invalid proof.
+/// Instead, it reports all invalid proofs, ordered by output.
+pub fn verify_output_proofs(
+    transaction: &Transaction,
+    spent_outputs: &[TxOut],
+) -> Result<Result<(), Vec<ProofViolation>>, Error> {
+    if transaction.is_coinbase() {
+        return Err(Error::Coinbase);
+    }
+    if transaction.input.len() != spent_outputs.len() {
+        return Err(Error::SpentOutputsMismatch);
+    }
+
+    let mut output_proofs = vec![0u8; transaction.output.len()];
+    let transaction = serialize(transaction);
+    let spent_outputs = serialize(&spent_outputs.to_vec());
+
+    let verified = ffi::call(|err| unsafe {
+        elements_consensus_sys::bitcoinconsensus_verify_output_proofs(
+            transaction.as_ptr(),
+            transaction.len() as u32,
+            spent_outputs.as_ptr(),
+            spent_outputs.len() as u32,
+            output_proofs.as_mut_ptr(),
+            output_proofs.len() as u32,
+            err,
+        )
+    })?;
+
+    if verified {
+        Ok(Ok(()))
+    } else {
+        Ok(Err(ProofViolation::from_raw(&output_proofs)))
+    }
+}
+
+impl ProofViolation {
+    fn from_raw(output_proofs: &[u8]) -> Vec<Self> {
+        let mut violations = Vec::new();
+
+        for (output, &flags) in output_proofs.iter().enumerate() {
+            let flags = u32::from(flags);
+            if flags & elements_consensus_sys::bitcoinconsensus_OUTPUT_PROOFS_INVALID_RANGEPROOF
+                != 0
+            {
+                violations.push(ProofViolation::InvalidRangeproof { output });
+            }
+            if flags
+                & elements_consensus_sys::bitcoinconsensus_OUTPUT_PROOFS_INVALID_SURJECTIONPROOF
+                != 0
+            {
+                violations.push(ProofViolation::InvalidSurjectionProof { output });
+            }
+        }
+
+        violations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use elements::confidential::{Asset, AssetBlindingFactor, Nonce, Value, ValueBlindingFactor};
+    use elements::issuance::AssetId;
+    use elements::secp256k1_zkp::rand::thread_rng;
+    use elements::secp256k1_zkp::{PublicKey, Secp256k1, SecretKey};
+    use elements::{AssetIssuance, OutPoint, Script, TxIn, TxOutSecrets};
+
+    fn asset() -> AssetId {
+        AssetId::from_slice(&[1; 32]).unwrap()
+    }
+
+    fn explicit_output(value: u64) -> TxOut {
+        TxOut {
+            asset: Asset::Explicit(asset()),
+            value: Value::Explicit(value),
+            script_pubkey: Script::from(vec![0x51]),
+            ..Default::default()
+        }
+    }
+
+    /// Spends `spent_output` to two blinded outputs and a fee.
+    fn blinded_spend(spent_output: &TxOut) -> Transaction {
+        let secp = Secp256k1::new();
+        let blinding_key =
+            PublicKey::from_secret_key(&secp, &SecretKey::from_slice(&[2; 32]).unwrap());
+        let blinded_output = |value| TxOut {
+            script_pubkey: Script::from(vec![0x51]).to_v0_p2wsh(),
+            nonce: Nonce::Confidential(blinding_key),
+            ..explicit_output(value)
+        };
+
+        let mut transaction = Transaction {
+            version: 2,
+            lock_time: 0,
+            input: vec![TxIn {
+                previous_output: OutPoint {
+                    vout: 0,
+                    ..Default::default()
+                },
+                is_pegin: false,
+                has_issuance: false,
+                script_sig: Script::new(),
+                sequence: 0xffff_ffff,
+                asset_issuance: AssetIssuance::default(),
+                witness: Default::default(),
+            }],
+            output: vec![
+                blinded_output(60_000),
+                blinded_output(39_000),
+                TxOut::new_fee(1_000, asset()),
+            ],
+        };
+        let secrets = TxOutSecrets::new(
+            asset(),
+            AssetBlindingFactor::zero(),
+            100_000,
+            ValueBlindingFactor::zero(),
+        );
+        transaction
+            .blind(&mut thread_rng(), &secp, &[(spent_output.asset, &secrets)])
+            .unwrap();
+
+        transaction
+    }
+
+    #[test]
+    fn blinded_outputs_have_valid_proofs() {
+        let spent_outputs = [explicit_output(100_000)];
+        let transaction = blinded_spend(&spent_outputs[0]);
+
+        verify_output_proofs(&transaction, &spent_outputs)
+            .unwrap()
+            .unwrap();
+        crate::verify_amounts(&transaction, &spent_outputs)
+            .unwrap()
+            .unwrap();
+    }
+
+    #[test]
+    fn reports_every_invalid_proof() {
+        let spent_output = explicit_output(100_000);
+        let mut transaction = blinded_spend(&spent_output);
+
+        let swapped_rangeproof = transaction.output[0].witness.rangeproof.take();
+        transaction.output[2].witness.rangeproof = swapped_rangeproof;
+        let other_asset = TxOut {
+            asset: Asset::Explicit(AssetId::from_slice(&[3; 32]).unwrap()),
+            ..spent_output
+        };
+
+        assert_eq!(
+            verify_output_proofs(&transaction, &[other_asset]).unwrap(),
+            Err(vec![
+                ProofViolation::InvalidRangeproof { output: 0 },
+                ProofViolation::InvalidSurjectionProof { output: 0 },
+                ProofViolation::InvalidSurjectionProof { output: 1 },
+                ProofViolation::InvalidRangeproof { output: 2 },
+            ])
+        );
+    }
+
+    #[test]
+    fn coinbase_proofs_cannot_be_verified() {
+        let mut coinbase = blinded_spend(&explicit_output(100_000));
+        coinbase.input[0].previous_output = OutPoint::default();
+
+        assert_eq!(
+            verify_output_proofs(&coinbase, &[]).unwrap_err(),
+            Error::Coinbase
+        );
+    }
+}
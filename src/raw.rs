@@ -0,0 +1,144 @@
+use crate::{ffi, ConsensusViolation, Error, ScriptError, VerificationFlags};
+use std::convert::TryFrom;
+
+/// Verifies that the output locked by `script` and worth `amount` is unlocked by input `index` in the serialized transaction `tx`.
+///
+/// Unlike [`verify`](crate::verify), this function takes the arguments in their consensus serialization, the way they are received from the network or an RPC.
+/// `amount` is a serialized [`confidential::Value`](elements::confidential::Value): either an explicit value or a commitment.
+/// None of the arguments are parsed on the Rust side, so any error in them is reported by the consensus library:
+/// - [`Error::TxDeserialize`] if `tx` or `amount` cannot be deserialized,
+/// - [`Error::TxSizeMismatch`] if `tx` has trailing data after the transaction,
+/// - [`Error::IndexOutOfBounds`] if `tx` has no input `index`,
+/// - [`Error::SpentOutputsRequired`] if `script` is a Taproot output.
+///
+/// Like [`verify`](crate::verify), this function returns two layers of errors.
+pub fn verify_raw(
+    script: &[u8],
+    amount: &[u8],
+    tx: &[u8],
+    index: usize,
+) -> Result<Result<(), ConsensusViolation>, Error> {
+    verify_raw_with_flags(script, amount, tx, index, VerificationFlags::ALL)
+}
+
+/// Verifies input `index` in the serialized transaction `tx` like [`verify_raw`], enforcing only the rules in `flags`.
+pub fn verify_raw_with_flags(
+    script: &[u8],
+    amount: &[u8],
+    tx: &[u8],
+    index: usize,
+    flags: VerificationFlags,
+) -> Result<Result<(), ConsensusViolation>, Error> {
+    let index = u32::try_from(index).map_err(|_| Error::IndexOutOfBounds)?;
+    let mut script_err = 0;
+
+    let verified = ffi::call(|err| unsafe {
+        elements_consensus_sys::bitcoinconsensus_verify_script_with_amount_and_script_error(
+            std::ptr::null(),
+            script.as_ptr(),
+            script.len() as u32,
+            amount.as_ptr(),
+            amount.len() as u32,
+            tx.as_ptr(),
+            tx.len() as u32,
+            index,
+            flags.bits(),
+            err,
+            &mut script_err,
+        )
+    })?;
+
+    if verified {
+        Ok(Ok(()))
+    } else {
+        Ok(Err(ConsensusViolation {
+            script_error: ScriptError::from_raw(script_err),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TX_HEX;
+    use elements::confidential;
+    use elements::encode::serialize;
+    use hex_literal::hex;
+
+    fn script() -> Vec<u8> {
+        "H1YukBju4An78pumXgTcu31DyKwmYLqgi1"
+            .parse::<elements::Address>()
+            .unwrap()
+            .script_pubkey()
+            .into_bytes()
+    }
+
+    fn amount() -> Vec<u8> {
+        serialize(
+            &confidential::Value::from_commitment(&hex!(
+                "08b0faffc84b19acd0001e5c615d058d7bec15cce82253ac416300f52bbb4a2bf9"
+            ))
+            .unwrap(),
+        )
+    }
+
+    #[test]
+    fn raw_mainnet_transaction_verifies() {
+        verify_raw(&script(), &amount(), TX_HEX, 0)
+            .unwrap()
+            .unwrap();
+    }
+
+    #[test]
+    fn raw_wrong_amount_does_not_verify() {
+        let amount = serialize(&confidential::Value::Explicit(1));
+
+        let violation = verify_raw(&script(), &amount, TX_HEX, 0)
+            .unwrap()
+            .unwrap_err();
+
+        assert_eq!(violation.script_error(), ScriptError::EvalFalse);
+    }
+
+    #[test]
+    fn raw_transaction_with_trailing_data_is_size_mismatch() {
+        let mut tx = TX_HEX.to_vec();
+        tx.push(0);
+
+        let result = verify_raw(&script(), &amount(), &tx, 0);
+
+        assert_eq!(result.unwrap_err(), Error::TxSizeMismatch);
+    }
+
+    #[test]
+    fn truncated_raw_transaction_is_deserialize_error() {
+        let tx = &TX_HEX[..TX_HEX.len() / 2];
+
+        let result = verify_raw(&script(), &amount(), tx, 0);
+
+        assert_eq!(result.unwrap_err(), Error::TxDeserialize);
+    }
+
+    #[test]
+    fn malformed_raw_amount_is_deserialize_error() {
+        let result = verify_raw(&script(), &[0x08], TX_HEX, 0);
+
+        assert_eq!(result.unwrap_err(), Error::TxDeserialize);
+    }
+
+    #[test]
+    fn raw_index_out_of_bounds_is_reported_by_library() {
+        let result = verify_raw(&script(), &amount(), TX_HEX, 2);
+
+        assert_eq!(result.unwrap_err(), Error::IndexOutOfBounds);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn raw_index_beyond_u32_is_out_of_bounds() {
+        // Truncated to 32 bits, this would be input 0, which verifies.
+        let result = verify_raw(&script(), &amount(), TX_HEX, 1 << 32);
+
+        assert_eq!(result.unwrap_err(), Error::IndexOutOfBounds);
+    }
+}
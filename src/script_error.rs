@@ -0,0 +1,290 @@
+use std::{error::Error, fmt};
+
+/// The reason why the interpreter rejected a script.
+///
+/// Mirrors `ScriptError` in `script/script_error.h` of the vendored Elements code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde-feature", derive(serde::Serialize))]
+pub enum ScriptError {
+    /// The interpreter failed for an unspecified reason.
+    UnknownError,
+    /// The script finished with a false or empty top stack element.
+    EvalFalse,
+    /// An `OP_RETURN` was executed.
+    OpReturn,
+
+    // Max sizes
+    /// The script is larger than 10,000 bytes.
+    ScriptSize,
+    /// A pushed element is larger than 520 bytes.
+    PushSize,
+    /// The script executes more than 201 non-push opcodes.
+    OpCount,
+    /// The stack and altstack hold more than 1,000 elements.
+    StackSize,
+    /// The signature count of an `OP_CHECKMULTISIG` is negative or greater than its key count.
+    SigCount,
+    /// The key count of an `OP_CHECKMULTISIG` is negative or greater than 20.
+    PubkeyCount,
+
+    // Failed verify operations
+    /// An `OP_VERIFY` failed.
+    Verify,
+    /// An `OP_EQUALVERIFY` failed.
+    EqualVerify,
+    /// An `OP_CHECKMULTISIGVERIFY` failed.
+    CheckMultisigVerify,
+    /// An `OP_CHECKSIGVERIFY` failed.
+    CheckSigVerify,
+    /// An `OP_NUMEQUALVERIFY` failed.
+    NumEqualVerify,
+
+    // Logical/Format/Canonical errors
+    /// An opcode is missing or not understood.
+    BadOpcode,
+    /// A disabled opcode was encountered.
+    DisabledOpcode,
+    /// An operation needs more elements than the stack holds.
+    InvalidStackOperation,
+    /// An operation needs more elements than the altstack holds.
+    InvalidAltstackOperation,
+    /// An `OP_IF` is not closed or an `OP_ELSE` or `OP_ENDIF` has no `OP_IF`.
+    UnbalancedConditional,
+
+    // CHECKLOCKTIMEVERIFY and CHECKSEQUENCEVERIFY
+    /// The argument of `OP_CHECKLOCKTIMEVERIFY` or `OP_CHECKSEQUENCEVERIFY` is negative.
+    NegativeLocktime,
+    /// The lock time or sequence of the transaction does not satisfy `OP_CHECKLOCKTIMEVERIFY` or
+    /// `OP_CHECKSEQUENCEVERIFY`.
+    UnsatisfiedLocktime,
+
+    // Malleability
+    /// The sighash type of a signature is missing or not understood.
+    SigHashtype,
+    /// A signature is not strictly DER encoded.
+    SigDer,
+    /// A push does not use the smallest possible encoding.
+    MinimalData,
+    /// The `script_sig` contains an opcode that is not a push.
+    SigPushOnly,
+    /// A signature has an S value above half the curve order.
+    SigHighS,
+    /// The dummy argument of an `OP_CHECKMULTISIG` is not empty.
+    SigNullDummy,
+    /// A public key is neither compressed nor uncompressed.
+    PubkeyType,
+    /// The stack does not hold exactly one element after execution.
+    CleanStack,
+    /// The argument of an `OP_IF` or `OP_NOTIF` in a segwit v0 script is not empty or `0x01`.
+    MinimalIf,
+    /// A signature that failed `OP_CHECKSIG` or `OP_CHECKMULTISIG` is not empty.
+    SigNullFail,
+
+    // Softfork safeness
+    /// An `OP_NOPx` reserved for soft forks was executed.
+    DiscourageUpgradableNops,
+    /// A witness program of a version reserved for soft forks was spent.
+    DiscourageUpgradableWitnessProgram,
+    /// A taproot leaf version reserved for soft forks was spent.
+    DiscourageUpgradableTaprootVersion,
+    /// A tapscript contains an `OP_SUCCESSx` reserved for soft forks.
+    DiscourageOpSuccess,
+    /// A tapscript public key type reserved for soft forks was used.
+    DiscourageUpgradablePubkeyType,
+
+    // Segregated witness
+    /// A witness program has an invalid length for its version.
+    WitnessProgramWrongLength,
+    /// A witness program was spent with an empty witness.
+    WitnessProgramWitnessEmpty,
+    /// The witness does not match the hash committed to by the witness program.
+    WitnessProgramMismatch,
+    /// A native witness program was spent with a non-empty `script_sig`.
+    WitnessMalleated,
+    /// A P2SH witness program was spent with a `script_sig` that is not only its redeem script.
+    WitnessMalleatedP2sh,
+    /// A witness was given for an output that is not a witness program.
+    WitnessUnexpected,
+    /// A segwit v0 script uses an uncompressed public key.
+    WitnessPubkeyType,
+
+    // Taproot
+    /// A Schnorr signature is neither 64 nor 65 bytes.
+    SchnorrSigSize,
+    /// The sighash type of a Schnorr signature is invalid.
+    SchnorrSigHashtype,
+    /// A Schnorr signature is invalid.
+    SchnorrSig,
+    /// The control block of a taproot script path spend has an invalid size.
+    TaprootWrongControlSize,
+    /// A tapscript checks more signatures than the weight of its witness allows.
+    TapscriptValidationWeight,
+    /// A tapscript uses `OP_CHECKMULTISIG` or `OP_CHECKMULTISIGVERIFY`.
+    TapscriptCheckMultisig,
+    /// The argument of an `OP_IF` or `OP_NOTIF` in a tapscript is not empty or `0x01`.
+    TapscriptMinimalIf,
+
+    // Constant scriptCode
+    /// A non-witness script uses `OP_CODESEPARATOR`.
+    OpCodeSeparator,
+    /// A non-witness script contains a signature it checks, which would be removed from its
+    /// script code.
+    SigFindAndDelete,
+
+    // Elements
+    /// A rangeproof is invalid, reported by the checks of confidential amounts rather than by
+    /// scripts.
+    Rangeproof,
+    /// The Pedersen commitments of a transaction do not balance, reported by the checks of
+    /// confidential amounts rather than by scripts.
+    PedersenTally,
+
+    /// The interpreter reported an error code that is not known to this crate.
+    Unrecognized(u32),
+}
+
+impl ScriptError {
+    /// Converts the raw `ScriptError` reported by the consensus library.
+    pub(crate) fn from_raw(code: u32) -> Self {
+        use elements_consensus_sys as sys;
+        use ScriptError::*;
+
+        match code {
+            sys::ScriptError_t_SCRIPT_ERR_UNKNOWN_ERROR => UnknownError,
+            sys::ScriptError_t_SCRIPT_ERR_EVAL_FALSE => EvalFalse,
+            sys::ScriptError_t_SCRIPT_ERR_OP_RETURN => OpReturn,
+            sys::ScriptError_t_SCRIPT_ERR_SCRIPT_SIZE => ScriptSize,
+            sys::ScriptError_t_SCRIPT_ERR_PUSH_SIZE => PushSize,
+            sys::ScriptError_t_SCRIPT_ERR_OP_COUNT => OpCount,
+            sys::ScriptError_t_SCRIPT_ERR_STACK_SIZE => StackSize,
+            sys::ScriptError_t_SCRIPT_ERR_SIG_COUNT => SigCount,
+            sys::ScriptError_t_SCRIPT_ERR_PUBKEY_COUNT => PubkeyCount,
+            sys::ScriptError_t_SCRIPT_ERR_VERIFY => Verify,
+            sys::ScriptError_t_SCRIPT_ERR_EQUALVERIFY => EqualVerify,
+            sys::ScriptError_t_SCRIPT_ERR_CHECKMULTISIGVERIFY => CheckMultisigVerify,
+            sys::ScriptError_t_SCRIPT_ERR_CHECKSIGVERIFY => CheckSigVerify,
+            sys::ScriptError_t_SCRIPT_ERR_NUMEQUALVERIFY => NumEqualVerify,
+            sys::ScriptError_t_SCRIPT_ERR_BAD_OPCODE => BadOpcode,
+            sys::ScriptError_t_SCRIPT_ERR_DISABLED_OPCODE => DisabledOpcode,
+            sys::ScriptError_t_SCRIPT_ERR_INVALID_STACK_OPERATION => InvalidStackOperation,
+            sys::ScriptError_t_SCRIPT_ERR_INVALID_ALTSTACK_OPERATION => InvalidAltstackOperation,
+            sys::ScriptError_t_SCRIPT_ERR_UNBALANCED_CONDITIONAL => UnbalancedConditional,
+            sys::ScriptError_t_SCRIPT_ERR_NEGATIVE_LOCKTIME => NegativeLocktime,
+            sys::ScriptError_t_SCRIPT_ERR_UNSATISFIED_LOCKTIME => UnsatisfiedLocktime,
+            sys::ScriptError_t_SCRIPT_ERR_SIG_HASHTYPE => SigHashtype,
+            sys::ScriptError_t_SCRIPT_ERR_SIG_DER => SigDer,
+            sys::ScriptError_t_SCRIPT_ERR_MINIMALDATA => MinimalData,
+            sys::ScriptError_t_SCRIPT_ERR_SIG_PUSHONLY => SigPushOnly,
+            sys::ScriptError_t_SCRIPT_ERR_SIG_HIGH_S => SigHighS,
+            sys::ScriptError_t_SCRIPT_ERR_SIG_NULLDUMMY => SigNullDummy,
+            sys::ScriptError_t_SCRIPT_ERR_PUBKEYTYPE => PubkeyType,
+            sys::ScriptError_t_SCRIPT_ERR_CLEANSTACK => CleanStack,
+            sys::ScriptError_t_SCRIPT_ERR_MINIMALIF => MinimalIf,
+            sys::ScriptError_t_SCRIPT_ERR_SIG_NULLFAIL => SigNullFail,
+            sys::ScriptError_t_SCRIPT_ERR_DISCOURAGE_UPGRADABLE_NOPS => DiscourageUpgradableNops,
+            sys::ScriptError_t_SCRIPT_ERR_DISCOURAGE_UPGRADABLE_WITNESS_PROGRAM => {
+                DiscourageUpgradableWitnessProgram
+            }
+            sys::ScriptError_t_SCRIPT_ERR_DISCOURAGE_UPGRADABLE_TAPROOT_VERSION => {
+                DiscourageUpgradableTaprootVersion
+            }
+            sys::ScriptError_t_SCRIPT_ERR_DISCOURAGE_OP_SUCCESS => DiscourageOpSuccess,
+            sys::ScriptError_t_SCRIPT_ERR_DISCOURAGE_UPGRADABLE_PUBKEYTYPE => {
+                DiscourageUpgradablePubkeyType
+            }
+            sys::ScriptError_t_SCRIPT_ERR_WITNESS_PROGRAM_WRONG_LENGTH => WitnessProgramWrongLength,
+            sys::ScriptError_t_SCRIPT_ERR_WITNESS_PROGRAM_WITNESS_EMPTY => {
+                WitnessProgramWitnessEmpty
+            }
+            sys::ScriptError_t_SCRIPT_ERR_WITNESS_PROGRAM_MISMATCH => WitnessProgramMismatch,
+            sys::ScriptError_t_SCRIPT_ERR_WITNESS_MALLEATED => WitnessMalleated,
+            sys::ScriptError_t_SCRIPT_ERR_WITNESS_MALLEATED_P2SH => WitnessMalleatedP2sh,
+            sys::ScriptError_t_SCRIPT_ERR_WITNESS_UNEXPECTED => WitnessUnexpected,
+            sys::ScriptError_t_SCRIPT_ERR_WITNESS_PUBKEYTYPE => WitnessPubkeyType,
+            sys::ScriptError_t_SCRIPT_ERR_SCHNORR_SIG_SIZE => SchnorrSigSize,
+            sys::ScriptError_t_SCRIPT_ERR_SCHNORR_SIG_HASHTYPE => SchnorrSigHashtype,
+            sys::ScriptError_t_SCRIPT_ERR_SCHNORR_SIG => SchnorrSig,
+            sys::ScriptError_t_SCRIPT_ERR_TAPROOT_WRONG_CONTROL_SIZE => TaprootWrongControlSize,
+            sys::ScriptError_t_SCRIPT_ERR_TAPSCRIPT_VALIDATION_WEIGHT => TapscriptValidationWeight,
+            sys::ScriptError_t_SCRIPT_ERR_TAPSCRIPT_CHECKMULTISIG => TapscriptCheckMultisig,
+            sys::ScriptError_t_SCRIPT_ERR_TAPSCRIPT_MINIMALIF => TapscriptMinimalIf,
+            sys::ScriptError_t_SCRIPT_ERR_OP_CODESEPARATOR => OpCodeSeparator,
+            sys::ScriptError_t_SCRIPT_ERR_SIG_FINDANDDELETE => SigFindAndDelete,
+            sys::ScriptError_t_SCRIPT_ERR_RANGEPROOF => Rangeproof,
+            sys::ScriptError_t_SCRIPT_ERR_PEDERSEN_TALLY => PedersenTally,
+            // SCRIPT_ERR_OK and SCRIPT_ERR_ERROR_COUNT are not errors
+            code => Unrecognized(code),
+        }
+    }
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use ScriptError::*;
+
+        // Messages are taken from `ScriptErrorString` in `script/script_error.cpp`.
+        let msg = match self {
+            UnknownError => "unknown error",
+            EvalFalse => {
+                "Script evaluated without error but finished with a false/empty top stack element"
+            }
+            OpReturn => "OP_RETURN was encountered",
+            ScriptSize => "Script is too big",
+            PushSize => "Push value size limit exceeded",
+            OpCount => "Operation limit exceeded",
+            StackSize => "Stack size limit exceeded",
+            SigCount => "Signature count negative or greater than pubkey count",
+            PubkeyCount => "Pubkey count negative or limit exceeded",
+            Verify => "Script failed an OP_VERIFY operation",
+            EqualVerify => "Script failed an OP_EQUALVERIFY operation",
+            CheckMultisigVerify => "Script failed an OP_CHECKMULTISIGVERIFY operation",
+            CheckSigVerify => "Script failed an OP_CHECKSIGVERIFY operation",
+            NumEqualVerify => "Script failed an OP_NUMEQUALVERIFY operation",
+            BadOpcode => "Opcode missing or not understood",
+            DisabledOpcode => "Attempted to use a disabled opcode",
+            InvalidStackOperation => "Operation not valid with the current stack size",
+            InvalidAltstackOperation => "Operation not valid with the current altstack size",
+            UnbalancedConditional => "Invalid OP_IF construction",
+            NegativeLocktime => "Negative locktime",
+            UnsatisfiedLocktime => "Locktime requirement not satisfied",
+            SigHashtype => "Signature hash type missing or not understood",
+            SigDer => "Non-canonical DER signature",
+            MinimalData => "Data push larger than necessary",
+            SigPushOnly => "Only push operators allowed in signatures",
+            SigHighS => "Non-canonical signature: S value is unnecessarily high",
+            SigNullDummy => "Dummy CHECKMULTISIG argument must be zero",
+            PubkeyType => "Public key is neither compressed or uncompressed",
+            CleanStack => "Stack size must be exactly one after execution",
+            MinimalIf => "OP_IF/NOTIF argument must be minimal",
+            SigNullFail => "Signature must be zero for failed CHECK(MULTI)SIG operation",
+            DiscourageUpgradableNops => "NOPx reserved for soft-fork upgrades",
+            DiscourageUpgradableWitnessProgram => "Witness version reserved for soft-fork upgrades",
+            DiscourageUpgradableTaprootVersion => "Taproot version reserved for soft-fork upgrades",
+            DiscourageOpSuccess => "OP_SUCCESSx reserved for soft-fork upgrades",
+            DiscourageUpgradablePubkeyType => "Public key version reserved for soft-fork upgrades",
+            WitnessProgramWrongLength => "Witness program has incorrect length",
+            WitnessProgramWitnessEmpty => "Witness program was passed an empty witness",
+            WitnessProgramMismatch => "Witness program hash mismatch",
+            WitnessMalleated => "Witness requires empty scriptSig",
+            WitnessMalleatedP2sh => "Witness requires only-redeemscript scriptSig",
+            WitnessUnexpected => "Witness provided for non-witness script",
+            WitnessPubkeyType => "Using non-compressed keys in segwit",
+            SchnorrSigSize => "Invalid Schnorr signature size",
+            SchnorrSigHashtype => "Invalid Schnorr signature hash type",
+            SchnorrSig => "Invalid Schnorr signature",
+            TaprootWrongControlSize => "Invalid Taproot control block size",
+            TapscriptValidationWeight => "Too much signature validation relative to witness weight",
+            TapscriptCheckMultisig => "OP_CHECKMULTISIG(VERIFY) is not available in tapscript",
+            TapscriptMinimalIf => "OP_IF/NOTIF argument must be minimal in tapscript",
+            OpCodeSeparator => "Using OP_CODESEPARATOR in non-witness script",
+            SigFindAndDelete => "Signature is found in scriptCode",
+            Rangeproof => "Invalid rangeproof",
+            PedersenTally => "Pedersen commitments do not balance",
+            Unrecognized(code) => return write!(f, "unrecognized script error code {}", code),
+        };
+
+        f.write_str(msg)
+    }
+}
+
+impl Error for ScriptError {}
@@ -0,0 +1,377 @@
+use crate::{ffi, ChainParams, Error};
+use elements::hashes::{sha256, Hash};
+use elements::{confidential, encode::serialize, Script, SigHash, SigHashType, Transaction, TxOut};
+
+/// The signature algorithm that a [`signature_hash`] is computed for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SigVersion {
+    /// Bare scripts and P2SH redeem scripts.
+    Base,
+    /// P2WPKH and P2WSH (BIP143).
+    WitnessV0,
+}
+
+/// The tapscript leaf that a Taproot script path signature (BIP342) is made in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ScriptPath {
+    /// The tapleaf hash of the executed script.
+    pub leaf_hash: sha256::Hash,
+    /// The opcode position of the last executed `OP_CODESEPARATOR`, or `u32::MAX` if none was executed.
+    pub codeseparator_position: u32,
+}
+
+/// Computes the signature hash that a signature in input `index` of `transaction` commits to, as done by the consensus library.
+///
+/// `script_code` and `value` belong to the output spent by input `index`.
+/// Only [`SigVersion::WitnessV0`] signatures commit to `value`.
+///
+/// The result can be compared with the one of `elements::sighash::SigHashCache` to tell whether a signature was made over the wrong data.
+pub fn signature_hash(
+    transaction: &Transaction,
+    index: usize,
+    script_code: &Script,
+    value: &confidential::Value,
+    sighash_type: SigHashType,
+    sig_version: SigVersion,
+) -> Result<SigHash, Error> {
+    if transaction.input.len() <= index {
+        return Err(Error::IndexOutOfBounds);
+    }
+
+    let transaction = serialize(transaction);
+    let script_code = script_code.as_bytes();
+    let value = serialize(value);
+    let sig_version = match sig_version {
+        SigVersion::Base => elements_consensus_sys::bitcoinconsensus_SIGVERSION_BASE,
+        SigVersion::WitnessV0 => elements_consensus_sys::bitcoinconsensus_SIGVERSION_WITNESS_V0,
+    };
+
+    let mut hash = [0u8; 32];
+
+    let computed = ffi::call(|err| unsafe {
+        elements_consensus_sys::bitcoinconsensus_signature_hash(
+            std::ptr::null(),
+            script_code.as_ptr(),
+            script_code.len() as u32,
+            value.as_ptr(),
+            value.len() as u32,
+            transaction.as_ptr(),
+            transaction.len() as u32,
+            index as u32,
+            sighash_type.as_u32() as i32,
+            sig_version,
+            hash.as_mut_ptr(),
+            err,
+        )
+    })?;
+
+    if !computed {
+        return Err(Error::NoResult);
+    }
+
+    Ok(SigHash::from_inner(hash))
+}
+
+/// Computes the Taproot signature hash (BIP341) that a Schnorr signature in input `index` of `transaction` commits to, as done by the consensus library.
+///
+/// `spent_outputs` are the outputs spent by all inputs of `transaction`, in the order of the inputs.
+/// `script_path` is `None` for key path spends.
+/// `hash_type` is the raw sighash byte, where `0` stands for `SIGHASH_DEFAULT`.
+/// The annex, if any, is taken from the witness of input `index`.
+/// Unlike in Bitcoin, the signature hash commits to the genesis block of `chain`.
+pub fn taproot_signature_hash(
+    transaction: &Transaction,
+    index: usize,
+    spent_outputs: &[TxOut],
+    hash_type: u8,
+    script_path: Option<ScriptPath>,
+    chain: &ChainParams,
+) -> Result<sha256::Hash, Error> {
+    if transaction.input.len() <= index {
+        return Err(Error::IndexOutOfBounds);
+    }
+    if transaction.input.len() != spent_outputs.len() {
+        return Err(Error::SpentOutputsMismatch);
+    }
+
+    let transaction = serialize(transaction);
+    let spent_outputs = serialize(&spent_outputs.to_vec());
+    let (leaf_hash, codeseparator_position) = match &script_path {
+        Some(script_path) => (
+            script_path.leaf_hash.as_inner().as_ptr(),
+            script_path.codeseparator_position,
+        ),
+        None => (std::ptr::null(), u32::MAX),
+    };
+
+    let mut hash = [0u8; 32];
+
+    let computed = ffi::call(|err| unsafe {
+        elements_consensus_sys::bitcoinconsensus_signature_hash_taproot(
+            chain.genesis_hash.as_inner().as_ptr(),
+            transaction.as_ptr(),
+            transaction.len() as u32,
+            spent_outputs.as_ptr(),
+            spent_outputs.len() as u32,
+            index as u32,
+            hash_type,
+            leaf_hash,
+            codeseparator_position,
+            hash.as_mut_ptr(),
+            err,
+        )
+    })?;
+
+    if !computed {
+        return Err(Error::NoResult);
+    }
+
+    Ok(sha256::Hash::from_inner(hash))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{key_path_spend, tagged_hash, taproot_leaf_spend, TX_HEX};
+    use crate::{verify_with_spent_outputs, ScriptError, VerificationFlags};
+    use elements::encode::deserialize;
+    use elements::opcodes::all::OP_CHECKSIG;
+    use elements::script::Builder;
+    use elements::secp256k1_zkp::{schnorrsig, Message, Secp256k1};
+    use elements::sighash::SigHashCache;
+    use elements::{OutPoint, TxIn};
+    use hex_literal::hex;
+
+    const SIGHASH_TYPES: [SigHashType; 6] = [
+        SigHashType::All,
+        SigHashType::None,
+        SigHashType::Single,
+        SigHashType::AllPlusAnyoneCanPay,
+        SigHashType::NonePlusAnyoneCanPay,
+        SigHashType::SinglePlusAnyoneCanPay,
+    ];
+
+    #[test]
+    fn signature_hash_matches_rust_elements() {
+        let transaction = deserialize::<Transaction>(TX_HEX).unwrap();
+        let script_code = "H1YukBju4An78pumXgTcu31DyKwmYLqgi1"
+            .parse::<elements::Address>()
+            .unwrap()
+            .script_pubkey();
+        let value = confidential::Value::from_commitment(&hex!(
+            "08b0faffc84b19acd0001e5c615d058d7bec15cce82253ac416300f52bbb4a2bf9"
+        ))
+        .unwrap();
+
+        let mut cache = SigHashCache::new(&transaction);
+        for &sighash_type in SIGHASH_TYPES.iter() {
+            for index in 0..transaction.input.len() {
+                assert_eq!(
+                    signature_hash(
+                        &transaction,
+                        index,
+                        &script_code,
+                        &value,
+                        sighash_type,
+                        SigVersion::Base
+                    )
+                    .unwrap(),
+                    cache.legacy_sighash(index, &script_code, sighash_type)
+                );
+                assert_eq!(
+                    signature_hash(
+                        &transaction,
+                        index,
+                        &script_code,
+                        &value,
+                        sighash_type,
+                        SigVersion::WitnessV0
+                    )
+                    .unwrap(),
+                    cache.segwitv0_sighash(index, &script_code, value, sighash_type)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn taproot_key_path_signature_over_signature_hash_verifies() {
+        let secp = Secp256k1::new();
+        let (keypair, mut transaction, spent_output) = key_path_spend();
+        let spent_outputs = [spent_output];
+
+        let sighash = taproot_signature_hash(
+            &transaction,
+            0,
+            &spent_outputs,
+            0x00,
+            None,
+            &ChainParams::elements_regtest(),
+        )
+        .unwrap();
+        let signature =
+            secp.schnorrsig_sign_no_aux_rand(&Message::from_slice(&sighash[..]).unwrap(), &keypair);
+        transaction.input[0].witness.script_witness = vec![signature.as_ref().to_vec()];
+
+        verify_with_spent_outputs(
+            &spent_outputs,
+            0,
+            &transaction,
+            VerificationFlags::ALL,
+            &ChainParams::elements_regtest(),
+        )
+        .unwrap()
+        .unwrap();
+    }
+
+    #[test]
+    fn taproot_signature_hashes_of_unsigned_transaction_verify() {
+        let secp = Secp256k1::new();
+        let (keypair, mut transaction, spent_output) = key_path_spend();
+        let mut input = transaction.input[0].clone();
+        input.witness = Default::default();
+        transaction.input = (0..3)
+            .map(|vout| TxIn {
+                previous_output: OutPoint {
+                    vout,
+                    ..Default::default()
+                },
+                ..input.clone()
+            })
+            .collect();
+        let spent_outputs = vec![spent_output; 3];
+
+        let sighashes = (0..transaction.input.len())
+            .map(|index| {
+                taproot_signature_hash(
+                    &transaction,
+                    index,
+                    &spent_outputs,
+                    0x00,
+                    None,
+                    &ChainParams::elements_regtest(),
+                )
+                .unwrap()
+            })
+            .collect::<Vec<_>>();
+        for (input, sighash) in transaction.input.iter_mut().zip(sighashes) {
+            let signature = secp
+                .schnorrsig_sign_no_aux_rand(&Message::from_slice(&sighash[..]).unwrap(), &keypair);
+            input.witness.script_witness = vec![signature.as_ref().to_vec()];
+        }
+
+        for index in 0..transaction.input.len() {
+            verify_with_spent_outputs(
+                &spent_outputs,
+                index,
+                &transaction,
+                VerificationFlags::ALL,
+                &ChainParams::elements_regtest(),
+            )
+            .unwrap()
+            .unwrap();
+        }
+    }
+
+    #[test]
+    fn taproot_signature_does_not_verify_on_another_chain() {
+        let secp = Secp256k1::new();
+        let (keypair, mut transaction, spent_output) = key_path_spend();
+        let spent_outputs = [spent_output];
+
+        let sighash = taproot_signature_hash(
+            &transaction,
+            0,
+            &spent_outputs,
+            0x00,
+            None,
+            &ChainParams::liquid(),
+        )
+        .unwrap();
+        let signature =
+            secp.schnorrsig_sign_no_aux_rand(&Message::from_slice(&sighash[..]).unwrap(), &keypair);
+        transaction.input[0].witness.script_witness = vec![signature.as_ref().to_vec()];
+
+        let on_liquid = verify_with_spent_outputs(
+            &spent_outputs,
+            0,
+            &transaction,
+            VerificationFlags::ALL,
+            &ChainParams::liquid(),
+        );
+        let on_regtest = verify_with_spent_outputs(
+            &spent_outputs,
+            0,
+            &transaction,
+            VerificationFlags::ALL,
+            &ChainParams::elements_regtest(),
+        );
+
+        on_liquid.unwrap().unwrap();
+        let violation = on_regtest.unwrap().unwrap_err();
+        assert_eq!(violation.script_error(), ScriptError::SchnorrSig);
+    }
+
+    #[test]
+    fn tapscript_signature_over_signature_hash_verifies() {
+        let secp = Secp256k1::new();
+        let (keypair, _, _) = key_path_spend();
+        let public_key = schnorrsig::PublicKey::from_keypair(&secp, &keypair);
+        let leaf_script = Builder::new()
+            .push_slice(&public_key.serialize())
+            .push_opcode(OP_CHECKSIG)
+            .into_script();
+        let (mut transaction, spent_output) = taproot_leaf_spend(leaf_script.clone());
+        let spent_outputs = [spent_output];
+
+        let mut leaf = vec![0xc0];
+        leaf.extend(serialize(&leaf_script));
+        let script_path = ScriptPath {
+            leaf_hash: sha256::Hash::from_inner(tagged_hash("TapLeaf/elements", &leaf)),
+            codeseparator_position: u32::MAX,
+        };
+        let sighash = taproot_signature_hash(
+            &transaction,
+            0,
+            &spent_outputs,
+            SigHashType::All as u8,
+            Some(script_path),
+            &ChainParams::elements_regtest(),
+        )
+        .unwrap();
+        let signature =
+            secp.schnorrsig_sign_no_aux_rand(&Message::from_slice(&sighash[..]).unwrap(), &keypair);
+        let mut signature = signature.as_ref().to_vec();
+        signature.push(SigHashType::All as u8);
+        transaction.input[0]
+            .witness
+            .script_witness
+            .insert(0, signature);
+
+        verify_with_spent_outputs(
+            &spent_outputs,
+            0,
+            &transaction,
+            VerificationFlags::ALL,
+            &ChainParams::elements_regtest(),
+        )
+        .unwrap()
+        .unwrap();
+    }
+
+    #[test]
+    fn taproot_signature_hash_rejects_undefined_hash_type() {
+        let (transaction, spent_output) = taproot_leaf_spend(Script::new());
+
+        let result = taproot_signature_hash(
+            &transaction,
+            0,
+            &[spent_output],
+            0x04,
+            None,
+            &ChainParams::elements_regtest(),
+        );
+
+        assert_eq!(result.unwrap_err(), Error::InvalidSigHash);
+    }
+}
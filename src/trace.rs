@@ -0,0 +1,306 @@
+use crate::{Error, PreparedTransaction, ScriptError, VerificationFlags};
+use elements::encode::deserialize;
+use elements::hashes::hex::ToHex;
+use elements::{confidential, opcodes, Script, Transaction};
+use std::fmt;
+use std::os::raw::{c_int, c_uint, c_void};
+
+/// The steps the interpreter took while verifying an input, as recorded by [`trace`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-feature", derive(serde::Serialize))]
+pub struct ExecutionTrace {
+    /// The executed scripts in the order of their execution, e.g. the `scriptSig` before the `scriptPubKey`.
+    pub scripts: Vec<ScriptTrace>,
+    /// The outcome of the verification.
+    ///
+    /// If a step failed, it is not part of the trace and the error is reported here.
+    pub result: Result<(), ScriptError>,
+}
+
+/// The execution of a single script.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-feature", derive(serde::Serialize))]
+pub struct ScriptTrace {
+    /// The executed script.
+    pub script: Script,
+    /// One step per opcode of `script` that was processed by the interpreter.
+    pub steps: Vec<Step>,
+}
+
+/// A single opcode processed by the interpreter, together with the stacks it left behind.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-feature", derive(serde::Serialize))]
+pub struct Step {
+    /// The byte offset of the opcode in the script.
+    pub pc: usize,
+    /// The opcode.
+    pub opcode: opcodes::All,
+    /// Whether the opcode was executed, as opposed to skipped in an unexecuted `OP_IF` branch.
+    pub executed: bool,
+    /// The main stack after the step, with the top of the stack last.
+    pub stack: Vec<Vec<u8>>,
+    /// The alt stack after the step, with the top of the stack last.
+    pub altstack: Vec<Vec<u8>>,
+}
+
+/// Verifies that the `coins` locked by `script` are unlocked by input `index` in `transaction` like [`verify_with_flags`](crate::verify_with_flags), recording every step of the interpreter.
+///
+/// Tracing is considerably slower than verifying, so it is best used to find out why a script is rejected.
+/// Use [`PreparedTransaction::trace`] to trace spends of Taproot outputs.
+pub fn trace(
+    script: Script,
+    coins: &confidential::Value,
+    index: usize,
+    transaction: &Transaction,
+    flags: VerificationFlags,
+) -> Result<ExecutionTrace, Error> {
+    if transaction.input.len() <= index {
+        return Err(Error::IndexOutOfBounds);
+    }
+
+    PreparedTransaction::new(transaction)?.trace(&script, coins, index, flags)
+}
+
+/// Creates a tracer that records into `scripts`, which must outlive its use by the consensus library.
+pub(crate) fn tracer(
+    scripts: &mut Vec<ScriptTrace>,
+) -> elements_consensus_sys::bitcoinconsensus_tracer {
+    elements_consensus_sys::bitcoinconsensus_tracer {
+        ctx: scripts as *mut Vec<ScriptTrace> as *mut c_void,
+        script: Some(trace_script),
+        step: Some(trace_step),
+    }
+}
+
+// The callbacks must not panic, as unwinding into the consensus library is undefined behaviour.
+
+unsafe extern "C" fn trace_script(ctx: *mut c_void, script: *const u8, script_len: c_uint) {
+    let scripts = &mut *(ctx as *mut Vec<ScriptTrace>);
+    let script = if script_len == 0 {
+        Script::new()
+    } else {
+        Script::from(std::slice::from_raw_parts(script, script_len as usize).to_vec())
+    };
+
+    scripts.push(ScriptTrace {
+        script,
+        steps: Vec::new(),
+    });
+}
+
+unsafe extern "C" fn trace_step(
+    ctx: *mut c_void,
+    opcode: c_uint,
+    pc: c_uint,
+    executed: c_int,
+    stack: *const u8,
+    stack_len: c_uint,
+    altstack: *const u8,
+    altstack_len: c_uint,
+) {
+    let scripts = &mut *(ctx as *mut Vec<ScriptTrace>);
+    let stack = deserialize(std::slice::from_raw_parts(stack, stack_len as usize));
+    let altstack = deserialize(std::slice::from_raw_parts(altstack, altstack_len as usize));
+
+    // the consensus library announces each script before its steps and serializes the stacks itself
+    if let (Some(script), Ok(stack), Ok(altstack)) = (scripts.last_mut(), stack, altstack) {
+        script.steps.push(Step {
+            pc: pc as usize,
+            opcode: opcodes::All::from(opcode as u8),
+            executed: executed != 0,
+            stack,
+            altstack,
+        });
+    }
+}
+
+impl fmt::Display for ExecutionTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, script) in self.scripts.iter().enumerate() {
+            writeln!(f, "script {}: {}", i, script.script)?;
+            for step in &script.steps {
+                writeln!(f, "  {}", step)?;
+            }
+        }
+        match &self.result {
+            Ok(()) => write!(f, "result: ok"),
+            Err(script_error) => write!(f, "result: {}", script_error),
+        }
+    }
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:>5} {}", self.pc, self.opcode)?;
+        if !self.executed {
+            return write!(f, " (skipped)");
+        }
+        write!(f, " stack: [")?;
+        fmt_stack(f, &self.stack)?;
+        write!(f, "] altstack: [")?;
+        fmt_stack(f, &self.altstack)?;
+        write!(f, "]")
+    }
+}
+
+fn fmt_stack(f: &mut fmt::Formatter<'_>, stack: &[Vec<u8>]) -> fmt::Result {
+    for (i, item) in stack.iter().enumerate() {
+        if i > 0 {
+            write!(f, " ")?;
+        }
+        write!(f, "{}", item.to_hex())?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::taproot_leaf_spend;
+    use crate::ChainParams;
+    use elements::opcodes::all::{
+        OP_DROP, OP_ELSE, OP_ENDIF, OP_EQUALVERIFY, OP_FROMALTSTACK, OP_IF, OP_PUSHNUM_1,
+        OP_PUSHNUM_2, OP_TOALTSTACK,
+    };
+    use elements::script::Builder;
+    use elements::TxOut;
+
+    #[test]
+    fn trace_records_opcodes_and_stacks_of_every_step() {
+        let leaf_script = Builder::new()
+            .push_opcode(OP_TOALTSTACK)
+            .push_int(0)
+            .push_opcode(OP_IF)
+            .push_opcode(OP_DROP)
+            .push_opcode(OP_ELSE)
+            .push_opcode(OP_FROMALTSTACK)
+            .push_opcode(OP_ENDIF)
+            .into_script();
+        let (mut transaction, spent_output) = taproot_leaf_spend(leaf_script.clone());
+        transaction.input[0]
+            .witness
+            .script_witness
+            .insert(0, vec![0x2a]);
+
+        let spent_outputs = [spent_output];
+
+        let prepared = PreparedTransaction::with_spent_outputs(
+            &transaction,
+            &spent_outputs,
+            &ChainParams::elements_regtest(),
+        )
+        .unwrap();
+        let trace = prepared
+            .trace(
+                &spent_outputs[0].script_pubkey,
+                &spent_outputs[0].value,
+                0,
+                VerificationFlags::ALL,
+            )
+            .unwrap();
+
+        assert_eq!(trace.result, Ok(()));
+        assert_eq!(trace.scripts.len(), 3); // the empty scriptSig, the scriptPubKey and the leaf script
+        assert_eq!(trace.scripts[2].script, leaf_script);
+        let steps = trace.scripts[2]
+            .steps
+            .iter()
+            .map(|step| {
+                (
+                    step.pc,
+                    step.opcode,
+                    step.executed,
+                    step.stack.clone(),
+                    step.altstack.clone(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            steps,
+            vec![
+                (0, OP_TOALTSTACK, true, vec![], vec![vec![0x2a]]),
+                (
+                    1,
+                    opcodes::all::OP_PUSHBYTES_0,
+                    true,
+                    vec![vec![]],
+                    vec![vec![0x2a]]
+                ),
+                (2, OP_IF, true, vec![], vec![vec![0x2a]]),
+                (3, OP_DROP, false, vec![], vec![vec![0x2a]]),
+                (4, OP_ELSE, true, vec![], vec![vec![0x2a]]),
+                (5, OP_FROMALTSTACK, true, vec![vec![0x2a]], vec![]),
+                (6, OP_ENDIF, true, vec![vec![0x2a]], vec![]),
+            ]
+        );
+    }
+
+    #[test]
+    fn trace_stops_at_failing_step() {
+        let (mut transaction, _) = taproot_leaf_spend(Script::new());
+        let script = Builder::new()
+            .push_opcode(OP_PUSHNUM_2)
+            .push_opcode(OP_EQUALVERIFY)
+            .into_script();
+        transaction.input[0].witness.script_witness = vec![vec![1], script.to_bytes()];
+        let spent_output = TxOut {
+            script_pubkey: script.to_v0_p2wsh(),
+            ..Default::default()
+        };
+
+        let trace = trace(
+            spent_output.script_pubkey,
+            &spent_output.value,
+            0,
+            &transaction,
+            VerificationFlags::ALL,
+        )
+        .unwrap();
+
+        assert_eq!(trace.result, Err(ScriptError::EqualVerify));
+        assert_eq!(trace.scripts.len(), 3); // the empty scriptSig, the scriptPubKey and the witness script
+        assert_eq!(trace.scripts[2].script, script);
+        assert_eq!(trace.scripts[2].steps.len(), 1);
+        assert_eq!(trace.scripts[2].steps[0].stack, vec![vec![1], vec![2]]);
+    }
+
+    #[test]
+    fn trace_is_printable() {
+        let script = Builder::new()
+            .push_opcode(OP_PUSHNUM_1)
+            .push_opcode(OP_TOALTSTACK)
+            .into_script();
+        let trace = ExecutionTrace {
+            scripts: vec![ScriptTrace {
+                script,
+                steps: vec![
+                    Step {
+                        pc: 0,
+                        opcode: OP_PUSHNUM_1,
+                        executed: true,
+                        stack: vec![vec![1]],
+                        altstack: vec![],
+                    },
+                    Step {
+                        pc: 1,
+                        opcode: OP_TOALTSTACK,
+                        executed: true,
+                        stack: vec![],
+                        altstack: vec![vec![1]],
+                    },
+                ],
+            }],
+            result: Err(ScriptError::EvalFalse),
+        };
+
+        assert_eq!(
+            trace.to_string(),
+            concat!(
+                "script 0: Script(OP_PUSHNUM_1 OP_TOALTSTACK)\n",
+                "      0 OP_PUSHNUM_1 stack: [01] altstack: []\n",
+                "      1 OP_TOALTSTACK stack: [] altstack: [01]\n",
+                "result: Script evaluated without error but finished with a false/empty top stack element"
+            )
+        );
+    }
+}
@@ -0,0 +1,739 @@
+use crate::{ChainParams, ConsensusViolation, Error, PreparedTransaction, VerificationFlags};
+use elements::{confidential, Script, Transaction, TxIn, TxOut};
+use rayon::prelude::*;
+use std::{
+    collections::{BTreeMap, HashMap},
+    error::Error as StdError,
+    fmt,
+};
+
+/// Provides the outputs spent by the inputs of a transaction.
+///
+/// Pegin inputs don't spend an output of this chain.
+/// Their spent output is derived from the pegin witness instead and never looked up here.
+pub trait Prevouts {
+    /// Returns the `script_pubkey` and value of the output spent by input `index`, or `None` if it is unknown.
+    fn script_and_value(&self, index: usize) -> Option<(&Script, &confidential::Value)>;
+
+    /// Returns the asset of the output spent by input `index`, or `None` if it is unknown.
+    ///
+    /// Spends of Taproot outputs can only be verified if the assets of all spent outputs are known.
+    fn asset(&self, _index: usize) -> Option<&confidential::Asset> {
+        None
+    }
+
+    /// Returns the full output spent by input `index`, or `None` if its script, value or asset is unknown.
+    ///
+    /// This is the output handed to the consensus library when it needs all spent outputs, like for spends of Taproot outputs.
+    /// The default assembles it from [`script_and_value`](Prevouts::script_and_value) and [`asset`](Prevouts::asset), leaving the nonce null.
+    fn spent_output(&self, index: usize) -> Option<TxOut> {
+        let (script, value) = self.script_and_value(index)?;
+
+        Some(TxOut {
+            asset: *self.asset(index)?,
+            value: *value,
+            script_pubkey: script.clone(),
+            ..Default::default()
+        })
+    }
+}
+
+impl Prevouts for [TxOut] {
+    fn script_and_value(&self, index: usize) -> Option<(&Script, &confidential::Value)> {
+        self.get(index)
+            .map(|txout| (&txout.script_pubkey, &txout.value))
+    }
+
+    fn asset(&self, index: usize) -> Option<&confidential::Asset> {
+        self.get(index).map(|txout| &txout.asset)
+    }
+
+    fn spent_output(&self, index: usize) -> Option<TxOut> {
+        self.get(index).cloned()
+    }
+}
+
+impl Prevouts for Vec<TxOut> {
+    fn script_and_value(&self, index: usize) -> Option<(&Script, &confidential::Value)> {
+        self.as_slice().script_and_value(index)
+    }
+
+    fn asset(&self, index: usize) -> Option<&confidential::Asset> {
+        self.as_slice().asset(index)
+    }
+
+    fn spent_output(&self, index: usize) -> Option<TxOut> {
+        self.as_slice().spent_output(index)
+    }
+}
+
+impl Prevouts for BTreeMap<usize, TxOut> {
+    fn script_and_value(&self, index: usize) -> Option<(&Script, &confidential::Value)> {
+        self.get(&index)
+            .map(|txout| (&txout.script_pubkey, &txout.value))
+    }
+
+    fn asset(&self, index: usize) -> Option<&confidential::Asset> {
+        self.get(&index).map(|txout| &txout.asset)
+    }
+
+    fn spent_output(&self, index: usize) -> Option<TxOut> {
+        self.get(&index).cloned()
+    }
+}
+
+impl<S: std::hash::BuildHasher> Prevouts for HashMap<usize, TxOut, S> {
+    fn script_and_value(&self, index: usize) -> Option<(&Script, &confidential::Value)> {
+        self.get(&index)
+            .map(|txout| (&txout.script_pubkey, &txout.value))
+    }
+
+    fn asset(&self, index: usize) -> Option<&confidential::Asset> {
+        self.get(&index).map(|txout| &txout.asset)
+    }
+
+    fn spent_output(&self, index: usize) -> Option<TxOut> {
+        self.get(&index).cloned()
+    }
+}
+
+impl Prevouts for BTreeMap<usize, (Script, confidential::Value)> {
+    fn script_and_value(&self, index: usize) -> Option<(&Script, &confidential::Value)> {
+        self.get(&index).map(|(script, value)| (script, value))
+    }
+}
+
+impl<S: std::hash::BuildHasher> Prevouts for HashMap<usize, (Script, confidential::Value), S> {
+    fn script_and_value(&self, index: usize) -> Option<(&Script, &confidential::Value)> {
+        self.get(&index).map(|(script, value)| (script, value))
+    }
+}
+
+/// The reason why a single input of a transaction failed to verify.
+#[derive(Debug)]
+pub enum InputError {
+    /// The output spent by this input was not provided.
+    MissingPrevout,
+    /// The input is a pegin but its pegin witness cannot be parsed.
+    InvalidPeginWitness,
+    /// The input spends a Taproot output, but not all outputs spent by the transaction are fully known.
+    SpentOutputsRequired,
+    /// The input does not correctly unlock the output it spends.
+    ConsensusViolation(ConsensusViolation),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::MissingPrevout => write!(f, "spent output is missing"),
+            InputError::InvalidPeginWitness => write!(f, "pegin witness is malformed"),
+            InputError::SpentOutputsRequired => {
+                write!(f, "verifying a taproot output requires all spent outputs")
+            }
+            InputError::ConsensusViolation(violation) => write!(f, "{}", violation),
+        }
+    }
+}
+
+impl StdError for InputError {}
+
+/// One or more inputs of a transaction failed to verify.
+#[derive(Debug)]
+pub struct TransactionViolation {
+    failures: Vec<(usize, InputError)>,
+}
+
+impl TransactionViolation {
+    /// The indices of the failed inputs together with the reason they failed, in ascending order.
+    pub fn failures(&self) -> &[(usize, InputError)] {
+        &self.failures
+    }
+
+    /// Returns the reason why input `index` failed, or `None` if it verified successfully.
+    pub fn input(&self, index: usize) -> Option<&InputError> {
+        self.failures
+            .iter()
+            .find(|(i, _)| *i == index)
+            .map(|(_, error)| error)
+    }
+}
+
+impl fmt::Display for TransactionViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} input(s) failed to verify", self.failures.len())?;
+
+        for (index, error) in &self.failures {
+            write!(f, "; input {}: {}", index, error)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl StdError for TransactionViolation {}
+
+/// Verifies all inputs of `transaction` against the outputs they spend according to the consensus rules.
+///
+/// The transaction is prepared only once, no matter how many inputs it has (see [`PreparedTransaction`]).
+/// Spends of Taproot outputs can only be verified if `prevouts` know the assets of all spent outputs, like a list of [`TxOut`]s does.
+/// Every input is checked, even if an earlier one already failed, so that the returned [`TransactionViolation`] names all failing inputs.
+/// Taproot signatures are checked against the genesis block of `chain`, and the rules are those of [`ChainParams::verification_flags`].
+///
+/// Like [`verify`](crate::verify), this function returns two layers of errors.
+/// The outer one is only reported if the consensus library fails to process the transaction.
+pub fn verify_transaction<P>(
+    transaction: &Transaction,
+    prevouts: &P,
+    chain: &ChainParams,
+) -> Result<Result<(), TransactionViolation>, Error>
+where
+    P: Prevouts + ?Sized,
+{
+    let prepared = prepare(transaction, prevouts, chain)?;
+    let flags = chain.verification_flags();
+
+    let failures = transaction
+        .input
+        .iter()
+        .enumerate()
+        .filter_map(|(index, input)| failure(&prepared, flags, index, input, prevouts))
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    if failures.is_empty() {
+        Ok(Ok(()))
+    } else {
+        Ok(Err(TransactionViolation { failures }))
+    }
+}
+
+/// Verifies all inputs of `transaction` like [`verify_transaction`], but spreads them over the global [`rayon`] thread pool.
+///
+/// The transaction is still prepared only once and shared by all threads.
+/// The result is identical to the one of [`verify_transaction`], including the order of the failures.
+/// For transactions with few inputs, the overhead of the thread pool outweighs the gain.
+pub fn verify_parallel<P>(
+    transaction: &Transaction,
+    prevouts: &P,
+    chain: &ChainParams,
+) -> Result<Result<(), TransactionViolation>, Error>
+where
+    P: Prevouts + Sync + ?Sized,
+{
+    let prepared = prepare(transaction, prevouts, chain)?;
+    let flags = chain.verification_flags();
+
+    let failures = transaction
+        .input
+        .par_iter()
+        .enumerate()
+        .filter_map(|(index, input)| failure(&prepared, flags, index, input, prevouts))
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    if failures.is_empty() {
+        Ok(Ok(()))
+    } else {
+        Ok(Err(TransactionViolation { failures }))
+    }
+}
+
+/// Prepares `transaction` with all spent outputs if they are fully known, so that spends of Taproot outputs can be verified.
+fn prepare<P>(
+    transaction: &Transaction,
+    prevouts: &P,
+    chain: &ChainParams,
+) -> Result<PreparedTransaction, Error>
+where
+    P: Prevouts + ?Sized,
+{
+    match spent_outputs(transaction, prevouts) {
+        Some(spent_outputs) => {
+            PreparedTransaction::with_spent_outputs(transaction, &spent_outputs, chain)
+        }
+        None => PreparedTransaction::new(transaction),
+    }
+}
+
+/// Collects the outputs spent by all inputs of `transaction`, or `None` if any of them is not fully known.
+fn spent_outputs<P>(transaction: &Transaction, prevouts: &P) -> Option<Vec<TxOut>>
+where
+    P: Prevouts + ?Sized,
+{
+    transaction
+        .input
+        .iter()
+        .enumerate()
+        .map(|(index, input)| {
+            if input.is_pegin() {
+                pegin_spent_output(input)
+            } else {
+                prevouts.spent_output(index)
+            }
+        })
+        .collect()
+}
+
+/// Derives the output spent by the pegin `input` from its pegin witness, or `None` if the witness cannot be parsed.
+pub(crate) fn pegin_spent_output(input: &TxIn) -> Option<TxOut> {
+    let pegin = input.pegin_data()?;
+
+    Some(TxOut {
+        asset: pegin.asset,
+        value: confidential::Value::Explicit(pegin.value),
+        script_pubkey: Script::from(pegin.claim_script.to_vec()),
+        ..Default::default()
+    })
+}
+
+/// Verifies input `index`, returning `None` if it unlocks the output it spends.
+fn failure<P>(
+    prepared: &PreparedTransaction,
+    flags: VerificationFlags,
+    index: usize,
+    input: &TxIn,
+    prevouts: &P,
+) -> Option<Result<(usize, InputError), Error>>
+where
+    P: Prevouts + ?Sized,
+{
+    match verify_input(prepared, flags, index, input, prevouts) {
+        Ok(Ok(())) => None,
+        Ok(Err(error)) => Some(Ok((index, error))),
+        Err(error) => Some(Err(error)),
+    }
+}
+
+fn verify_input<P>(
+    prepared: &PreparedTransaction,
+    flags: VerificationFlags,
+    index: usize,
+    input: &TxIn,
+    prevouts: &P,
+) -> Result<Result<(), InputError>, Error>
+where
+    P: Prevouts + ?Sized,
+{
+    let pegin_prevout;
+
+    let (script, value) = if input.is_pegin() {
+        let pegin = match input.pegin_data() {
+            Some(pegin) => pegin,
+            None => return Ok(Err(InputError::InvalidPeginWitness)),
+        };
+        pegin_prevout = (
+            Script::from(pegin.claim_script.to_vec()),
+            confidential::Value::Explicit(pegin.value),
+        );
+
+        (&pegin_prevout.0, &pegin_prevout.1)
+    } else {
+        match prevouts.script_and_value(index) {
+            Some(prevout) => prevout,
+            None => return Ok(Err(InputError::MissingPrevout)),
+        }
+    };
+
+    match prepared.verify_with_flags(script, value, index, flags) {
+        Ok(result) => Ok(result.map_err(InputError::ConsensusViolation)),
+        Err(Error::SpentOutputsRequired) => Ok(Err(InputError::SpentOutputsRequired)),
+        Err(error) => Err(error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        tests::{taproot_script_path_spend, TX_HEX},
+        ScriptError,
+    };
+    use elements::encode::{deserialize, serialize};
+    use elements::hashes::{sha256d, Hash, HashEngine};
+    use elements::opcodes::all::{OP_CHECKSIG, OP_PUSHNUM_1, OP_RETURN};
+    use elements::script::Builder;
+    use elements::secp256k1_zkp::{Message, PublicKey, Secp256k1, SecretKey};
+    use elements::sighash::SigHashCache;
+    use elements::{AssetIssuance, OutPoint, SigHashType, TxInWitness, Txid};
+    use hex_literal::hex;
+    use std::thread;
+
+    #[test]
+    fn reports_missing_prevout_per_input() {
+        let transaction = deserialize::<Transaction>(TX_HEX).unwrap();
+        let mut prevouts = BTreeMap::new();
+        prevouts.insert(0, input_0_prevout());
+
+        let violation =
+            verify_transaction(&transaction, &prevouts, &ChainParams::elements_regtest())
+                .unwrap()
+                .unwrap_err();
+
+        assert_eq!(violation.failures().len(), 1);
+        assert!(violation.input(0).is_none());
+        assert!(matches!(
+            violation.input(1),
+            Some(InputError::MissingPrevout)
+        ));
+    }
+
+    #[test]
+    fn reports_consensus_violation_per_input() {
+        let transaction = deserialize::<Transaction>(TX_HEX).unwrap();
+        let (script, value) = input_0_prevout();
+        let prevouts = vec![
+            TxOut {
+                script_pubkey: script.clone(),
+                value,
+                ..Default::default()
+            },
+            TxOut {
+                script_pubkey: script,
+                value,
+                ..Default::default()
+            },
+        ];
+
+        let violation =
+            verify_transaction(&transaction, &prevouts, &ChainParams::elements_regtest())
+                .unwrap()
+                .unwrap_err();
+
+        assert_eq!(violation.failures().len(), 1);
+        match violation.input(1) {
+            Some(InputError::ConsensusViolation(violation)) => {
+                assert_eq!(violation.script_error(), ScriptError::EvalFalse)
+            }
+            other => panic!("unexpected result for input 1: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn pegin_input_spends_claim_script() {
+        let transaction = pegin_transaction(Builder::new().push_opcode(OP_PUSHNUM_1).into_script());
+
+        verify_transaction(&transaction, &Vec::new(), &ChainParams::elements_regtest())
+            .unwrap()
+            .unwrap();
+    }
+
+    #[test]
+    fn pegin_input_with_failing_claim_script_does_not_verify() {
+        let transaction = pegin_transaction(Builder::new().push_opcode(OP_RETURN).into_script());
+
+        let violation =
+            verify_transaction(&transaction, &Vec::new(), &ChainParams::elements_regtest())
+                .unwrap()
+                .unwrap_err();
+
+        match violation.input(0) {
+            Some(InputError::ConsensusViolation(violation)) => {
+                assert_eq!(violation.script_error(), ScriptError::OpReturn)
+            }
+            other => panic!("unexpected result for input 0: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn taproot_input_verifies_against_list_of_spent_outputs() {
+        let (transaction, spent_output) = taproot_script_path_spend();
+
+        verify_transaction(
+            &transaction,
+            &vec![spent_output],
+            &ChainParams::elements_regtest(),
+        )
+        .unwrap()
+        .unwrap();
+    }
+
+    #[test]
+    fn taproot_input_requires_assets_of_spent_outputs() {
+        let (transaction, spent_output) = taproot_script_path_spend();
+        let mut prevouts = BTreeMap::new();
+        prevouts.insert(0, (spent_output.script_pubkey, spent_output.value));
+
+        let violation =
+            verify_transaction(&transaction, &prevouts, &ChainParams::elements_regtest())
+                .unwrap()
+                .unwrap_err();
+
+        assert!(matches!(
+            violation.input(0),
+            Some(InputError::SpentOutputsRequired)
+        ));
+    }
+
+    #[test]
+    fn taproot_input_verifies_against_map_of_spent_outputs() {
+        let (transaction, spent_output) = taproot_script_path_spend();
+        let mut prevouts = HashMap::new();
+        prevouts.insert(0, spent_output);
+
+        verify_transaction(&transaction, &prevouts, &ChainParams::elements_regtest())
+            .unwrap()
+            .unwrap();
+    }
+
+    #[test]
+    fn spent_output_is_assembled_from_script_value_and_asset() {
+        let (_, spent_output) = taproot_script_path_spend();
+        let mut prevouts = BTreeMap::new();
+        prevouts.insert(0, spent_output.clone());
+
+        let assembled = AssembledPrevouts(&prevouts).spent_output(0).unwrap();
+
+        assert_eq!(assembled, spent_output);
+        assert_eq!(prevouts.spent_output(1), None);
+    }
+
+    #[test]
+    fn rangeproof_signature_verifies_with_dynamic_federations() {
+        let (transaction, spent_outputs) = rangeproof_signed_spend();
+
+        verify_transaction(&transaction, &spent_outputs, &ChainParams::liquid())
+            .unwrap()
+            .unwrap();
+    }
+
+    #[test]
+    fn rangeproof_signature_does_not_verify_without_dynamic_federations() {
+        let (transaction, spent_outputs) = rangeproof_signed_spend();
+        let chain = ChainParams {
+            dynafed_active: false,
+            ..ChainParams::liquid()
+        };
+
+        let violation = verify_transaction(&transaction, &spent_outputs, &chain)
+            .unwrap()
+            .unwrap_err();
+
+        match violation.input(0) {
+            Some(InputError::ConsensusViolation(violation)) => {
+                assert_eq!(violation.script_error(), ScriptError::EvalFalse)
+            }
+            other => panic!("unexpected result for input 0: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parallel_verification_matches_serial_verification() {
+        let (transaction, prevouts) = p2pk_spends(48);
+        let chain = ChainParams::elements_regtest();
+        let serial = format!(
+            "{:?}",
+            verify_transaction(&transaction, &prevouts, &chain)
+                .unwrap()
+                .unwrap_err()
+        );
+
+        let threads = (0..4)
+            .map(|_| {
+                let transaction = transaction.clone();
+                let prevouts = prevouts.clone();
+                let chain = chain.clone();
+                let serial = serial.clone();
+
+                thread::spawn(move || {
+                    for _ in 0..10 {
+                        let parallel = verify_parallel(&transaction, &prevouts, &chain).unwrap();
+
+                        assert_eq!(format!("{:?}", parallel.unwrap_err()), serial);
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+
+        let violation = verify_parallel(&transaction, &prevouts, &chain)
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(
+            violation
+                .failures()
+                .iter()
+                .map(|(index, _)| *index)
+                .collect::<Vec<_>>(),
+            (0..48).filter(|index| index % 3 != 0).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn parallel_verification_of_valid_transaction_succeeds() {
+        let transaction = pegin_transaction(Builder::new().push_opcode(OP_PUSHNUM_1).into_script());
+
+        verify_parallel(&transaction, &Vec::new(), &ChainParams::elements_regtest())
+            .unwrap()
+            .unwrap();
+    }
+
+    /// Hides every provided method of the wrapped prevouts, so that their defaults are used.
+    struct AssembledPrevouts<'a>(&'a BTreeMap<usize, TxOut>);
+
+    impl Prevouts for AssembledPrevouts<'_> {
+        fn script_and_value(&self, index: usize) -> Option<(&Script, &confidential::Value)> {
+            self.0.script_and_value(index)
+        }
+
+        fn asset(&self, index: usize) -> Option<&confidential::Asset> {
+            self.0.asset(index)
+        }
+    }
+
+    fn input_0_prevout() -> (Script, confidential::Value) {
+        let address = "H1YukBju4An78pumXgTcu31DyKwmYLqgi1"
+            .parse::<elements::Address>()
+            .unwrap();
+        let value = confidential::Value::from_commitment(&hex!(
+            "08b0faffc84b19acd0001e5c615d058d7bec15cce82253ac416300f52bbb4a2bf9"
+        ))
+        .unwrap();
+
+        (address.script_pubkey(), value)
+    }
+
+    /// Builds a transaction with `inputs` signed spends of P2PK outputs.
+    ///
+    /// Every input whose index is not a multiple of 3 fails: those with remainder 1 carry a signature of the wrong key, those with remainder 2 miss their prevout.
+    fn p2pk_spends(inputs: u32) -> (Transaction, BTreeMap<usize, (Script, confidential::Value)>) {
+        let secp = Secp256k1::new();
+        let secret_key = SecretKey::from_slice(&[0x2a; 32]).unwrap();
+        let wrong_key = SecretKey::from_slice(&[0x2b; 32]).unwrap();
+        let script = Builder::new()
+            .push_slice(&PublicKey::from_secret_key(&secp, &secret_key).serialize())
+            .push_opcode(OP_CHECKSIG)
+            .into_script();
+        let value = confidential::Value::Explicit(100_000);
+
+        let mut transaction = Transaction {
+            version: 2,
+            lock_time: 0,
+            input: (0..inputs)
+                .map(|vout| TxIn {
+                    previous_output: OutPoint::new(Txid::hash(b"funding"), vout),
+                    is_pegin: false,
+                    has_issuance: false,
+                    script_sig: Script::new(),
+                    sequence: 0xffff_ffff,
+                    asset_issuance: AssetIssuance::default(),
+                    witness: TxInWitness::default(),
+                })
+                .collect(),
+            output: Vec::new(),
+        };
+        let mut prevouts = BTreeMap::new();
+        for index in 0..transaction.input.len() {
+            let sighash =
+                SigHashCache::new(&transaction).legacy_sighash(index, &script, SigHashType::All);
+            let key = if index % 3 == 1 {
+                &wrong_key
+            } else {
+                &secret_key
+            };
+            let mut signature = secp
+                .sign(&Message::from_slice(&sighash[..]).unwrap(), key)
+                .serialize_der()
+                .to_vec();
+            signature.push(SigHashType::All.as_u32() as u8);
+
+            transaction.input[index].script_sig =
+                Builder::new().push_slice(&signature).into_script();
+            if index % 3 != 2 {
+                prevouts.insert(index, (script.clone(), value));
+            }
+        }
+
+        (transaction, prevouts)
+    }
+
+    /// Builds a transaction that spends a P2WSH output with a `SIGHASH_ALL | SIGHASH_RANGEPROOF` signature.
+    fn rangeproof_signed_spend() -> (Transaction, Vec<TxOut>) {
+        let secp = Secp256k1::new();
+        let secret_key = SecretKey::from_slice(&[0x2a; 32]).unwrap();
+        let witness_script = Builder::new()
+            .push_slice(&PublicKey::from_secret_key(&secp, &secret_key).serialize())
+            .push_opcode(OP_CHECKSIG)
+            .into_script();
+        let spent_output = TxOut {
+            script_pubkey: witness_script.to_v0_p2wsh(),
+            value: confidential::Value::Explicit(100_000),
+            ..Default::default()
+        };
+        let mut transaction = Transaction {
+            version: 2,
+            lock_time: 0,
+            input: vec![TxIn {
+                previous_output: OutPoint::new(Txid::hash(b"funding"), 0),
+                is_pegin: false,
+                has_issuance: false,
+                script_sig: Script::new(),
+                sequence: 0xffff_ffff,
+                asset_issuance: AssetIssuance::default(),
+                witness: TxInWitness::default(),
+            }],
+            output: vec![TxOut {
+                script_pubkey: Builder::new().push_opcode(OP_RETURN).into_script(),
+                value: confidential::Value::Explicit(100_000),
+                ..Default::default()
+            }],
+        };
+
+        // The BIP143 signing data of SIGHASH_ALL, with the hash of the output proofs inserted before the lock time.
+        let mut signing_data = Vec::new();
+        SigHashCache::new(&transaction)
+            .encode_segwitv0_signing_data_to(
+                &mut signing_data,
+                0,
+                &witness_script,
+                spent_output.value,
+                SigHashType::All,
+            )
+            .unwrap();
+        let (head, tail) = signing_data.split_at(signing_data.len() - 8);
+        let mut proofs = sha256d::Hash::engine();
+        for _ in &transaction.output {
+            proofs.input(&[0x00, 0x00]); // empty rangeproof and surjection proof
+        }
+        let mut preimage = head.to_vec();
+        preimage.extend_from_slice(&sha256d::Hash::from_engine(proofs)[..]);
+        preimage.extend_from_slice(&tail[..4]);
+        preimage.extend_from_slice(&0x41u32.to_le_bytes());
+
+        let sighash = sha256d::Hash::hash(&preimage);
+        let mut signature = secp
+            .sign(&Message::from_slice(&sighash[..]).unwrap(), &secret_key)
+            .serialize_der()
+            .to_vec();
+        signature.push(0x41);
+        transaction.input[0].witness.script_witness = vec![signature, witness_script.into_bytes()];
+
+        (transaction, vec![spent_output])
+    }
+
+    fn pegin_transaction(claim_script: Script) -> Transaction {
+        Transaction {
+            version: 2,
+            lock_time: 0,
+            input: vec![TxIn {
+                previous_output: OutPoint::default(),
+                is_pegin: true,
+                has_issuance: false,
+                script_sig: Script::new(),
+                sequence: 0xffff_ffff,
+                asset_issuance: AssetIssuance::default(),
+                witness: TxInWitness {
+                    pegin_witness: vec![
+                        serialize(&100_000_000u64),
+                        vec![0x6f; 32],
+                        vec![0x06; 32],
+                        claim_script.into_bytes(),
+                        vec![0x00; 60],
+                        vec![0x00; 80],
+                    ],
+                    ..Default::default()
+                },
+            }],
+            output: Vec::new(),
+        }
+    }
+}
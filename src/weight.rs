@@ -0,0 +1,229 @@
+use crate::{ffi, Error, VerificationFlags};
+use elements::{encode::serialize, Transaction, TxOut};
+
+/// The weight of a witness byte relative to a byte of the transaction without witness data, `WITNESS_SCALE_FACTOR` in Elements.
+const WITNESS_SCALE_FACTOR: usize = 4;
+
+/// The weight that Elements charges for each unit of signature operation cost when relaying transactions, `-bytespersigop`.
+const BYTES_PER_SIGOP: usize = 20;
+
+/// The size and signature operation cost of a transaction, computed by the consensus library.
+///
+/// Fee estimates based on these agree exactly with Elements, including the discount of the rangeproofs and surjection proofs of confidential outputs, which are witness data.
+pub trait TransactionExt {
+    /// The weight of the transaction, like `GetTransactionWeight` in Elements.
+    fn consensus_weight(&self) -> Result<usize, Error>;
+
+    /// The virtual size of the transaction, i.e. its weight divided by four and rounded up.
+    fn consensus_vsize(&self) -> Result<usize, Error> {
+        Ok(to_vsize(self.consensus_weight()?))
+    }
+
+    /// The signature operation cost of the transaction under `flags`, like `GetTransactionSigOpCost` in Elements.
+    ///
+    /// `spent_outputs` must hold the output spent by each input, including the outputs claimed by peg-ins, and be empty for a coinbase.
+    /// P2SH and witness signature operations are only counted if `flags` contain [`VerificationFlags::P2SH`] and [`VerificationFlags::WITNESS`] respectively.
+    fn sigop_cost(&self, spent_outputs: &[TxOut], flags: VerificationFlags)
+        -> Result<usize, Error>;
+
+    /// The virtual size that `elementsd` computes fee rates with, which counts each unit of signature operation cost as at least 20 weight units.
+    fn sigop_adjusted_vsize(&self, spent_outputs: &[TxOut]) -> Result<usize, Error> {
+        let weight = self.consensus_weight()?;
+        let sigop_cost = self.sigop_cost(spent_outputs, VerificationFlags::ALL)?;
+
+        Ok(to_vsize(weight.max(sigop_cost * BYTES_PER_SIGOP)))
+    }
+}
+
+impl TransactionExt for Transaction {
+    fn consensus_weight(&self) -> Result<usize, Error> {
+        let transaction = serialize(self);
+
+        let mut weight = 0;
+
+        let computed = ffi::call(|err| unsafe {
+            elements_consensus_sys::bitcoinconsensus_transaction_weight(
+                transaction.as_ptr(),
+                transaction.len() as u32,
+                &mut weight,
+                err,
+            )
+        })?;
+
+        if !computed {
+            return Err(Error::NoResult);
+        }
+
+        Ok(weight as usize)
+    }
+
+    fn sigop_cost(
+        &self,
+        spent_outputs: &[TxOut],
+        flags: VerificationFlags,
+    ) -> Result<usize, Error> {
+        let expected_spent_outputs = if self.is_coinbase() {
+            0
+        } else {
+            self.input.len()
+        };
+        if spent_outputs.len() != expected_spent_outputs {
+            return Err(Error::SpentOutputsMismatch);
+        }
+
+        let transaction = serialize(self);
+        let spent_outputs = serialize(&spent_outputs.to_vec());
+
+        let mut sigop_cost = 0;
+
+        let computed = ffi::call(|err| unsafe {
+            elements_consensus_sys::bitcoinconsensus_transaction_sigop_cost(
+                transaction.as_ptr(),
+                transaction.len() as u32,
+                spent_outputs.as_ptr(),
+                spent_outputs.len() as u32,
+                flags.bits(),
+                &mut sigop_cost,
+                err,
+            )
+        })?;
+
+        if !computed {
+            return Err(Error::NoResult);
+        }
+
+        Ok(sigop_cost as usize)
+    }
+}
+
+fn to_vsize(weight: usize) -> usize {
+    weight.saturating_add(WITNESS_SCALE_FACTOR - 1) / WITNESS_SCALE_FACTOR
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TX_HEX;
+    use elements::encode::deserialize;
+    use elements::opcodes::all::OP_CHECKMULTISIG;
+    use elements::script::Builder;
+    use elements::{AssetIssuance, OutPoint, Script, TxIn};
+
+    fn mainnet_spent_outputs() -> Vec<TxOut> {
+        let address = "H1YukBju4An78pumXgTcu31DyKwmYLqgi1"
+            .parse::<elements::Address>()
+            .unwrap();
+        let spent_output = TxOut {
+            script_pubkey: address.script_pubkey(),
+            ..Default::default()
+        };
+
+        vec![spent_output.clone(), spent_output]
+    }
+
+    fn spend(outputs: Vec<TxOut>) -> Transaction {
+        Transaction {
+            version: 2,
+            lock_time: 0,
+            input: vec![TxIn {
+                previous_output: OutPoint {
+                    vout: 0,
+                    ..Default::default()
+                },
+                is_pegin: false,
+                has_issuance: false,
+                script_sig: Script::new(),
+                sequence: 0xffff_ffff,
+                asset_issuance: AssetIssuance::default(),
+                witness: Default::default(),
+            }],
+            output: outputs,
+        }
+    }
+
+    #[test]
+    fn confidential_transaction_weight_discounts_proofs() {
+        let transaction: Transaction = deserialize(TX_HEX).unwrap();
+
+        let weight = transaction.consensus_weight().unwrap();
+
+        assert_eq!(weight, transaction.get_weight());
+        assert!(weight < transaction.get_size() * WITNESS_SCALE_FACTOR);
+
+        let vsize = transaction.consensus_vsize().unwrap();
+        assert!(vsize * WITNESS_SCALE_FACTOR >= weight);
+        assert!(vsize * WITNESS_SCALE_FACTOR < weight + WITNESS_SCALE_FACTOR);
+    }
+
+    #[test]
+    fn sigop_cost_counts_witness_sigops_with_witness_flag() {
+        let transaction: Transaction = deserialize(TX_HEX).unwrap();
+        let spent_outputs = mainnet_spent_outputs();
+
+        assert_eq!(
+            transaction
+                .sigop_cost(&spent_outputs, VerificationFlags::ALL)
+                .unwrap(),
+            2
+        );
+        assert_eq!(
+            transaction
+                .sigop_cost(&spent_outputs, VerificationFlags::P2SH)
+                .unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn vsize_is_adjusted_for_sigops() {
+        let mut sigops = Builder::new();
+        for _ in 0..10 {
+            sigops = sigops.push_opcode(OP_CHECKMULTISIG);
+        }
+        let transaction = spend(vec![TxOut {
+            script_pubkey: sigops.into_script(),
+            ..Default::default()
+        }]);
+        let spent_outputs = [TxOut::default()];
+
+        // Each OP_CHECKMULTISIG outside of a witness counts as 20 legacy signature operations.
+        let sigop_cost = 10 * 20 * WITNESS_SCALE_FACTOR;
+        assert_eq!(
+            transaction
+                .sigop_cost(&spent_outputs, VerificationFlags::ALL)
+                .unwrap(),
+            sigop_cost
+        );
+        assert!(transaction.consensus_weight().unwrap() < sigop_cost * BYTES_PER_SIGOP);
+        assert_eq!(
+            transaction.sigop_adjusted_vsize(&spent_outputs).unwrap(),
+            sigop_cost * BYTES_PER_SIGOP / WITNESS_SCALE_FACTOR
+        );
+    }
+
+    #[test]
+    fn coinbase_spends_no_outputs() {
+        let mut coinbase = spend(vec![TxOut::default()]);
+        coinbase.input[0].previous_output = OutPoint::default();
+
+        assert_eq!(coinbase.sigop_cost(&[], VerificationFlags::ALL).unwrap(), 0);
+        assert_eq!(
+            coinbase
+                .sigop_cost(&[TxOut::default()], VerificationFlags::ALL)
+                .unwrap_err(),
+            Error::SpentOutputsMismatch
+        );
+    }
+
+    #[test]
+    fn sigop_cost_rejects_invalid_flags() {
+        let transaction = spend(vec![TxOut::default()]);
+
+        assert_eq!(
+            transaction
+                .sigop_cost(&[TxOut::default()], VerificationFlags::WITNESS)
+                .unwrap_err(),
+            Error::InvalidFlags
+        );
+    }
+}